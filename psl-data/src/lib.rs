@@ -0,0 +1,13 @@
+//! Snapshot of the [Public Suffix List], used by the `psl` feature of
+//! [`iref`](https://docs.rs/iref).
+//!
+//! The list is distributed under the terms of the Mozilla Public License,
+//! v. 2.0.
+//!
+//! [Public Suffix List]: <https://publicsuffix.org/>
+#![no_std]
+
+/// Content of `public_suffix_list.dat`, in the [PSL format].
+///
+/// [PSL format]: <https://github.com/publicsuffix/list/wiki/Format>
+pub const DATA: &str = include_str!("../public_suffix_list.dat");
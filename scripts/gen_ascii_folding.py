@@ -0,0 +1,75 @@
+#!/usr/bin/env python3
+"""Generates `src/common/ascii_folding.rs`.
+
+The table lists the non-ASCII characters that UTS #46 maps to ASCII: the
+characters whose NFKC case folding (`NFKC_Casefold` property) is ASCII (such
+as fullwidth digits), and the ideographic full stops used as label
+separators.
+
+Reads the `NFKC_Casefold` property from the `DerivedNormalizationProps.txt`
+data file of the Unicode Character Database, available at
+<https://www.unicode.org/Public/>.
+
+Usage: python3 scripts/gen_ascii_folding.py PATH/TO/DerivedNormalizationProps.txt
+"""
+import re
+import sys
+
+OUTPUT = "src/common/ascii_folding.rs"
+
+# Label separators mapped to `.` by UTS #46 (section 4.5).
+SEPARATORS = [0x3002, 0xFF0E, 0xFF61]
+
+if len(sys.argv) != 2:
+    sys.exit(__doc__.split("\n\n")[-1].strip())
+
+PROPS = sys.argv[1]
+
+
+def rust_str(s):
+    assert all(0x20 <= ord(c) < 0x7F for c in s), repr(s)
+    return '"%s"' % s.replace("\\", "\\\\").replace('"', '\\"')
+
+
+folding = {cp: "." for cp in SEPARATORS}
+with open(PROPS, encoding="utf-8") as f:
+    m = re.match(r"# DerivedNormalizationProps-(\d+\.\d+\.\d+)\.txt$", f.readline().strip())
+    if not m:
+        sys.exit("%s: not a `DerivedNormalizationProps.txt` file" % PROPS)
+    version = m.group(1)
+
+    for line in f:
+        fields = [field.strip() for field in line.split("#")[0].split(";")]
+        if len(fields) < 3 or fields[1] != "NFKC_CF":
+            continue
+
+        start, _, end = fields[0].partition("..")
+        folded = "".join(chr(int(cp, 16)) for cp in fields[2].split())
+        if folded and folded.isascii():
+            for cp in range(int(start, 16), int(end or start, 16) + 1):
+                if cp >= 0x80:
+                    folding.setdefault(cp, folded)
+
+entries = sorted(folding.items())
+
+out = [
+    "//! ASCII folding table.",
+    "//!",
+    "//! Generated by `scripts/gen_ascii_folding.py` from the Unicode Character",
+    "//! Database, version %s. Do not edit." % version,
+    "//!",
+    "//! The Unicode data is distributed under the terms of the Unicode License",
+    "//! v3 (see `LICENSE-UNICODE`).",
+    "",
+    "/// Non-ASCII characters mapped to ASCII by UTS #46, with their mapping,",
+    "/// sorted by character.",
+    "pub(super) static ASCII_FOLDING: [(char, &str); %d] = [" % len(entries),
+]
+for cp, folded in entries:
+    out.append("\t('\\u{%X}', %s)," % (cp, rust_str(folded)))
+out.append("];")
+
+with open(OUTPUT, "w") as f:
+    f.write("\n".join(out) + "\n")
+
+print(len(entries))
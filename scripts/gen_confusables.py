@@ -0,0 +1,96 @@
+#!/usr/bin/env python3
+"""Generates `src/security/confusables.rs`.
+
+Reads the UTS #39 `confusables.txt` data file, available at
+<https://www.unicode.org/Public/security/>, and keeps the non-ASCII
+characters whose prototype is a single printable ASCII character. The
+Unicode version is read from the `# Version:` header line of the file. The
+character names in the generated comments come from the Python `unicodedata`
+module, and are omitted for characters it does not know.
+
+Usage: python3 scripts/gen_confusables.py PATH/TO/confusables.txt
+"""
+import re
+import sys
+import unicodedata
+
+OUTPUT = "src/security/confusables.rs"
+
+LINE = re.compile(r"^([0-9A-F]+) ;\t((?:[0-9A-F]+ )+);\t\w+")
+VERSION = re.compile(r"^# Version: (\d+\.\d+\.\d+)$")
+
+if len(sys.argv) != 2:
+    sys.exit(__doc__.split("\n\n")[-1].strip())
+
+CONFUSABLES = sys.argv[1]
+
+
+def rust_char(c):
+    if c in "\\'":
+        return "'\\%s'" % c
+    return "'%s'" % c
+
+
+entries = []
+version = None
+with open(CONFUSABLES, encoding="utf-8-sig") as f:
+    for line in f:
+        m = VERSION.match(line.strip())
+        if m:
+            version = m.group(1)
+            continue
+
+        m = LINE.match(line)
+        if not m:
+            continue
+
+        source = int(m.group(1), 16)
+        prototype = [int(cp, 16) for cp in m.group(2).split()]
+        if source >= 0x80 and len(prototype) == 1 and 0x21 <= prototype[0] < 0x7F:
+            entries.append((source, chr(prototype[0])))
+
+if version is None:
+    sys.exit("%s: missing `# Version:` header" % CONFUSABLES)
+
+entries.sort()
+
+out = [
+    "//! Characters confusable with ASCII characters.",
+    "//!",
+    "//! Generated by `scripts/gen_confusables.py` from the [UTS #39] confusables",
+    "//! data, version %s, keeping the non-ASCII characters whose prototype" % version,
+    "//! is a single printable ASCII character. Do not edit.",
+    "//!",
+    "//! The Unicode data is distributed under the terms of the Unicode License",
+    "//! v3 (see `LICENSE-UNICODE`).",
+    "//!",
+    "//! [UTS #39]: <https://www.unicode.org/reports/tr39/#Confusable_Detection>",
+    "",
+    "/// Confusable characters and their ASCII prototype, sorted by character.",
+    "const CONFUSABLES: [(char, char); %d] = [" % len(entries),
+]
+for source, prototype in entries:
+    entry = "\t('\\u{%04X}', %s)," % (source, rust_char(prototype))
+    name = unicodedata.name(chr(source), None)
+    if name:
+        entry += " // %s" % name
+    out.append(entry)
+out.append("];")
+out.append("""
+/// Returns the ASCII character the given non-ASCII character is confusable
+/// with, if any.
+pub(super) fn ascii_prototype(c: char) -> Option<char> {
+	match c {
+		// Fullwidth ASCII variants.
+		'\\u{FF01}'..='\\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0),
+		_ => CONFUSABLES
+			.binary_search_by_key(&c, |(c, _)| *c)
+			.ok()
+			.map(|i| CONFUSABLES[i].1),
+	}
+}""")
+
+with open(OUTPUT, "w") as f:
+    f.write("\n".join(out) + "\n")
+
+print(len(entries))
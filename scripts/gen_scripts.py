@@ -0,0 +1,145 @@
+#!/usr/bin/env python3
+"""Generates `src/security/scripts.rs`.
+
+Reads the `Script_Extensions` property from the following data files of the
+Unicode Character Database, available at <https://www.unicode.org/Public/>,
+which must all come from the same Unicode version:
+
+- `Scripts.txt`, giving the `Script` property of each code point, used when
+  the code point has no explicit `Script_Extensions` value;
+- `ScriptExtensions.txt`, giving the explicit `Script_Extensions` values as
+  lists of short script names;
+- `PropertyValueAliases.txt`, mapping short script names to long names.
+
+Usage: python3 scripts/gen_scripts.py PATH/TO/Scripts.txt \\
+	PATH/TO/ScriptExtensions.txt PATH/TO/PropertyValueAliases.txt
+"""
+import re
+import sys
+
+OUTPUT = "src/security/scripts.rs"
+
+# UTS #39 augmented scripts.
+AUGMENTED = ["Han_with_Bopomofo", "Japanese", "Korean"]
+
+if len(sys.argv) != 4:
+    sys.exit(__doc__.split("\n\n")[-1].strip())
+
+SCRIPTS, SCRIPT_EXTENSIONS, PROPERTY_VALUE_ALIASES = sys.argv[1:]
+
+
+def camel(name):
+    return "".join(p[:1].upper() + p[1:] for p in name.split("_"))
+
+
+def read(path, name):
+    """Returns the Unicode version of the given data file, checking its name,
+    and its data lines split into fields."""
+    with open(path, encoding="utf-8") as f:
+        header = f.readline()
+        m = re.match(r"# %s-(\d+\.\d+\.\d+)\.txt$" % name, header.strip())
+        if not m:
+            sys.exit("%s: not a `%s.txt` file" % (path, name))
+
+        lines = []
+        for line in f:
+            line = line.split("#")[0].strip()
+            if line:
+                lines.append([field.strip() for field in line.split(";")])
+
+        return m.group(1), lines
+
+
+def code_points(field):
+    start, _, end = field.partition("..")
+    return int(start, 16), int(end or start, 16)
+
+
+version, script_lines = read(SCRIPTS, "Scripts")
+for path, name in [
+    (SCRIPT_EXTENSIONS, "ScriptExtensions"),
+    (PROPERTY_VALUE_ALIASES, "PropertyValueAliases"),
+]:
+    other_version, lines = read(path, name)
+    if other_version != version:
+        sys.exit("%s: version %s, expected %s" % (path, other_version, version))
+
+    if name == "ScriptExtensions":
+        extension_lines = lines
+    else:
+        alias_lines = lines
+
+# Long script names, by short or alternative name.
+long_names = {}
+for fields in alias_lines:
+    if fields[0] == "sc":
+        for alias in fields[1:]:
+            long_names[alias] = fields[2]
+
+scx = {}
+for fields in script_lines:
+    start, end = code_points(fields[0])
+    for cp in range(start, end + 1):
+        scx[cp] = [fields[1]]
+
+for fields in extension_lines:
+    start, end = code_points(fields[0])
+    for cp in range(start, end + 1):
+        scx[cp] = [long_names[name] for name in fields[1].split()]
+
+names = {name for scripts in scx.values() for name in scripts}
+names.discard("Unknown")
+all_names = sorted(names) + ["Unknown"] + AUGMENTED
+assert len(all_names) <= 192, len(all_names)
+
+# Ranges of consecutive code points with identical script sets.
+ranges = []
+for cp in sorted(scx):
+    if ranges and ranges[-1][1] + 1 == cp and ranges[-1][2] == scx[cp]:
+        ranges[-1] = (ranges[-1][0], cp, scx[cp])
+    else:
+        ranges.append((cp, cp, scx[cp]))
+
+out = [
+    "//! Unicode script tables.",
+    "//!",
+    "//! Generated by `scripts/gen_scripts.py` from the `Script_Extensions`",
+    "//! property of the Unicode Character Database, version %s. Do not edit."
+    % version,
+    "//!",
+    "//! The Unicode data is distributed under the terms of the Unicode License",
+    "//! v3 (see `LICENSE-UNICODE`).",
+    "",
+    "/// Unicode script.",
+    "///",
+    "/// Besides the scripts of the Unicode Character Database, this includes the",
+    "/// [UTS #39] augmented scripts `Han_with_Bopomofo`, `Japanese` and `Korean`.",
+    "///",
+    "/// [UTS #39]: <https://www.unicode.org/reports/tr39/#Mixed_Script_Detection>",
+    "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]",
+    "#[repr(u8)]",
+    "pub enum Script {",
+]
+for n in all_names:
+    out.append("\t%s," % camel(n))
+out.append("}\n")
+out.append("impl Script {")
+out.append("\t/// Number of scripts.")
+out.append("\tpub(super) const COUNT: usize = %d;\n" % len(all_names))
+out.append("\t/// Returns the name of this script, as used in the Unicode Character")
+out.append("\t/// Database (e.g. `Old_Italic`).")
+out.append("\tpub fn name(&self) -> &'static str {")
+out.append("\t\tmatch self {")
+for n in all_names:
+    out.append('\t\t\tSelf::%s => "%s",' % (camel(n), n))
+out.append("\t\t}\n\t}\n}\n")
+out.append("/// `Script_Extensions` property values, by code point range.")
+out.append("///")
+out.append("/// Code points not covered by any range have the `Unknown` script.")
+out.append("pub(super) static SCRIPT_EXTENSIONS: [(u32, u32, &[Script]); %d] = [" % len(ranges))
+for s, e, scripts in ranges:
+    out.append("\t(0x%X, 0x%X, &[%s])," % (s, e, ", ".join("Script::" + camel(x) for x in scripts)))
+out.append("];")
+
+with open(OUTPUT, "w") as f:
+    f.write("\n".join(out) + "\n")
@@ -0,0 +1,1455 @@
+//! ASCII folding table.
+//!
+//! Generated by `scripts/gen_ascii_folding.py` from the Unicode Character
+//! Database, version 16.0.0. Do not edit.
+//!
+//! The Unicode data is distributed under the terms of the Unicode License
+//! v3 (see `LICENSE-UNICODE`).
+
+/// Non-ASCII characters mapped to ASCII by UTS #46, with their mapping,
+/// sorted by character.
+pub(super) static ASCII_FOLDING: [(char, &str); 1443] = [
+	('\u{A0}', " "),
+	('\u{AA}', "a"),
+	('\u{B2}', "2"),
+	('\u{B3}', "3"),
+	('\u{B9}', "1"),
+	('\u{BA}', "o"),
+	('\u{DF}', "ss"),
+	('\u{132}', "ij"),
+	('\u{133}', "ij"),
+	('\u{17F}', "s"),
+	('\u{1C7}', "lj"),
+	('\u{1C8}', "lj"),
+	('\u{1C9}', "lj"),
+	('\u{1CA}', "nj"),
+	('\u{1CB}', "nj"),
+	('\u{1CC}', "nj"),
+	('\u{1F1}', "dz"),
+	('\u{1F2}', "dz"),
+	('\u{1F3}', "dz"),
+	('\u{2B0}', "h"),
+	('\u{2B2}', "j"),
+	('\u{2B3}', "r"),
+	('\u{2B7}', "w"),
+	('\u{2B8}', "y"),
+	('\u{2E1}', "l"),
+	('\u{2E2}', "s"),
+	('\u{2E3}', "x"),
+	('\u{37E}', ";"),
+	('\u{1D2C}', "a"),
+	('\u{1D2E}', "b"),
+	('\u{1D30}', "d"),
+	('\u{1D31}', "e"),
+	('\u{1D33}', "g"),
+	('\u{1D34}', "h"),
+	('\u{1D35}', "i"),
+	('\u{1D36}', "j"),
+	('\u{1D37}', "k"),
+	('\u{1D38}', "l"),
+	('\u{1D39}', "m"),
+	('\u{1D3A}', "n"),
+	('\u{1D3C}', "o"),
+	('\u{1D3E}', "p"),
+	('\u{1D3F}', "r"),
+	('\u{1D40}', "t"),
+	('\u{1D41}', "u"),
+	('\u{1D42}', "w"),
+	('\u{1D43}', "a"),
+	('\u{1D47}', "b"),
+	('\u{1D48}', "d"),
+	('\u{1D49}', "e"),
+	('\u{1D4D}', "g"),
+	('\u{1D4F}', "k"),
+	('\u{1D50}', "m"),
+	('\u{1D52}', "o"),
+	('\u{1D56}', "p"),
+	('\u{1D57}', "t"),
+	('\u{1D58}', "u"),
+	('\u{1D5B}', "v"),
+	('\u{1D62}', "i"),
+	('\u{1D63}', "r"),
+	('\u{1D64}', "u"),
+	('\u{1D65}', "v"),
+	('\u{1D9C}', "c"),
+	('\u{1DA0}', "f"),
+	('\u{1DBB}', "z"),
+	('\u{1E9E}', "ss"),
+	('\u{1FEF}', "`"),
+	('\u{2000}', " "),
+	('\u{2001}', " "),
+	('\u{2002}', " "),
+	('\u{2003}', " "),
+	('\u{2004}', " "),
+	('\u{2005}', " "),
+	('\u{2006}', " "),
+	('\u{2007}', " "),
+	('\u{2008}', " "),
+	('\u{2009}', " "),
+	('\u{200A}', " "),
+	('\u{2024}', "."),
+	('\u{2025}', ".."),
+	('\u{2026}', "..."),
+	('\u{202F}', " "),
+	('\u{203C}', "!!"),
+	('\u{2047}', "??"),
+	('\u{2048}', "?!"),
+	('\u{2049}', "!?"),
+	('\u{205F}', " "),
+	('\u{2070}', "0"),
+	('\u{2071}', "i"),
+	('\u{2074}', "4"),
+	('\u{2075}', "5"),
+	('\u{2076}', "6"),
+	('\u{2077}', "7"),
+	('\u{2078}', "8"),
+	('\u{2079}', "9"),
+	('\u{207A}', "+"),
+	('\u{207C}', "="),
+	('\u{207D}', "("),
+	('\u{207E}', ")"),
+	('\u{207F}', "n"),
+	('\u{2080}', "0"),
+	('\u{2081}', "1"),
+	('\u{2082}', "2"),
+	('\u{2083}', "3"),
+	('\u{2084}', "4"),
+	('\u{2085}', "5"),
+	('\u{2086}', "6"),
+	('\u{2087}', "7"),
+	('\u{2088}', "8"),
+	('\u{2089}', "9"),
+	('\u{208A}', "+"),
+	('\u{208C}', "="),
+	('\u{208D}', "("),
+	('\u{208E}', ")"),
+	('\u{2090}', "a"),
+	('\u{2091}', "e"),
+	('\u{2092}', "o"),
+	('\u{2093}', "x"),
+	('\u{2095}', "h"),
+	('\u{2096}', "k"),
+	('\u{2097}', "l"),
+	('\u{2098}', "m"),
+	('\u{2099}', "n"),
+	('\u{209A}', "p"),
+	('\u{209B}', "s"),
+	('\u{209C}', "t"),
+	('\u{20A8}', "rs"),
+	('\u{2100}', "a/c"),
+	('\u{2101}', "a/s"),
+	('\u{2102}', "c"),
+	('\u{2105}', "c/o"),
+	('\u{2106}', "c/u"),
+	('\u{210A}', "g"),
+	('\u{210B}', "h"),
+	('\u{210C}', "h"),
+	('\u{210D}', "h"),
+	('\u{210E}', "h"),
+	('\u{2110}', "i"),
+	('\u{2111}', "i"),
+	('\u{2112}', "l"),
+	('\u{2113}', "l"),
+	('\u{2115}', "n"),
+	('\u{2116}', "no"),
+	('\u{2119}', "p"),
+	('\u{211A}', "q"),
+	('\u{211B}', "r"),
+	('\u{211C}', "r"),
+	('\u{211D}', "r"),
+	('\u{2120}', "sm"),
+	('\u{2121}', "tel"),
+	('\u{2122}', "tm"),
+	('\u{2124}', "z"),
+	('\u{2128}', "z"),
+	('\u{212A}', "k"),
+	('\u{212C}', "b"),
+	('\u{212D}', "c"),
+	('\u{212F}', "e"),
+	('\u{2130}', "e"),
+	('\u{2131}', "f"),
+	('\u{2133}', "m"),
+	('\u{2134}', "o"),
+	('\u{2139}', "i"),
+	('\u{213B}', "fax"),
+	('\u{2145}', "d"),
+	('\u{2146}', "d"),
+	('\u{2147}', "e"),
+	('\u{2148}', "i"),
+	('\u{2149}', "j"),
+	('\u{2160}', "i"),
+	('\u{2161}', "ii"),
+	('\u{2162}', "iii"),
+	('\u{2163}', "iv"),
+	('\u{2164}', "v"),
+	('\u{2165}', "vi"),
+	('\u{2166}', "vii"),
+	('\u{2167}', "viii"),
+	('\u{2168}', "ix"),
+	('\u{2169}', "x"),
+	('\u{216A}', "xi"),
+	('\u{216B}', "xii"),
+	('\u{216C}', "l"),
+	('\u{216D}', "c"),
+	('\u{216E}', "d"),
+	('\u{216F}', "m"),
+	('\u{2170}', "i"),
+	('\u{2171}', "ii"),
+	('\u{2172}', "iii"),
+	('\u{2173}', "iv"),
+	('\u{2174}', "v"),
+	('\u{2175}', "vi"),
+	('\u{2176}', "vii"),
+	('\u{2177}', "viii"),
+	('\u{2178}', "ix"),
+	('\u{2179}', "x"),
+	('\u{217A}', "xi"),
+	('\u{217B}', "xii"),
+	('\u{217C}', "l"),
+	('\u{217D}', "c"),
+	('\u{217E}', "d"),
+	('\u{217F}', "m"),
+	('\u{2460}', "1"),
+	('\u{2461}', "2"),
+	('\u{2462}', "3"),
+	('\u{2463}', "4"),
+	('\u{2464}', "5"),
+	('\u{2465}', "6"),
+	('\u{2466}', "7"),
+	('\u{2467}', "8"),
+	('\u{2468}', "9"),
+	('\u{2469}', "10"),
+	('\u{246A}', "11"),
+	('\u{246B}', "12"),
+	('\u{246C}', "13"),
+	('\u{246D}', "14"),
+	('\u{246E}', "15"),
+	('\u{246F}', "16"),
+	('\u{2470}', "17"),
+	('\u{2471}', "18"),
+	('\u{2472}', "19"),
+	('\u{2473}', "20"),
+	('\u{2474}', "(1)"),
+	('\u{2475}', "(2)"),
+	('\u{2476}', "(3)"),
+	('\u{2477}', "(4)"),
+	('\u{2478}', "(5)"),
+	('\u{2479}', "(6)"),
+	('\u{247A}', "(7)"),
+	('\u{247B}', "(8)"),
+	('\u{247C}', "(9)"),
+	('\u{247D}', "(10)"),
+	('\u{247E}', "(11)"),
+	('\u{247F}', "(12)"),
+	('\u{2480}', "(13)"),
+	('\u{2481}', "(14)"),
+	('\u{2482}', "(15)"),
+	('\u{2483}', "(16)"),
+	('\u{2484}', "(17)"),
+	('\u{2485}', "(18)"),
+	('\u{2486}', "(19)"),
+	('\u{2487}', "(20)"),
+	('\u{2488}', "1."),
+	('\u{2489}', "2."),
+	('\u{248A}', "3."),
+	('\u{248B}', "4."),
+	('\u{248C}', "5."),
+	('\u{248D}', "6."),
+	('\u{248E}', "7."),
+	('\u{248F}', "8."),
+	('\u{2490}', "9."),
+	('\u{2491}', "10."),
+	('\u{2492}', "11."),
+	('\u{2493}', "12."),
+	('\u{2494}', "13."),
+	('\u{2495}', "14."),
+	('\u{2496}', "15."),
+	('\u{2497}', "16."),
+	('\u{2498}', "17."),
+	('\u{2499}', "18."),
+	('\u{249A}', "19."),
+	('\u{249B}', "20."),
+	('\u{249C}', "(a)"),
+	('\u{249D}', "(b)"),
+	('\u{249E}', "(c)"),
+	('\u{249F}', "(d)"),
+	('\u{24A0}', "(e)"),
+	('\u{24A1}', "(f)"),
+	('\u{24A2}', "(g)"),
+	('\u{24A3}', "(h)"),
+	('\u{24A4}', "(i)"),
+	('\u{24A5}', "(j)"),
+	('\u{24A6}', "(k)"),
+	('\u{24A7}', "(l)"),
+	('\u{24A8}', "(m)"),
+	('\u{24A9}', "(n)"),
+	('\u{24AA}', "(o)"),
+	('\u{24AB}', "(p)"),
+	('\u{24AC}', "(q)"),
+	('\u{24AD}', "(r)"),
+	('\u{24AE}', "(s)"),
+	('\u{24AF}', "(t)"),
+	('\u{24B0}', "(u)"),
+	('\u{24B1}', "(v)"),
+	('\u{24B2}', "(w)"),
+	('\u{24B3}', "(x)"),
+	('\u{24B4}', "(y)"),
+	('\u{24B5}', "(z)"),
+	('\u{24B6}', "a"),
+	('\u{24B7}', "b"),
+	('\u{24B8}', "c"),
+	('\u{24B9}', "d"),
+	('\u{24BA}', "e"),
+	('\u{24BB}', "f"),
+	('\u{24BC}', "g"),
+	('\u{24BD}', "h"),
+	('\u{24BE}', "i"),
+	('\u{24BF}', "j"),
+	('\u{24C0}', "k"),
+	('\u{24C1}', "l"),
+	('\u{24C2}', "m"),
+	('\u{24C3}', "n"),
+	('\u{24C4}', "o"),
+	('\u{24C5}', "p"),
+	('\u{24C6}', "q"),
+	('\u{24C7}', "r"),
+	('\u{24C8}', "s"),
+	('\u{24C9}', "t"),
+	('\u{24CA}', "u"),
+	('\u{24CB}', "v"),
+	('\u{24CC}', "w"),
+	('\u{24CD}', "x"),
+	('\u{24CE}', "y"),
+	('\u{24CF}', "z"),
+	('\u{24D0}', "a"),
+	('\u{24D1}', "b"),
+	('\u{24D2}', "c"),
+	('\u{24D3}', "d"),
+	('\u{24D4}', "e"),
+	('\u{24D5}', "f"),
+	('\u{24D6}', "g"),
+	('\u{24D7}', "h"),
+	('\u{24D8}', "i"),
+	('\u{24D9}', "j"),
+	('\u{24DA}', "k"),
+	('\u{24DB}', "l"),
+	('\u{24DC}', "m"),
+	('\u{24DD}', "n"),
+	('\u{24DE}', "o"),
+	('\u{24DF}', "p"),
+	('\u{24E0}', "q"),
+	('\u{24E1}', "r"),
+	('\u{24E2}', "s"),
+	('\u{24E3}', "t"),
+	('\u{24E4}', "u"),
+	('\u{24E5}', "v"),
+	('\u{24E6}', "w"),
+	('\u{24E7}', "x"),
+	('\u{24E8}', "y"),
+	('\u{24E9}', "z"),
+	('\u{24EA}', "0"),
+	('\u{2A74}', "::="),
+	('\u{2A75}', "=="),
+	('\u{2A76}', "==="),
+	('\u{2C7C}', "j"),
+	('\u{2C7D}', "v"),
+	('\u{3000}', " "),
+	('\u{3002}', "."),
+	('\u{3250}', "pte"),
+	('\u{3251}', "21"),
+	('\u{3252}', "22"),
+	('\u{3253}', "23"),
+	('\u{3254}', "24"),
+	('\u{3255}', "25"),
+	('\u{3256}', "26"),
+	('\u{3257}', "27"),
+	('\u{3258}', "28"),
+	('\u{3259}', "29"),
+	('\u{325A}', "30"),
+	('\u{325B}', "31"),
+	('\u{325C}', "32"),
+	('\u{325D}', "33"),
+	('\u{325E}', "34"),
+	('\u{325F}', "35"),
+	('\u{32B1}', "36"),
+	('\u{32B2}', "37"),
+	('\u{32B3}', "38"),
+	('\u{32B4}', "39"),
+	('\u{32B5}', "40"),
+	('\u{32B6}', "41"),
+	('\u{32B7}', "42"),
+	('\u{32B8}', "43"),
+	('\u{32B9}', "44"),
+	('\u{32BA}', "45"),
+	('\u{32BB}', "46"),
+	('\u{32BC}', "47"),
+	('\u{32BD}', "48"),
+	('\u{32BE}', "49"),
+	('\u{32BF}', "50"),
+	('\u{32CC}', "hg"),
+	('\u{32CD}', "erg"),
+	('\u{32CE}', "ev"),
+	('\u{32CF}', "ltd"),
+	('\u{3371}', "hpa"),
+	('\u{3372}', "da"),
+	('\u{3373}', "au"),
+	('\u{3374}', "bar"),
+	('\u{3375}', "ov"),
+	('\u{3376}', "pc"),
+	('\u{3377}', "dm"),
+	('\u{3378}', "dm2"),
+	('\u{3379}', "dm3"),
+	('\u{337A}', "iu"),
+	('\u{3380}', "pa"),
+	('\u{3381}', "na"),
+	('\u{3383}', "ma"),
+	('\u{3384}', "ka"),
+	('\u{3385}', "kb"),
+	('\u{3386}', "mb"),
+	('\u{3387}', "gb"),
+	('\u{3388}', "cal"),
+	('\u{3389}', "kcal"),
+	('\u{338A}', "pf"),
+	('\u{338B}', "nf"),
+	('\u{338E}', "mg"),
+	('\u{338F}', "kg"),
+	('\u{3390}', "hz"),
+	('\u{3391}', "khz"),
+	('\u{3392}', "mhz"),
+	('\u{3393}', "ghz"),
+	('\u{3394}', "thz"),
+	('\u{3396}', "ml"),
+	('\u{3397}', "dl"),
+	('\u{3398}', "kl"),
+	('\u{3399}', "fm"),
+	('\u{339A}', "nm"),
+	('\u{339C}', "mm"),
+	('\u{339D}', "cm"),
+	('\u{339E}', "km"),
+	('\u{339F}', "mm2"),
+	('\u{33A0}', "cm2"),
+	('\u{33A1}', "m2"),
+	('\u{33A2}', "km2"),
+	('\u{33A3}', "mm3"),
+	('\u{33A4}', "cm3"),
+	('\u{33A5}', "m3"),
+	('\u{33A6}', "km3"),
+	('\u{33A9}', "pa"),
+	('\u{33AA}', "kpa"),
+	('\u{33AB}', "mpa"),
+	('\u{33AC}', "gpa"),
+	('\u{33AD}', "rad"),
+	('\u{33B0}', "ps"),
+	('\u{33B1}', "ns"),
+	('\u{33B3}', "ms"),
+	('\u{33B4}', "pv"),
+	('\u{33B5}', "nv"),
+	('\u{33B7}', "mv"),
+	('\u{33B8}', "kv"),
+	('\u{33B9}', "mv"),
+	('\u{33BA}', "pw"),
+	('\u{33BB}', "nw"),
+	('\u{33BD}', "mw"),
+	('\u{33BE}', "kw"),
+	('\u{33BF}', "mw"),
+	('\u{33C2}', "a.m."),
+	('\u{33C3}', "bq"),
+	('\u{33C4}', "cc"),
+	('\u{33C5}', "cd"),
+	('\u{33C7}', "co."),
+	('\u{33C8}', "db"),
+	('\u{33C9}', "gy"),
+	('\u{33CA}', "ha"),
+	('\u{33CB}', "hp"),
+	('\u{33CC}', "in"),
+	('\u{33CD}', "kk"),
+	('\u{33CE}', "km"),
+	('\u{33CF}', "kt"),
+	('\u{33D0}', "lm"),
+	('\u{33D1}', "ln"),
+	('\u{33D2}', "log"),
+	('\u{33D3}', "lx"),
+	('\u{33D4}', "mb"),
+	('\u{33D5}', "mil"),
+	('\u{33D6}', "mol"),
+	('\u{33D7}', "ph"),
+	('\u{33D8}', "p.m."),
+	('\u{33D9}', "ppm"),
+	('\u{33DA}', "pr"),
+	('\u{33DB}', "sr"),
+	('\u{33DC}', "sv"),
+	('\u{33DD}', "wb"),
+	('\u{33FF}', "gal"),
+	('\u{A7F2}', "c"),
+	('\u{A7F3}', "f"),
+	('\u{A7F4}', "q"),
+	('\u{FB00}', "ff"),
+	('\u{FB01}', "fi"),
+	('\u{FB02}', "fl"),
+	('\u{FB03}', "ffi"),
+	('\u{FB04}', "ffl"),
+	('\u{FB05}', "st"),
+	('\u{FB06}', "st"),
+	('\u{FB29}', "+"),
+	('\u{FE10}', ","),
+	('\u{FE13}', ":"),
+	('\u{FE14}', ";"),
+	('\u{FE15}', "!"),
+	('\u{FE16}', "?"),
+	('\u{FE19}', "..."),
+	('\u{FE30}', ".."),
+	('\u{FE33}', "_"),
+	('\u{FE34}', "_"),
+	('\u{FE35}', "("),
+	('\u{FE36}', ")"),
+	('\u{FE37}', "{"),
+	('\u{FE38}', "}"),
+	('\u{FE47}', "["),
+	('\u{FE48}', "]"),
+	('\u{FE4D}', "_"),
+	('\u{FE4E}', "_"),
+	('\u{FE4F}', "_"),
+	('\u{FE50}', ","),
+	('\u{FE52}', "."),
+	('\u{FE54}', ";"),
+	('\u{FE55}', ":"),
+	('\u{FE56}', "?"),
+	('\u{FE57}', "!"),
+	('\u{FE59}', "("),
+	('\u{FE5A}', ")"),
+	('\u{FE5B}', "{"),
+	('\u{FE5C}', "}"),
+	('\u{FE5F}', "#"),
+	('\u{FE60}', "&"),
+	('\u{FE61}', "*"),
+	('\u{FE62}', "+"),
+	('\u{FE63}', "-"),
+	('\u{FE64}', "<"),
+	('\u{FE65}', ">"),
+	('\u{FE66}', "="),
+	('\u{FE68}', "\\"),
+	('\u{FE69}', "$"),
+	('\u{FE6A}', "%"),
+	('\u{FE6B}', "@"),
+	('\u{FF01}', "!"),
+	('\u{FF02}', "\""),
+	('\u{FF03}', "#"),
+	('\u{FF04}', "$"),
+	('\u{FF05}', "%"),
+	('\u{FF06}', "&"),
+	('\u{FF07}', "'"),
+	('\u{FF08}', "("),
+	('\u{FF09}', ")"),
+	('\u{FF0A}', "*"),
+	('\u{FF0B}', "+"),
+	('\u{FF0C}', ","),
+	('\u{FF0D}', "-"),
+	('\u{FF0E}', "."),
+	('\u{FF0F}', "/"),
+	('\u{FF10}', "0"),
+	('\u{FF11}', "1"),
+	('\u{FF12}', "2"),
+	('\u{FF13}', "3"),
+	('\u{FF14}', "4"),
+	('\u{FF15}', "5"),
+	('\u{FF16}', "6"),
+	('\u{FF17}', "7"),
+	('\u{FF18}', "8"),
+	('\u{FF19}', "9"),
+	('\u{FF1A}', ":"),
+	('\u{FF1B}', ";"),
+	('\u{FF1C}', "<"),
+	('\u{FF1D}', "="),
+	('\u{FF1E}', ">"),
+	('\u{FF1F}', "?"),
+	('\u{FF20}', "@"),
+	('\u{FF21}', "a"),
+	('\u{FF22}', "b"),
+	('\u{FF23}', "c"),
+	('\u{FF24}', "d"),
+	('\u{FF25}', "e"),
+	('\u{FF26}', "f"),
+	('\u{FF27}', "g"),
+	('\u{FF28}', "h"),
+	('\u{FF29}', "i"),
+	('\u{FF2A}', "j"),
+	('\u{FF2B}', "k"),
+	('\u{FF2C}', "l"),
+	('\u{FF2D}', "m"),
+	('\u{FF2E}', "n"),
+	('\u{FF2F}', "o"),
+	('\u{FF30}', "p"),
+	('\u{FF31}', "q"),
+	('\u{FF32}', "r"),
+	('\u{FF33}', "s"),
+	('\u{FF34}', "t"),
+	('\u{FF35}', "u"),
+	('\u{FF36}', "v"),
+	('\u{FF37}', "w"),
+	('\u{FF38}', "x"),
+	('\u{FF39}', "y"),
+	('\u{FF3A}', "z"),
+	('\u{FF3B}', "["),
+	('\u{FF3C}', "\\"),
+	('\u{FF3D}', "]"),
+	('\u{FF3E}', "^"),
+	('\u{FF3F}', "_"),
+	('\u{FF40}', "`"),
+	('\u{FF41}', "a"),
+	('\u{FF42}', "b"),
+	('\u{FF43}', "c"),
+	('\u{FF44}', "d"),
+	('\u{FF45}', "e"),
+	('\u{FF46}', "f"),
+	('\u{FF47}', "g"),
+	('\u{FF48}', "h"),
+	('\u{FF49}', "i"),
+	('\u{FF4A}', "j"),
+	('\u{FF4B}', "k"),
+	('\u{FF4C}', "l"),
+	('\u{FF4D}', "m"),
+	('\u{FF4E}', "n"),
+	('\u{FF4F}', "o"),
+	('\u{FF50}', "p"),
+	('\u{FF51}', "q"),
+	('\u{FF52}', "r"),
+	('\u{FF53}', "s"),
+	('\u{FF54}', "t"),
+	('\u{FF55}', "u"),
+	('\u{FF56}', "v"),
+	('\u{FF57}', "w"),
+	('\u{FF58}', "x"),
+	('\u{FF59}', "y"),
+	('\u{FF5A}', "z"),
+	('\u{FF5B}', "{"),
+	('\u{FF5C}', "|"),
+	('\u{FF5D}', "}"),
+	('\u{FF5E}', "~"),
+	('\u{FF61}', "."),
+	('\u{107A5}', "q"),
+	('\u{1CCD6}', "a"),
+	('\u{1CCD7}', "b"),
+	('\u{1CCD8}', "c"),
+	('\u{1CCD9}', "d"),
+	('\u{1CCDA}', "e"),
+	('\u{1CCDB}', "f"),
+	('\u{1CCDC}', "g"),
+	('\u{1CCDD}', "h"),
+	('\u{1CCDE}', "i"),
+	('\u{1CCDF}', "j"),
+	('\u{1CCE0}', "k"),
+	('\u{1CCE1}', "l"),
+	('\u{1CCE2}', "m"),
+	('\u{1CCE3}', "n"),
+	('\u{1CCE4}', "o"),
+	('\u{1CCE5}', "p"),
+	('\u{1CCE6}', "q"),
+	('\u{1CCE7}', "r"),
+	('\u{1CCE8}', "s"),
+	('\u{1CCE9}', "t"),
+	('\u{1CCEA}', "u"),
+	('\u{1CCEB}', "v"),
+	('\u{1CCEC}', "w"),
+	('\u{1CCED}', "x"),
+	('\u{1CCEE}', "y"),
+	('\u{1CCEF}', "z"),
+	('\u{1CCF0}', "0"),
+	('\u{1CCF1}', "1"),
+	('\u{1CCF2}', "2"),
+	('\u{1CCF3}', "3"),
+	('\u{1CCF4}', "4"),
+	('\u{1CCF5}', "5"),
+	('\u{1CCF6}', "6"),
+	('\u{1CCF7}', "7"),
+	('\u{1CCF8}', "8"),
+	('\u{1CCF9}', "9"),
+	('\u{1D400}', "a"),
+	('\u{1D401}', "b"),
+	('\u{1D402}', "c"),
+	('\u{1D403}', "d"),
+	('\u{1D404}', "e"),
+	('\u{1D405}', "f"),
+	('\u{1D406}', "g"),
+	('\u{1D407}', "h"),
+	('\u{1D408}', "i"),
+	('\u{1D409}', "j"),
+	('\u{1D40A}', "k"),
+	('\u{1D40B}', "l"),
+	('\u{1D40C}', "m"),
+	('\u{1D40D}', "n"),
+	('\u{1D40E}', "o"),
+	('\u{1D40F}', "p"),
+	('\u{1D410}', "q"),
+	('\u{1D411}', "r"),
+	('\u{1D412}', "s"),
+	('\u{1D413}', "t"),
+	('\u{1D414}', "u"),
+	('\u{1D415}', "v"),
+	('\u{1D416}', "w"),
+	('\u{1D417}', "x"),
+	('\u{1D418}', "y"),
+	('\u{1D419}', "z"),
+	('\u{1D41A}', "a"),
+	('\u{1D41B}', "b"),
+	('\u{1D41C}', "c"),
+	('\u{1D41D}', "d"),
+	('\u{1D41E}', "e"),
+	('\u{1D41F}', "f"),
+	('\u{1D420}', "g"),
+	('\u{1D421}', "h"),
+	('\u{1D422}', "i"),
+	('\u{1D423}', "j"),
+	('\u{1D424}', "k"),
+	('\u{1D425}', "l"),
+	('\u{1D426}', "m"),
+	('\u{1D427}', "n"),
+	('\u{1D428}', "o"),
+	('\u{1D429}', "p"),
+	('\u{1D42A}', "q"),
+	('\u{1D42B}', "r"),
+	('\u{1D42C}', "s"),
+	('\u{1D42D}', "t"),
+	('\u{1D42E}', "u"),
+	('\u{1D42F}', "v"),
+	('\u{1D430}', "w"),
+	('\u{1D431}', "x"),
+	('\u{1D432}', "y"),
+	('\u{1D433}', "z"),
+	('\u{1D434}', "a"),
+	('\u{1D435}', "b"),
+	('\u{1D436}', "c"),
+	('\u{1D437}', "d"),
+	('\u{1D438}', "e"),
+	('\u{1D439}', "f"),
+	('\u{1D43A}', "g"),
+	('\u{1D43B}', "h"),
+	('\u{1D43C}', "i"),
+	('\u{1D43D}', "j"),
+	('\u{1D43E}', "k"),
+	('\u{1D43F}', "l"),
+	('\u{1D440}', "m"),
+	('\u{1D441}', "n"),
+	('\u{1D442}', "o"),
+	('\u{1D443}', "p"),
+	('\u{1D444}', "q"),
+	('\u{1D445}', "r"),
+	('\u{1D446}', "s"),
+	('\u{1D447}', "t"),
+	('\u{1D448}', "u"),
+	('\u{1D449}', "v"),
+	('\u{1D44A}', "w"),
+	('\u{1D44B}', "x"),
+	('\u{1D44C}', "y"),
+	('\u{1D44D}', "z"),
+	('\u{1D44E}', "a"),
+	('\u{1D44F}', "b"),
+	('\u{1D450}', "c"),
+	('\u{1D451}', "d"),
+	('\u{1D452}', "e"),
+	('\u{1D453}', "f"),
+	('\u{1D454}', "g"),
+	('\u{1D456}', "i"),
+	('\u{1D457}', "j"),
+	('\u{1D458}', "k"),
+	('\u{1D459}', "l"),
+	('\u{1D45A}', "m"),
+	('\u{1D45B}', "n"),
+	('\u{1D45C}', "o"),
+	('\u{1D45D}', "p"),
+	('\u{1D45E}', "q"),
+	('\u{1D45F}', "r"),
+	('\u{1D460}', "s"),
+	('\u{1D461}', "t"),
+	('\u{1D462}', "u"),
+	('\u{1D463}', "v"),
+	('\u{1D464}', "w"),
+	('\u{1D465}', "x"),
+	('\u{1D466}', "y"),
+	('\u{1D467}', "z"),
+	('\u{1D468}', "a"),
+	('\u{1D469}', "b"),
+	('\u{1D46A}', "c"),
+	('\u{1D46B}', "d"),
+	('\u{1D46C}', "e"),
+	('\u{1D46D}', "f"),
+	('\u{1D46E}', "g"),
+	('\u{1D46F}', "h"),
+	('\u{1D470}', "i"),
+	('\u{1D471}', "j"),
+	('\u{1D472}', "k"),
+	('\u{1D473}', "l"),
+	('\u{1D474}', "m"),
+	('\u{1D475}', "n"),
+	('\u{1D476}', "o"),
+	('\u{1D477}', "p"),
+	('\u{1D478}', "q"),
+	('\u{1D479}', "r"),
+	('\u{1D47A}', "s"),
+	('\u{1D47B}', "t"),
+	('\u{1D47C}', "u"),
+	('\u{1D47D}', "v"),
+	('\u{1D47E}', "w"),
+	('\u{1D47F}', "x"),
+	('\u{1D480}', "y"),
+	('\u{1D481}', "z"),
+	('\u{1D482}', "a"),
+	('\u{1D483}', "b"),
+	('\u{1D484}', "c"),
+	('\u{1D485}', "d"),
+	('\u{1D486}', "e"),
+	('\u{1D487}', "f"),
+	('\u{1D488}', "g"),
+	('\u{1D489}', "h"),
+	('\u{1D48A}', "i"),
+	('\u{1D48B}', "j"),
+	('\u{1D48C}', "k"),
+	('\u{1D48D}', "l"),
+	('\u{1D48E}', "m"),
+	('\u{1D48F}', "n"),
+	('\u{1D490}', "o"),
+	('\u{1D491}', "p"),
+	('\u{1D492}', "q"),
+	('\u{1D493}', "r"),
+	('\u{1D494}', "s"),
+	('\u{1D495}', "t"),
+	('\u{1D496}', "u"),
+	('\u{1D497}', "v"),
+	('\u{1D498}', "w"),
+	('\u{1D499}', "x"),
+	('\u{1D49A}', "y"),
+	('\u{1D49B}', "z"),
+	('\u{1D49C}', "a"),
+	('\u{1D49E}', "c"),
+	('\u{1D49F}', "d"),
+	('\u{1D4A2}', "g"),
+	('\u{1D4A5}', "j"),
+	('\u{1D4A6}', "k"),
+	('\u{1D4A9}', "n"),
+	('\u{1D4AA}', "o"),
+	('\u{1D4AB}', "p"),
+	('\u{1D4AC}', "q"),
+	('\u{1D4AE}', "s"),
+	('\u{1D4AF}', "t"),
+	('\u{1D4B0}', "u"),
+	('\u{1D4B1}', "v"),
+	('\u{1D4B2}', "w"),
+	('\u{1D4B3}', "x"),
+	('\u{1D4B4}', "y"),
+	('\u{1D4B5}', "z"),
+	('\u{1D4B6}', "a"),
+	('\u{1D4B7}', "b"),
+	('\u{1D4B8}', "c"),
+	('\u{1D4B9}', "d"),
+	('\u{1D4BB}', "f"),
+	('\u{1D4BD}', "h"),
+	('\u{1D4BE}', "i"),
+	('\u{1D4BF}', "j"),
+	('\u{1D4C0}', "k"),
+	('\u{1D4C1}', "l"),
+	('\u{1D4C2}', "m"),
+	('\u{1D4C3}', "n"),
+	('\u{1D4C5}', "p"),
+	('\u{1D4C6}', "q"),
+	('\u{1D4C7}', "r"),
+	('\u{1D4C8}', "s"),
+	('\u{1D4C9}', "t"),
+	('\u{1D4CA}', "u"),
+	('\u{1D4CB}', "v"),
+	('\u{1D4CC}', "w"),
+	('\u{1D4CD}', "x"),
+	('\u{1D4CE}', "y"),
+	('\u{1D4CF}', "z"),
+	('\u{1D4D0}', "a"),
+	('\u{1D4D1}', "b"),
+	('\u{1D4D2}', "c"),
+	('\u{1D4D3}', "d"),
+	('\u{1D4D4}', "e"),
+	('\u{1D4D5}', "f"),
+	('\u{1D4D6}', "g"),
+	('\u{1D4D7}', "h"),
+	('\u{1D4D8}', "i"),
+	('\u{1D4D9}', "j"),
+	('\u{1D4DA}', "k"),
+	('\u{1D4DB}', "l"),
+	('\u{1D4DC}', "m"),
+	('\u{1D4DD}', "n"),
+	('\u{1D4DE}', "o"),
+	('\u{1D4DF}', "p"),
+	('\u{1D4E0}', "q"),
+	('\u{1D4E1}', "r"),
+	('\u{1D4E2}', "s"),
+	('\u{1D4E3}', "t"),
+	('\u{1D4E4}', "u"),
+	('\u{1D4E5}', "v"),
+	('\u{1D4E6}', "w"),
+	('\u{1D4E7}', "x"),
+	('\u{1D4E8}', "y"),
+	('\u{1D4E9}', "z"),
+	('\u{1D4EA}', "a"),
+	('\u{1D4EB}', "b"),
+	('\u{1D4EC}', "c"),
+	('\u{1D4ED}', "d"),
+	('\u{1D4EE}', "e"),
+	('\u{1D4EF}', "f"),
+	('\u{1D4F0}', "g"),
+	('\u{1D4F1}', "h"),
+	('\u{1D4F2}', "i"),
+	('\u{1D4F3}', "j"),
+	('\u{1D4F4}', "k"),
+	('\u{1D4F5}', "l"),
+	('\u{1D4F6}', "m"),
+	('\u{1D4F7}', "n"),
+	('\u{1D4F8}', "o"),
+	('\u{1D4F9}', "p"),
+	('\u{1D4FA}', "q"),
+	('\u{1D4FB}', "r"),
+	('\u{1D4FC}', "s"),
+	('\u{1D4FD}', "t"),
+	('\u{1D4FE}', "u"),
+	('\u{1D4FF}', "v"),
+	('\u{1D500}', "w"),
+	('\u{1D501}', "x"),
+	('\u{1D502}', "y"),
+	('\u{1D503}', "z"),
+	('\u{1D504}', "a"),
+	('\u{1D505}', "b"),
+	('\u{1D507}', "d"),
+	('\u{1D508}', "e"),
+	('\u{1D509}', "f"),
+	('\u{1D50A}', "g"),
+	('\u{1D50D}', "j"),
+	('\u{1D50E}', "k"),
+	('\u{1D50F}', "l"),
+	('\u{1D510}', "m"),
+	('\u{1D511}', "n"),
+	('\u{1D512}', "o"),
+	('\u{1D513}', "p"),
+	('\u{1D514}', "q"),
+	('\u{1D516}', "s"),
+	('\u{1D517}', "t"),
+	('\u{1D518}', "u"),
+	('\u{1D519}', "v"),
+	('\u{1D51A}', "w"),
+	('\u{1D51B}', "x"),
+	('\u{1D51C}', "y"),
+	('\u{1D51E}', "a"),
+	('\u{1D51F}', "b"),
+	('\u{1D520}', "c"),
+	('\u{1D521}', "d"),
+	('\u{1D522}', "e"),
+	('\u{1D523}', "f"),
+	('\u{1D524}', "g"),
+	('\u{1D525}', "h"),
+	('\u{1D526}', "i"),
+	('\u{1D527}', "j"),
+	('\u{1D528}', "k"),
+	('\u{1D529}', "l"),
+	('\u{1D52A}', "m"),
+	('\u{1D52B}', "n"),
+	('\u{1D52C}', "o"),
+	('\u{1D52D}', "p"),
+	('\u{1D52E}', "q"),
+	('\u{1D52F}', "r"),
+	('\u{1D530}', "s"),
+	('\u{1D531}', "t"),
+	('\u{1D532}', "u"),
+	('\u{1D533}', "v"),
+	('\u{1D534}', "w"),
+	('\u{1D535}', "x"),
+	('\u{1D536}', "y"),
+	('\u{1D537}', "z"),
+	('\u{1D538}', "a"),
+	('\u{1D539}', "b"),
+	('\u{1D53B}', "d"),
+	('\u{1D53C}', "e"),
+	('\u{1D53D}', "f"),
+	('\u{1D53E}', "g"),
+	('\u{1D540}', "i"),
+	('\u{1D541}', "j"),
+	('\u{1D542}', "k"),
+	('\u{1D543}', "l"),
+	('\u{1D544}', "m"),
+	('\u{1D546}', "o"),
+	('\u{1D54A}', "s"),
+	('\u{1D54B}', "t"),
+	('\u{1D54C}', "u"),
+	('\u{1D54D}', "v"),
+	('\u{1D54E}', "w"),
+	('\u{1D54F}', "x"),
+	('\u{1D550}', "y"),
+	('\u{1D552}', "a"),
+	('\u{1D553}', "b"),
+	('\u{1D554}', "c"),
+	('\u{1D555}', "d"),
+	('\u{1D556}', "e"),
+	('\u{1D557}', "f"),
+	('\u{1D558}', "g"),
+	('\u{1D559}', "h"),
+	('\u{1D55A}', "i"),
+	('\u{1D55B}', "j"),
+	('\u{1D55C}', "k"),
+	('\u{1D55D}', "l"),
+	('\u{1D55E}', "m"),
+	('\u{1D55F}', "n"),
+	('\u{1D560}', "o"),
+	('\u{1D561}', "p"),
+	('\u{1D562}', "q"),
+	('\u{1D563}', "r"),
+	('\u{1D564}', "s"),
+	('\u{1D565}', "t"),
+	('\u{1D566}', "u"),
+	('\u{1D567}', "v"),
+	('\u{1D568}', "w"),
+	('\u{1D569}', "x"),
+	('\u{1D56A}', "y"),
+	('\u{1D56B}', "z"),
+	('\u{1D56C}', "a"),
+	('\u{1D56D}', "b"),
+	('\u{1D56E}', "c"),
+	('\u{1D56F}', "d"),
+	('\u{1D570}', "e"),
+	('\u{1D571}', "f"),
+	('\u{1D572}', "g"),
+	('\u{1D573}', "h"),
+	('\u{1D574}', "i"),
+	('\u{1D575}', "j"),
+	('\u{1D576}', "k"),
+	('\u{1D577}', "l"),
+	('\u{1D578}', "m"),
+	('\u{1D579}', "n"),
+	('\u{1D57A}', "o"),
+	('\u{1D57B}', "p"),
+	('\u{1D57C}', "q"),
+	('\u{1D57D}', "r"),
+	('\u{1D57E}', "s"),
+	('\u{1D57F}', "t"),
+	('\u{1D580}', "u"),
+	('\u{1D581}', "v"),
+	('\u{1D582}', "w"),
+	('\u{1D583}', "x"),
+	('\u{1D584}', "y"),
+	('\u{1D585}', "z"),
+	('\u{1D586}', "a"),
+	('\u{1D587}', "b"),
+	('\u{1D588}', "c"),
+	('\u{1D589}', "d"),
+	('\u{1D58A}', "e"),
+	('\u{1D58B}', "f"),
+	('\u{1D58C}', "g"),
+	('\u{1D58D}', "h"),
+	('\u{1D58E}', "i"),
+	('\u{1D58F}', "j"),
+	('\u{1D590}', "k"),
+	('\u{1D591}', "l"),
+	('\u{1D592}', "m"),
+	('\u{1D593}', "n"),
+	('\u{1D594}', "o"),
+	('\u{1D595}', "p"),
+	('\u{1D596}', "q"),
+	('\u{1D597}', "r"),
+	('\u{1D598}', "s"),
+	('\u{1D599}', "t"),
+	('\u{1D59A}', "u"),
+	('\u{1D59B}', "v"),
+	('\u{1D59C}', "w"),
+	('\u{1D59D}', "x"),
+	('\u{1D59E}', "y"),
+	('\u{1D59F}', "z"),
+	('\u{1D5A0}', "a"),
+	('\u{1D5A1}', "b"),
+	('\u{1D5A2}', "c"),
+	('\u{1D5A3}', "d"),
+	('\u{1D5A4}', "e"),
+	('\u{1D5A5}', "f"),
+	('\u{1D5A6}', "g"),
+	('\u{1D5A7}', "h"),
+	('\u{1D5A8}', "i"),
+	('\u{1D5A9}', "j"),
+	('\u{1D5AA}', "k"),
+	('\u{1D5AB}', "l"),
+	('\u{1D5AC}', "m"),
+	('\u{1D5AD}', "n"),
+	('\u{1D5AE}', "o"),
+	('\u{1D5AF}', "p"),
+	('\u{1D5B0}', "q"),
+	('\u{1D5B1}', "r"),
+	('\u{1D5B2}', "s"),
+	('\u{1D5B3}', "t"),
+	('\u{1D5B4}', "u"),
+	('\u{1D5B5}', "v"),
+	('\u{1D5B6}', "w"),
+	('\u{1D5B7}', "x"),
+	('\u{1D5B8}', "y"),
+	('\u{1D5B9}', "z"),
+	('\u{1D5BA}', "a"),
+	('\u{1D5BB}', "b"),
+	('\u{1D5BC}', "c"),
+	('\u{1D5BD}', "d"),
+	('\u{1D5BE}', "e"),
+	('\u{1D5BF}', "f"),
+	('\u{1D5C0}', "g"),
+	('\u{1D5C1}', "h"),
+	('\u{1D5C2}', "i"),
+	('\u{1D5C3}', "j"),
+	('\u{1D5C4}', "k"),
+	('\u{1D5C5}', "l"),
+	('\u{1D5C6}', "m"),
+	('\u{1D5C7}', "n"),
+	('\u{1D5C8}', "o"),
+	('\u{1D5C9}', "p"),
+	('\u{1D5CA}', "q"),
+	('\u{1D5CB}', "r"),
+	('\u{1D5CC}', "s"),
+	('\u{1D5CD}', "t"),
+	('\u{1D5CE}', "u"),
+	('\u{1D5CF}', "v"),
+	('\u{1D5D0}', "w"),
+	('\u{1D5D1}', "x"),
+	('\u{1D5D2}', "y"),
+	('\u{1D5D3}', "z"),
+	('\u{1D5D4}', "a"),
+	('\u{1D5D5}', "b"),
+	('\u{1D5D6}', "c"),
+	('\u{1D5D7}', "d"),
+	('\u{1D5D8}', "e"),
+	('\u{1D5D9}', "f"),
+	('\u{1D5DA}', "g"),
+	('\u{1D5DB}', "h"),
+	('\u{1D5DC}', "i"),
+	('\u{1D5DD}', "j"),
+	('\u{1D5DE}', "k"),
+	('\u{1D5DF}', "l"),
+	('\u{1D5E0}', "m"),
+	('\u{1D5E1}', "n"),
+	('\u{1D5E2}', "o"),
+	('\u{1D5E3}', "p"),
+	('\u{1D5E4}', "q"),
+	('\u{1D5E5}', "r"),
+	('\u{1D5E6}', "s"),
+	('\u{1D5E7}', "t"),
+	('\u{1D5E8}', "u"),
+	('\u{1D5E9}', "v"),
+	('\u{1D5EA}', "w"),
+	('\u{1D5EB}', "x"),
+	('\u{1D5EC}', "y"),
+	('\u{1D5ED}', "z"),
+	('\u{1D5EE}', "a"),
+	('\u{1D5EF}', "b"),
+	('\u{1D5F0}', "c"),
+	('\u{1D5F1}', "d"),
+	('\u{1D5F2}', "e"),
+	('\u{1D5F3}', "f"),
+	('\u{1D5F4}', "g"),
+	('\u{1D5F5}', "h"),
+	('\u{1D5F6}', "i"),
+	('\u{1D5F7}', "j"),
+	('\u{1D5F8}', "k"),
+	('\u{1D5F9}', "l"),
+	('\u{1D5FA}', "m"),
+	('\u{1D5FB}', "n"),
+	('\u{1D5FC}', "o"),
+	('\u{1D5FD}', "p"),
+	('\u{1D5FE}', "q"),
+	('\u{1D5FF}', "r"),
+	('\u{1D600}', "s"),
+	('\u{1D601}', "t"),
+	('\u{1D602}', "u"),
+	('\u{1D603}', "v"),
+	('\u{1D604}', "w"),
+	('\u{1D605}', "x"),
+	('\u{1D606}', "y"),
+	('\u{1D607}', "z"),
+	('\u{1D608}', "a"),
+	('\u{1D609}', "b"),
+	('\u{1D60A}', "c"),
+	('\u{1D60B}', "d"),
+	('\u{1D60C}', "e"),
+	('\u{1D60D}', "f"),
+	('\u{1D60E}', "g"),
+	('\u{1D60F}', "h"),
+	('\u{1D610}', "i"),
+	('\u{1D611}', "j"),
+	('\u{1D612}', "k"),
+	('\u{1D613}', "l"),
+	('\u{1D614}', "m"),
+	('\u{1D615}', "n"),
+	('\u{1D616}', "o"),
+	('\u{1D617}', "p"),
+	('\u{1D618}', "q"),
+	('\u{1D619}', "r"),
+	('\u{1D61A}', "s"),
+	('\u{1D61B}', "t"),
+	('\u{1D61C}', "u"),
+	('\u{1D61D}', "v"),
+	('\u{1D61E}', "w"),
+	('\u{1D61F}', "x"),
+	('\u{1D620}', "y"),
+	('\u{1D621}', "z"),
+	('\u{1D622}', "a"),
+	('\u{1D623}', "b"),
+	('\u{1D624}', "c"),
+	('\u{1D625}', "d"),
+	('\u{1D626}', "e"),
+	('\u{1D627}', "f"),
+	('\u{1D628}', "g"),
+	('\u{1D629}', "h"),
+	('\u{1D62A}', "i"),
+	('\u{1D62B}', "j"),
+	('\u{1D62C}', "k"),
+	('\u{1D62D}', "l"),
+	('\u{1D62E}', "m"),
+	('\u{1D62F}', "n"),
+	('\u{1D630}', "o"),
+	('\u{1D631}', "p"),
+	('\u{1D632}', "q"),
+	('\u{1D633}', "r"),
+	('\u{1D634}', "s"),
+	('\u{1D635}', "t"),
+	('\u{1D636}', "u"),
+	('\u{1D637}', "v"),
+	('\u{1D638}', "w"),
+	('\u{1D639}', "x"),
+	('\u{1D63A}', "y"),
+	('\u{1D63B}', "z"),
+	('\u{1D63C}', "a"),
+	('\u{1D63D}', "b"),
+	('\u{1D63E}', "c"),
+	('\u{1D63F}', "d"),
+	('\u{1D640}', "e"),
+	('\u{1D641}', "f"),
+	('\u{1D642}', "g"),
+	('\u{1D643}', "h"),
+	('\u{1D644}', "i"),
+	('\u{1D645}', "j"),
+	('\u{1D646}', "k"),
+	('\u{1D647}', "l"),
+	('\u{1D648}', "m"),
+	('\u{1D649}', "n"),
+	('\u{1D64A}', "o"),
+	('\u{1D64B}', "p"),
+	('\u{1D64C}', "q"),
+	('\u{1D64D}', "r"),
+	('\u{1D64E}', "s"),
+	('\u{1D64F}', "t"),
+	('\u{1D650}', "u"),
+	('\u{1D651}', "v"),
+	('\u{1D652}', "w"),
+	('\u{1D653}', "x"),
+	('\u{1D654}', "y"),
+	('\u{1D655}', "z"),
+	('\u{1D656}', "a"),
+	('\u{1D657}', "b"),
+	('\u{1D658}', "c"),
+	('\u{1D659}', "d"),
+	('\u{1D65A}', "e"),
+	('\u{1D65B}', "f"),
+	('\u{1D65C}', "g"),
+	('\u{1D65D}', "h"),
+	('\u{1D65E}', "i"),
+	('\u{1D65F}', "j"),
+	('\u{1D660}', "k"),
+	('\u{1D661}', "l"),
+	('\u{1D662}', "m"),
+	('\u{1D663}', "n"),
+	('\u{1D664}', "o"),
+	('\u{1D665}', "p"),
+	('\u{1D666}', "q"),
+	('\u{1D667}', "r"),
+	('\u{1D668}', "s"),
+	('\u{1D669}', "t"),
+	('\u{1D66A}', "u"),
+	('\u{1D66B}', "v"),
+	('\u{1D66C}', "w"),
+	('\u{1D66D}', "x"),
+	('\u{1D66E}', "y"),
+	('\u{1D66F}', "z"),
+	('\u{1D670}', "a"),
+	('\u{1D671}', "b"),
+	('\u{1D672}', "c"),
+	('\u{1D673}', "d"),
+	('\u{1D674}', "e"),
+	('\u{1D675}', "f"),
+	('\u{1D676}', "g"),
+	('\u{1D677}', "h"),
+	('\u{1D678}', "i"),
+	('\u{1D679}', "j"),
+	('\u{1D67A}', "k"),
+	('\u{1D67B}', "l"),
+	('\u{1D67C}', "m"),
+	('\u{1D67D}', "n"),
+	('\u{1D67E}', "o"),
+	('\u{1D67F}', "p"),
+	('\u{1D680}', "q"),
+	('\u{1D681}', "r"),
+	('\u{1D682}', "s"),
+	('\u{1D683}', "t"),
+	('\u{1D684}', "u"),
+	('\u{1D685}', "v"),
+	('\u{1D686}', "w"),
+	('\u{1D687}', "x"),
+	('\u{1D688}', "y"),
+	('\u{1D689}', "z"),
+	('\u{1D68A}', "a"),
+	('\u{1D68B}', "b"),
+	('\u{1D68C}', "c"),
+	('\u{1D68D}', "d"),
+	('\u{1D68E}', "e"),
+	('\u{1D68F}', "f"),
+	('\u{1D690}', "g"),
+	('\u{1D691}', "h"),
+	('\u{1D692}', "i"),
+	('\u{1D693}', "j"),
+	('\u{1D694}', "k"),
+	('\u{1D695}', "l"),
+	('\u{1D696}', "m"),
+	('\u{1D697}', "n"),
+	('\u{1D698}', "o"),
+	('\u{1D699}', "p"),
+	('\u{1D69A}', "q"),
+	('\u{1D69B}', "r"),
+	('\u{1D69C}', "s"),
+	('\u{1D69D}', "t"),
+	('\u{1D69E}', "u"),
+	('\u{1D69F}', "v"),
+	('\u{1D6A0}', "w"),
+	('\u{1D6A1}', "x"),
+	('\u{1D6A2}', "y"),
+	('\u{1D6A3}', "z"),
+	('\u{1D7CE}', "0"),
+	('\u{1D7CF}', "1"),
+	('\u{1D7D0}', "2"),
+	('\u{1D7D1}', "3"),
+	('\u{1D7D2}', "4"),
+	('\u{1D7D3}', "5"),
+	('\u{1D7D4}', "6"),
+	('\u{1D7D5}', "7"),
+	('\u{1D7D6}', "8"),
+	('\u{1D7D7}', "9"),
+	('\u{1D7D8}', "0"),
+	('\u{1D7D9}', "1"),
+	('\u{1D7DA}', "2"),
+	('\u{1D7DB}', "3"),
+	('\u{1D7DC}', "4"),
+	('\u{1D7DD}', "5"),
+	('\u{1D7DE}', "6"),
+	('\u{1D7DF}', "7"),
+	('\u{1D7E0}', "8"),
+	('\u{1D7E1}', "9"),
+	('\u{1D7E2}', "0"),
+	('\u{1D7E3}', "1"),
+	('\u{1D7E4}', "2"),
+	('\u{1D7E5}', "3"),
+	('\u{1D7E6}', "4"),
+	('\u{1D7E7}', "5"),
+	('\u{1D7E8}', "6"),
+	('\u{1D7E9}', "7"),
+	('\u{1D7EA}', "8"),
+	('\u{1D7EB}', "9"),
+	('\u{1D7EC}', "0"),
+	('\u{1D7ED}', "1"),
+	('\u{1D7EE}', "2"),
+	('\u{1D7EF}', "3"),
+	('\u{1D7F0}', "4"),
+	('\u{1D7F1}', "5"),
+	('\u{1D7F2}', "6"),
+	('\u{1D7F3}', "7"),
+	('\u{1D7F4}', "8"),
+	('\u{1D7F5}', "9"),
+	('\u{1D7F6}', "0"),
+	('\u{1D7F7}', "1"),
+	('\u{1D7F8}', "2"),
+	('\u{1D7F9}', "3"),
+	('\u{1D7FA}', "4"),
+	('\u{1D7FB}', "5"),
+	('\u{1D7FC}', "6"),
+	('\u{1D7FD}', "7"),
+	('\u{1D7FE}', "8"),
+	('\u{1D7FF}', "9"),
+	('\u{1F100}', "0."),
+	('\u{1F101}', "0,"),
+	('\u{1F102}', "1,"),
+	('\u{1F103}', "2,"),
+	('\u{1F104}', "3,"),
+	('\u{1F105}', "4,"),
+	('\u{1F106}', "5,"),
+	('\u{1F107}', "6,"),
+	('\u{1F108}', "7,"),
+	('\u{1F109}', "8,"),
+	('\u{1F10A}', "9,"),
+	('\u{1F110}', "(a)"),
+	('\u{1F111}', "(b)"),
+	('\u{1F112}', "(c)"),
+	('\u{1F113}', "(d)"),
+	('\u{1F114}', "(e)"),
+	('\u{1F115}', "(f)"),
+	('\u{1F116}', "(g)"),
+	('\u{1F117}', "(h)"),
+	('\u{1F118}', "(i)"),
+	('\u{1F119}', "(j)"),
+	('\u{1F11A}', "(k)"),
+	('\u{1F11B}', "(l)"),
+	('\u{1F11C}', "(m)"),
+	('\u{1F11D}', "(n)"),
+	('\u{1F11E}', "(o)"),
+	('\u{1F11F}', "(p)"),
+	('\u{1F120}', "(q)"),
+	('\u{1F121}', "(r)"),
+	('\u{1F122}', "(s)"),
+	('\u{1F123}', "(t)"),
+	('\u{1F124}', "(u)"),
+	('\u{1F125}', "(v)"),
+	('\u{1F126}', "(w)"),
+	('\u{1F127}', "(x)"),
+	('\u{1F128}', "(y)"),
+	('\u{1F129}', "(z)"),
+	('\u{1F12B}', "c"),
+	('\u{1F12C}', "r"),
+	('\u{1F12D}', "cd"),
+	('\u{1F12E}', "wz"),
+	('\u{1F130}', "a"),
+	('\u{1F131}', "b"),
+	('\u{1F132}', "c"),
+	('\u{1F133}', "d"),
+	('\u{1F134}', "e"),
+	('\u{1F135}', "f"),
+	('\u{1F136}', "g"),
+	('\u{1F137}', "h"),
+	('\u{1F138}', "i"),
+	('\u{1F139}', "j"),
+	('\u{1F13A}', "k"),
+	('\u{1F13B}', "l"),
+	('\u{1F13C}', "m"),
+	('\u{1F13D}', "n"),
+	('\u{1F13E}', "o"),
+	('\u{1F13F}', "p"),
+	('\u{1F140}', "q"),
+	('\u{1F141}', "r"),
+	('\u{1F142}', "s"),
+	('\u{1F143}', "t"),
+	('\u{1F144}', "u"),
+	('\u{1F145}', "v"),
+	('\u{1F146}', "w"),
+	('\u{1F147}', "x"),
+	('\u{1F148}', "y"),
+	('\u{1F149}', "z"),
+	('\u{1F14A}', "hv"),
+	('\u{1F14B}', "mv"),
+	('\u{1F14C}', "sd"),
+	('\u{1F14D}', "ss"),
+	('\u{1F14E}', "ppv"),
+	('\u{1F14F}', "wc"),
+	('\u{1F16A}', "mc"),
+	('\u{1F16B}', "md"),
+	('\u{1F16C}', "mr"),
+	('\u{1F190}', "dj"),
+	('\u{1FBF0}', "0"),
+	('\u{1FBF1}', "1"),
+	('\u{1FBF2}', "2"),
+	('\u{1FBF3}', "3"),
+	('\u{1FBF4}', "4"),
+	('\u{1FBF5}', "5"),
+	('\u{1FBF6}', "6"),
+	('\u{1FBF7}', "7"),
+	('\u{1FBF8}', "8"),
+	('\u{1FBF9}', "9"),
+];
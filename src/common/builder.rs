@@ -0,0 +1,92 @@
+//! URI/IRI builder states.
+//!
+//! A builder ([`UriBuilder`](crate::uri::UriBuilder),
+//! [`IriBuilder`](crate::iri::IriBuilder), etc.) is parameterized by a state
+//! type naming the next component it expects. Each step consumes the
+//! builder and returns it in a later state, so components can only be
+//! given in order, and only once.
+//!
+//! The traits of this module are sealed, and only used to share steps
+//! between states.
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+/// Start of a URI/IRI reference: the scheme is optional.
+#[derive(Debug)]
+pub struct Scheme;
+
+/// After the scheme: the authority, or its user info or host, is next.
+#[derive(Debug)]
+pub struct Authority;
+
+/// After the user info: the host is required.
+#[derive(Debug)]
+pub struct Host;
+
+/// After the host: the port is optional.
+#[derive(Debug)]
+pub struct Port;
+
+/// Path segments.
+#[derive(Debug)]
+pub struct Path;
+
+/// After the path: the query is optional.
+#[derive(Debug)]
+pub struct Query;
+
+/// Query key-value pairs.
+#[derive(Debug)]
+pub struct QueryPairs;
+
+/// After the query: the fragment is optional.
+#[derive(Debug)]
+pub struct Fragment;
+
+/// All the components have been given.
+#[derive(Debug)]
+pub struct Done;
+
+/// States in which the authority, or its user info or host, can be set.
+pub trait AuthorityStep: sealed::Sealed {}
+
+/// States in which the whole path can be set.
+pub trait PathStep: sealed::Sealed {}
+
+/// States in which a path segment can be pushed.
+pub trait SegmentStep: sealed::Sealed {}
+
+/// States in which the whole query can be set.
+pub trait QueryStep: sealed::Sealed {}
+
+/// States in which a query key-value pair can be pushed.
+pub trait QueryPairStep: sealed::Sealed {}
+
+/// States in which the fragment can be set.
+pub trait FragmentStep: sealed::Sealed {}
+
+/// States in which the builder can be completed.
+pub trait Complete: sealed::Sealed {}
+
+macro_rules! states {
+	($($state:ident: $($tr:ident),*;)*) => {
+		$(
+			impl sealed::Sealed for $state {}
+			$(impl $tr for $state {})*
+		)*
+	};
+}
+
+states! {
+	Scheme: AuthorityStep, PathStep, SegmentStep, QueryStep, QueryPairStep, FragmentStep, Complete;
+	Authority: AuthorityStep, PathStep, SegmentStep, QueryStep, QueryPairStep, FragmentStep, Complete;
+	Host: ;
+	Port: PathStep, SegmentStep, QueryStep, QueryPairStep, FragmentStep, Complete;
+	Path: SegmentStep, QueryStep, QueryPairStep, FragmentStep, Complete;
+	Query: QueryStep, QueryPairStep, FragmentStep, Complete;
+	QueryPairs: QueryPairStep, FragmentStep, Complete;
+	Fragment: FragmentStep, Complete;
+	Done: Complete;
+}
@@ -0,0 +1,105 @@
+//! Canonical digests.
+use pct_str::PctStr;
+
+/// 128-bit FNV-1a hasher.
+///
+/// See <http://www.isthe.com/chongo/tech/comp/fnv/>.
+pub(crate) struct Fnv1a128(u128);
+
+impl Fnv1a128 {
+	const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+	const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+	pub fn new() -> Self {
+		Self(Self::OFFSET_BASIS)
+	}
+
+	pub fn write_u8(&mut self, b: u8) {
+		self.0 ^= b as u128;
+		self.0 = self.0.wrapping_mul(Self::PRIME)
+	}
+
+	/// Writes a length, as a 64-bit little-endian integer.
+	pub fn write_len(&mut self, len: usize) {
+		for b in (len as u64).to_le_bytes() {
+			self.write_u8(b)
+		}
+	}
+
+	/// Writes a length-prefixed byte string.
+	pub fn write_bytes(&mut self, len: usize, bytes: impl IntoIterator<Item = u8>) {
+		self.write_len(len);
+		for b in bytes {
+			self.write_u8(b)
+		}
+	}
+
+	/// Writes a percent-decoded, length-prefixed string.
+	pub fn write_pct_str(&mut self, value: &PctStr) {
+		self.write_bytes(value.bytes().count(), value.bytes())
+	}
+
+	/// Writes an optional value: `0x00` if absent, or `0x01` followed by the
+	/// value.
+	pub fn write_option<T>(&mut self, value: Option<T>, f: impl FnOnce(&mut Self, T)) {
+		match value {
+			Some(value) => {
+				self.write_u8(1);
+				f(self, value)
+			}
+			None => self.write_u8(0),
+		}
+	}
+
+	pub fn finish(self) -> u128 {
+		self.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{IriRef, UriRef};
+
+	#[test]
+	fn fnv1a128() {
+		// Reference values from the FNV test suite.
+		let vectors: [(&[u8], u128); 3] = [
+			(b"", 0x6c62272e07bb014262b821756295c58d),
+			(b"a", 0xd228cb696f1a8caf78912b704e4a8964),
+			(b"foobar", 0x343e1662793c64bf6f0d3597ba446f18),
+		];
+
+		for (input, expected) in vectors {
+			let mut hasher = Fnv1a128::new();
+			for b in input {
+				hasher.write_u8(*b)
+			}
+			assert_eq!(hasher.finish(), expected)
+		}
+	}
+
+	/// Pinned digests. These must never change.
+	#[test]
+	fn canonical_digest() {
+		let mut count = 0;
+		for line in include_str!("digest_vectors.txt").lines() {
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let (expected, input) = line.split_once(' ').unwrap();
+			let expected = u128::from_str_radix(expected, 16).unwrap();
+			let iri_ref = IriRef::new(input).unwrap();
+			assert_eq!(iri_ref.canonical_digest(), expected, "{input}");
+
+			if let Ok(uri_ref) = UriRef::new(input) {
+				assert_eq!(uri_ref.canonical_digest(), expected, "{input}")
+			}
+
+			count += 1
+		}
+
+		assert_eq!(count, 33)
+	}
+}
@@ -0,0 +1,152 @@
+use core::{
+	borrow::Borrow,
+	cmp::Ordering,
+	fmt,
+	hash::{Hash, Hasher},
+	ops::Deref,
+};
+
+use super::Scheme;
+
+/// Byte-exact comparison wrapper.
+///
+/// URIs, IRIs and their components compare modulo normalization: scheme
+/// case, percent-encoding, dot segments, etc. This wrapper compares, orders
+/// and hashes the wrapped value as its raw string instead, which is cheaper
+/// and usually what caches want.
+///
+/// Since the hash of an `Exact<T>` is the hash of its string, hash maps and
+/// sets keyed by `Exact<T>` can be queried with a `&str`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use iref::{Exact, Iri};
+///
+/// let a = Iri::new("https://example.org/a/../b").unwrap();
+/// let b = Iri::new("https://example.org/b").unwrap();
+/// assert_eq!(a, b);
+/// assert_ne!(a.as_exact(), b.as_exact());
+///
+/// let mut set = HashSet::new();
+/// set.insert(Exact(a.to_owned()));
+/// set.insert(Exact(b.to_owned()));
+/// assert_eq!(set.len(), 2);
+/// assert!(set.contains("https://example.org/a/../b"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct Exact<T>(pub T);
+
+impl<T> Exact<T> {
+	/// Returns the wrapped value.
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T: AsRef<str>> Exact<T> {
+	/// Returns the raw string of the wrapped value.
+	pub fn as_str(&self) -> &str {
+		self.0.as_ref()
+	}
+}
+
+impl<T> Deref for Exact<T> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		&self.0
+	}
+}
+
+impl<T: AsRef<str>> AsRef<str> for Exact<T> {
+	fn as_ref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl<T: AsRef<str>> Borrow<str> for Exact<T> {
+	fn borrow(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl<T: AsRef<str>, U: AsRef<str>> PartialEq<Exact<U>> for Exact<T> {
+	fn eq(&self, other: &Exact<U>) -> bool {
+		self.as_str() == other.as_str()
+	}
+}
+
+impl<T: AsRef<str>> Eq for Exact<T> {}
+
+impl<T: AsRef<str>, U: AsRef<str>> PartialOrd<Exact<U>> for Exact<T> {
+	fn partial_cmp(&self, other: &Exact<U>) -> Option<Ordering> {
+		Some(self.as_str().cmp(other.as_str()))
+	}
+}
+
+impl<T: AsRef<str>> Ord for Exact<T> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_str().cmp(other.as_str())
+	}
+}
+
+impl<T: AsRef<str>> Hash for Exact<T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.as_str().hash(state)
+	}
+}
+
+impl<T: fmt::Display> fmt::Display for Exact<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl Scheme {
+	/// Returns a view of this scheme comparing byte-exactly.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::Scheme;
+	///
+	/// assert_eq!(Scheme::new("HTTP").unwrap(), Scheme::HTTP);
+	/// assert_ne!(Scheme::new("HTTP").unwrap().as_exact(), Scheme::HTTP.as_exact());
+	/// ```
+	pub fn as_exact(&self) -> Exact<&Self> {
+		Exact(self)
+	}
+}
+
+/// Owned scheme comparing byte-exactly.
+#[cfg(feature = "std")]
+pub type ExactSchemeBuf = Exact<super::SchemeBuf>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn exact() {
+		let vectors = [
+			("http", "http", true),
+			("http", "HTTP", false),
+			("a+b", "a+b", true),
+			("a", "b", false),
+		];
+
+		for (a, b, expected) in vectors {
+			let a = Scheme::new(a).unwrap();
+			let b = Scheme::new(b).unwrap();
+			assert_eq!(a.as_exact() == b.as_exact(), expected, "{a} {b}");
+			assert_eq!(
+				a.as_exact().cmp(&b.as_exact()),
+				a.as_str().cmp(b.as_str()),
+				"{a} {b}"
+			)
+		}
+	}
+}
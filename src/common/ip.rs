@@ -0,0 +1,363 @@
+use core::fmt;
+
+/// Scope of an IP address.
+///
+/// Scopes follow the IANA IPv4 and IPv6 special-purpose address registries.
+/// IPv6 addresses embedding an IPv4 address (IPv4-mapped, IPv4-compatible,
+/// IPv4-translated, NAT64 and 6to4 addresses) have the scope of the embedded
+/// address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IpScope {
+	/// Unspecified address (`0.0.0.0/8`, `::`).
+	Unspecified,
+
+	/// Loopback address (`127.0.0.0/8`, `::1`).
+	Loopback,
+
+	/// Private network address (`10.0.0.0/8`, `172.16.0.0/12`,
+	/// `192.168.0.0/16`, shared address space `100.64.0.0/10`, unique local
+	/// addresses `fc00::/7`, site-local addresses `fec0::/10` and local-use
+	/// NAT64 addresses `64:ff9b:1::/48`).
+	Private,
+
+	/// Link-local address (`169.254.0.0/16`, `fe80::/10`).
+	LinkLocal,
+
+	/// Multicast address (`224.0.0.0/4`, `ff00::/8`).
+	Multicast,
+
+	/// Documentation address (`192.0.2.0/24`, `198.51.100.0/24`,
+	/// `203.0.113.0/24`, `2001:db8::/32`, `3fff::/20`).
+	Documentation,
+
+	/// Other reserved address, such as the IETF protocol assignments
+	/// (`192.0.0.0/24`, `2001::/23`), benchmarking addresses
+	/// (`198.18.0.0/15`, `2001:2::/48`), the discard prefix (`100::/64`) or
+	/// the future use and broadcast addresses (`240.0.0.0/4`).
+	Reserved,
+
+	/// Globally reachable address.
+	Global,
+}
+
+impl IpScope {
+	/// Returns the scope of the given IPv4 address.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::IpScope;
+	///
+	/// assert_eq!(IpScope::of_ipv4(0x7f000001), IpScope::Loopback);
+	/// assert_eq!(IpScope::of_ipv4(0x08080808), IpScope::Global);
+	/// ```
+	pub const fn of_ipv4(addr: u32) -> Self {
+		const fn within(addr: u32, prefix: u32, len: u32) -> bool {
+			addr >> (32 - len) == prefix >> (32 - len)
+		}
+
+		if within(addr, 0x00000000, 8) {
+			Self::Unspecified
+		} else if within(addr, 0x7f000000, 8) {
+			Self::Loopback
+		} else if within(addr, 0x0a000000, 8)
+			|| within(addr, 0xac100000, 12)
+			|| within(addr, 0xc0a80000, 16)
+			|| within(addr, 0x64400000, 10)
+		{
+			Self::Private
+		} else if within(addr, 0xa9fe0000, 16) {
+			Self::LinkLocal
+		} else if within(addr, 0xe0000000, 4) {
+			Self::Multicast
+		} else if within(addr, 0xc0000200, 24)
+			|| within(addr, 0xc6336400, 24)
+			|| within(addr, 0xcb007100, 24)
+		{
+			Self::Documentation
+		} else if within(addr, 0xc0000000, 24)
+			|| within(addr, 0xc6120000, 15)
+			|| within(addr, 0xf0000000, 4)
+		{
+			Self::Reserved
+		} else {
+			Self::Global
+		}
+	}
+
+	/// Returns the scope of the given IPv6 address.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::IpScope;
+	///
+	/// assert_eq!(IpScope::of_ipv6(1), IpScope::Loopback);
+	/// assert_eq!(IpScope::of_ipv6(0xffff_7f00_0001), IpScope::Loopback);
+	/// assert_eq!(IpScope::of_ipv6(0xfe80 << 112), IpScope::LinkLocal);
+	/// ```
+	pub const fn of_ipv6(addr: u128) -> Self {
+		const fn within(addr: u128, prefix: u128, len: u32) -> bool {
+			addr >> (128 - len) == prefix >> (128 - len)
+		}
+
+		if addr == 0 {
+			Self::Unspecified
+		} else if addr == 1 {
+			Self::Loopback
+		} else if within(addr, 0, 96)
+			|| within(addr, 0xffff << 32, 96)
+			|| within(addr, 0xffff << 48, 96)
+			|| within(addr, 0x0064_ff9b << 96, 96)
+		{
+			// IPv4-compatible, IPv4-mapped, IPv4-translated and NAT64
+			// addresses.
+			Self::of_ipv4(addr as u32)
+		} else if within(addr, 0x0064_ff9b_0001 << 80, 48) {
+			// Local-use NAT64 addresses (RFC 8215), whose embedded IPv4
+			// address depends on the network configuration.
+			Self::Private
+		} else if within(addr, 0x2002 << 112, 16) {
+			// 6to4 addresses.
+			Self::of_ipv4((addr >> 80) as u32)
+		} else if within(addr, 0xfc00 << 112, 7) || within(addr, 0xfec0 << 112, 10) {
+			Self::Private
+		} else if within(addr, 0xfe80 << 112, 10) {
+			Self::LinkLocal
+		} else if within(addr, 0xff00 << 112, 8) {
+			Self::Multicast
+		} else if within(addr, 0x2001_0db8 << 96, 32) || within(addr, 0x3fff << 112, 20) {
+			Self::Documentation
+		} else if within(addr, 0x0100 << 112, 64)
+			|| within(addr, 0x2001_0002 << 96, 48)
+			|| within(addr, 0x2001 << 112, 23)
+		{
+			Self::Reserved
+		} else {
+			Self::Global
+		}
+	}
+
+	/// Checks if this is the global scope.
+	pub const fn is_global(&self) -> bool {
+		matches!(self, Self::Global)
+	}
+}
+
+impl fmt::Display for IpScope {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Unspecified => "unspecified",
+			Self::Loopback => "loopback",
+			Self::Private => "private",
+			Self::LinkLocal => "link-local",
+			Self::Multicast => "multicast",
+			Self::Documentation => "documentation",
+			Self::Reserved => "reserved",
+			Self::Global => "global",
+		})
+	}
+}
+
+/// Parses an IPv4 address the way the WHATWG URL Standard does.
+///
+/// Besides the dotted-decimal form, this accepts addresses with less than
+/// four parts (`127.1`), a trailing dot, and parts in octal (`0177`) or
+/// hexadecimal (`0x7f`).
+///
+/// See <https://url.spec.whatwg.org/#concept-ipv4-parser>.
+pub(crate) fn parse_lenient_ipv4(bytes: impl Iterator<Item = u8>) -> Option<u32> {
+	let mut numbers = [0u64; 4];
+	let mut len = 0;
+	let mut part = LenientIpv4Part::default();
+
+	let mut bytes = bytes.peekable();
+	while let Some(b) = bytes.next() {
+		if b == b'.' {
+			if len == 4 {
+				return None;
+			}
+
+			numbers[len] = part.end()?;
+			len += 1;
+			part = LenientIpv4Part::default();
+
+			if bytes.peek().is_none() {
+				// Trailing dot.
+				return assemble_lenient_ipv4(&numbers[..len]);
+			}
+		} else {
+			part.push(b)?
+		}
+	}
+
+	if len == 4 {
+		return None;
+	}
+
+	numbers[len] = part.end()?;
+	len += 1;
+	assemble_lenient_ipv4(&numbers[..len])
+}
+
+fn assemble_lenient_ipv4(numbers: &[u64]) -> Option<u32> {
+	let (last, init) = numbers.split_last()?;
+	if init.iter().any(|n| *n > 255) || *last >= 1 << (8 * (5 - numbers.len())) {
+		return None;
+	}
+
+	let mut addr = *last as u32;
+	for (i, n) in init.iter().enumerate() {
+		addr += (*n as u32) << (8 * (3 - i))
+	}
+
+	Some(addr)
+}
+
+/// Part of a lenient IPv4 address, parsed byte by byte.
+///
+/// Parts starting with `0x` are hexadecimal, other parts starting with `0`
+/// are octal, and the remaining parts are decimal.
+#[derive(Default)]
+struct LenientIpv4Part {
+	len: usize,
+	radix: u32,
+	value: u64,
+}
+
+impl LenientIpv4Part {
+	fn push(&mut self, b: u8) -> Option<()> {
+		match (self.len, b) {
+			(0, b'0') => self.radix = 8,
+			(1, b'x' | b'X') if self.radix == 8 => self.radix = 16,
+			_ => {
+				if self.len == 0 {
+					self.radix = 10
+				}
+
+				let digit = (b as char).to_digit(self.radix)? as u64;
+				self.value = self.value * self.radix as u64 + digit;
+				if self.value > u32::MAX as u64 {
+					return None;
+				}
+			}
+		}
+
+		self.len += 1;
+		Some(())
+	}
+
+	fn end(&self) -> Option<u64> {
+		if self.len == 0 {
+			None
+		} else {
+			Some(self.value)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn lenient_ipv4() {
+		let vectors = [
+			("127.0.0.1", Some(0x7f000001)),
+			("127.0.0.1.", Some(0x7f000001)),
+			("127.1", Some(0x7f000001)),
+			("127.0.1", Some(0x7f000001)),
+			("2130706433", Some(0x7f000001)),
+			("0x7f.1", Some(0x7f000001)),
+			("0X7F000001", Some(0x7f000001)),
+			("0177.0.0.01", Some(0x7f000001)),
+			("0", Some(0)),
+			("0x", Some(0)),
+			("00", Some(0)),
+			("255.255.255.255", Some(0xffffffff)),
+			("4294967295", Some(0xffffffff)),
+			("4294967296", None),
+			("256.0.0.1", None),
+			("1.2.3.256", None),
+			("1.2.65536", None),
+			("1.2.3.4.5", None),
+			("1.2.3.4..", None),
+			("", None),
+			(".", None),
+			("1..2", None),
+			("08", None),
+			("0xg", None),
+			("example.org", None),
+			("1.2.3.a", None),
+			(
+				"0000000000000000000000000000000000000000000000000000000000000000001",
+				Some(1),
+			),
+		];
+
+		for (input, expected) in vectors {
+			assert_eq!(parse_lenient_ipv4(input.bytes()), expected, "{input}")
+		}
+	}
+
+	#[test]
+	fn ipv4_scope() {
+		let vectors = [
+			(0x00000000, IpScope::Unspecified),
+			(0x00ffffff, IpScope::Unspecified),
+			(0x7f000001, IpScope::Loopback),
+			(0x7fffffff, IpScope::Loopback),
+			(0x0a000001, IpScope::Private),
+			(0xac1f0001, IpScope::Private),
+			(0xac200001, IpScope::Global),
+			(0xc0a80101, IpScope::Private),
+			(0x64400001, IpScope::Private),
+			(0xa9fea9fe, IpScope::LinkLocal),
+			(0xe0000001, IpScope::Multicast),
+			(0xc0000201, IpScope::Documentation),
+			(0xcb007101, IpScope::Documentation),
+			(0xc0000001, IpScope::Reserved),
+			(0xc6130001, IpScope::Reserved),
+			(0xffffffff, IpScope::Reserved),
+			(0x08080808, IpScope::Global),
+			(0x01010101, IpScope::Global),
+		];
+
+		for (addr, expected) in vectors {
+			assert_eq!(IpScope::of_ipv4(addr), expected, "{addr:#x}")
+		}
+	}
+
+	#[test]
+	fn ipv6_scope() {
+		let vectors = [
+			(0, IpScope::Unspecified),
+			(1, IpScope::Loopback),
+			(0xffff_7f00_0001, IpScope::Loopback),
+			(0xffff_0a00_0001, IpScope::Private),
+			(0xffff_0808_0808, IpScope::Global),
+			(0x7f00_0001, IpScope::Loopback),
+			(0x0064_ff9b << 96 | 0xa9fe_a9fe, IpScope::LinkLocal),
+			(0xffff_0000_7f00_0001, IpScope::Loopback),
+			(0xffff_0000_0808_0808, IpScope::Global),
+			(0x0064_ff9b_0001 << 80 | 0x0a00_0001, IpScope::Private),
+			(0x0064_ff9b_0001 << 80 | 0x0808_0808, IpScope::Private),
+			(0x2002_7f00_0001 << 80, IpScope::Loopback),
+			(0xfd00 << 112, IpScope::Private),
+			(0xfec0 << 112 | 1, IpScope::Private),
+			(0xfe80 << 112 | 1, IpScope::LinkLocal),
+			(0xff02 << 112 | 1, IpScope::Multicast),
+			(0x2001_0db8 << 96 | 1, IpScope::Documentation),
+			(0x3fff << 112 | 1, IpScope::Documentation),
+			(0x0100 << 112 | 1, IpScope::Reserved),
+			(0x2001 << 112 | 1, IpScope::Reserved),
+			(0x2001_0002 << 96 | 1, IpScope::Reserved),
+			(0x2606_4700 << 96 | 1, IpScope::Global),
+			(0x2001_4860 << 96 | 0x8888, IpScope::Global),
+		];
+
+		for (addr, expected) in vectors {
+			assert_eq!(IpScope::of_ipv6(addr), expected, "{addr:#x}")
+		}
+	}
+}
@@ -0,0 +1,130 @@
+/// Size and complexity limits on untrusted URIs and IRIs.
+///
+/// Parsing is linear in the input length, but some operations are not:
+/// path normalization and comparison allocate beyond a few segments, and
+/// query processing is usually linear in the number of key-value pairs.
+/// Limits can be enforced when parsing untrusted input with checked
+/// constructors such as [`Uri::new_with_limits`](crate::Uri::new_with_limits)
+/// and [`Iri::new_with_limits`](crate::Iri::new_with_limits).
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Iri, LimitError, LimitedParseError, Limits};
+///
+/// let limits = Limits {
+///     max_segments: 2,
+///     ..Limits::default()
+/// };
+///
+/// assert!(Iri::new_with_limits("https://example.org/a/b", &limits).is_ok());
+/// assert!(matches!(
+///     Iri::new_with_limits("https://example.org/a/b/c", &limits),
+///     Err(LimitedParseError::Limit(LimitError::Segments(2)))
+/// ));
+/// assert!(matches!(
+///     Iri::new_with_limits("not an IRI", &limits),
+///     Err(LimitedParseError::Invalid(_))
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Limits {
+	/// Maximum total length, in bytes.
+	pub max_len: usize,
+
+	/// Maximum number of path segments.
+	pub max_segments: usize,
+
+	/// Maximum number of query key-value pairs (separated by `&`).
+	pub max_query_pairs: usize,
+
+	/// Maximum host length, in bytes.
+	pub max_host_len: usize,
+}
+
+impl Limits {
+	/// Default limits: 8 KiB in total, 256 path segments, 256 query pairs
+	/// and 255 bytes of host.
+	pub const DEFAULT: Self = Self {
+		max_len: 8 * 1024,
+		max_segments: 256,
+		max_query_pairs: 256,
+		max_host_len: 255,
+	};
+
+	/// Checks the total length.
+	pub(crate) fn check_len(&self, len: usize) -> Result<(), LimitError> {
+		if len > self.max_len {
+			Err(LimitError::Length(self.max_len))
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Checks the number of path segments.
+	pub(crate) fn check_segments(&self, count: usize) -> Result<(), LimitError> {
+		if count > self.max_segments {
+			Err(LimitError::Segments(self.max_segments))
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Checks the number of query pairs in the given query.
+	pub(crate) fn check_query_pairs(&self, query: &str) -> Result<(), LimitError> {
+		if query.split('&').nth(self.max_query_pairs).is_some() {
+			Err(LimitError::QueryPairs(self.max_query_pairs))
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Checks the host length.
+	pub(crate) fn check_host_len(&self, len: usize) -> Result<(), LimitError> {
+		if len > self.max_host_len {
+			Err(LimitError::HostLength(self.max_host_len))
+		} else {
+			Ok(())
+		}
+	}
+}
+
+impl Default for Limits {
+	fn default() -> Self {
+		Self::DEFAULT
+	}
+}
+
+/// Exceeded [`Limits`].
+///
+/// Each variant holds the exceeded limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum LimitError {
+	/// The input is longer than the maximum length.
+	#[error("input longer than {0} bytes")]
+	Length(usize),
+
+	/// The path has too many segments.
+	#[error("more than {0} path segments")]
+	Segments(usize),
+
+	/// The query has too many key-value pairs.
+	#[error("more than {0} query pairs")]
+	QueryPairs(usize),
+
+	/// The host is longer than the maximum length.
+	#[error("host longer than {0} bytes")]
+	HostLength(usize),
+}
+
+/// Error returned by checked constructors enforcing [`Limits`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LimitedParseError<E> {
+	/// The input is invalid.
+	#[error(transparent)]
+	Invalid(E),
+
+	/// The input exceeds the limits.
+	#[error(transparent)]
+	Limit(LimitError),
+}
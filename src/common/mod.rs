@@ -1,13 +1,35 @@
 use static_automata::grammar;
 
+mod ascii_folding;
+#[cfg(feature = "std")]
+pub mod builder;
+pub(crate) mod digest;
+mod exact;
+mod ip;
+mod limits;
 pub(crate) mod parse;
 mod path;
 mod port;
+#[cfg(feature = "std")]
+pub(crate) mod punycode;
 mod scheme;
+#[cfg(feature = "std")]
+pub(crate) mod storage;
+#[cfg(feature = "std")]
+mod target;
+mod unicode;
 
 #[grammar(file = "grammar.abnf", export("scheme", "port"))]
 mod grammar {}
 
+pub use exact::*;
+pub use ip::*;
+pub use limits::*;
 pub use path::*;
 pub use port::*;
 pub use scheme::*;
+#[cfg(feature = "std")]
+pub use storage::{Storage, StorageMut};
+#[cfg(feature = "std")]
+pub use target::*;
+pub(crate) use unicode::*;
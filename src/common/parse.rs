@@ -1,6 +1,6 @@
 use core::ops::Range;
 
-fn is_scheme_char(b: u8) -> bool {
+pub const fn is_scheme_char(b: u8) -> bool {
 	// ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
 	b.is_ascii_alphanumeric() | matches!(b, b'+' | b'-' | b'.')
 }
@@ -10,7 +10,7 @@ fn is_scheme_char(b: u8) -> bool {
 /// Returns `true` if it is of the form `prefix:suffix` where `prefix` is a
 /// valid scheme, of `false` otherwise.
 #[inline]
-pub fn looks_like_scheme(bytes: &[u8]) -> bool {
+pub const fn looks_like_scheme(bytes: &[u8]) -> bool {
 	let mut i = 0;
 	while i < bytes.len() {
 		if i == 0 {
@@ -393,6 +393,7 @@ pub fn find_fragment(bytes: &[u8], mut i: usize) -> Result<Range<usize>, usize>
 	Err(i)
 }
 
+#[derive(Debug, Clone)]
 pub struct ReferenceParts {
 	pub scheme: Option<Range<usize>>,
 	pub authority: Option<Range<usize>>,
@@ -445,6 +446,7 @@ pub fn reference_parts(bytes: &[u8], i: usize) -> ReferenceParts {
 	}
 }
 
+#[derive(Debug, Clone)]
 pub struct Parts {
 	pub scheme: Range<usize>,
 	pub authority: Option<Range<usize>>,
@@ -0,0 +1,201 @@
+//! Punycode encoding and decoding ([RFC 3492](https://datatracker.ietf.org/doc/html/rfc3492)).
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+
+/// Bias adaptation function.
+fn adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+	delta /= if first_time { DAMP } else { 2 };
+	delta += delta / num_points;
+
+	let mut k = 0;
+	while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+		delta /= BASE - T_MIN;
+		k += BASE
+	}
+
+	k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+/// Threshold of the digit at position `k`.
+fn threshold(k: u32, bias: u32) -> u32 {
+	if k <= bias {
+		T_MIN
+	} else if k >= bias + T_MAX {
+		T_MAX
+	} else {
+		k - bias
+	}
+}
+
+/// Encodes a digit value (`0..36`).
+fn encode_digit(d: u32) -> char {
+	if d < 26 {
+		(b'a' + d as u8) as char
+	} else {
+		(b'0' + (d - 26) as u8) as char
+	}
+}
+
+/// Encodes a string into Punycode (without the `xn--` prefix).
+///
+/// Returns `None` on overflow, which only happens on very long inputs.
+pub fn encode(input: &str) -> Option<String> {
+	let input: Vec<u32> = input.chars().map(u32::from).collect();
+	let mut output: String = input
+		.iter()
+		.filter(|c| **c < INITIAL_N)
+		.map(|c| *c as u8 as char)
+		.collect();
+
+	let basic_len = output.len() as u32;
+	if basic_len > 0 {
+		output.push('-')
+	}
+
+	let mut n = INITIAL_N;
+	let mut delta: u32 = 0;
+	let mut bias = INITIAL_BIAS;
+	let mut h = basic_len;
+	while (h as usize) < input.len() {
+		let m = input.iter().copied().filter(|c| *c >= n).min()?;
+		delta = delta.checked_add((m - n).checked_mul(h + 1)?)?;
+		n = m;
+
+		for &c in &input {
+			if c < n {
+				delta = delta.checked_add(1)?
+			} else if c == n {
+				let mut q = delta;
+				let mut k = BASE;
+				loop {
+					let t = threshold(k, bias);
+					if q < t {
+						break;
+					}
+
+					output.push(encode_digit(t + (q - t) % (BASE - t)));
+					q = (q - t) / (BASE - t);
+					k += BASE
+				}
+
+				output.push(encode_digit(q));
+				bias = adapt(delta, h + 1, h == basic_len);
+				delta = 0;
+				h += 1
+			}
+		}
+
+		delta = delta.checked_add(1)?;
+		n += 1
+	}
+
+	Some(output)
+}
+
+/// Decodes a Punycode string (without the `xn--` prefix).
+///
+/// Returns `None` if the input is not valid Punycode.
+#[cfg_attr(not(any(feature = "psl", feature = "security")), allow(dead_code))]
+pub fn decode(input: &str) -> Option<String> {
+	let (basic, extended) = match input.rfind('-') {
+		Some(i) => (&input[..i], &input[(i + 1)..]),
+		None => ("", input),
+	};
+
+	if !basic.is_ascii() {
+		return None;
+	}
+
+	let mut output: Vec<char> = basic.chars().collect();
+	let mut n = INITIAL_N;
+	let mut i: u32 = 0;
+	let mut bias = INITIAL_BIAS;
+
+	let mut digits = extended.bytes().peekable();
+	while digits.peek().is_some() {
+		let old_i = i;
+		let mut w: u32 = 1;
+		let mut k = BASE;
+		loop {
+			let digit = match digits.next()? {
+				b @ b'a'..=b'z' => b - b'a',
+				b @ b'A'..=b'Z' => b - b'A',
+				b @ b'0'..=b'9' => b - b'0' + 26,
+				_ => return None,
+			} as u32;
+
+			i = i.checked_add(digit.checked_mul(w)?)?;
+			let t = threshold(k, bias);
+
+			if digit < t {
+				break;
+			}
+
+			w = w.checked_mul(BASE - t)?;
+			k += BASE
+		}
+
+		let len = output.len() as u32 + 1;
+		bias = adapt(i - old_i, len, old_i == 0);
+		n = n.checked_add(i / len)?;
+		i %= len;
+		output.insert(i as usize, char::from_u32(n)?);
+		i += 1
+	}
+
+	Some(output.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn decode_vectors() {
+		let vectors = [
+			("", ""),
+			("abc-", "abc"),
+			("bcher-kva", "bücher"),
+			("mnchen-3ya", "münchen"),
+			("fiqs8s", "中国"),
+			("55qx5d", "公司"),
+			("wgbh1c", "مصر"),
+			("zckzah", "テスト"),
+			// RFC 3492 section 7.1 (L).
+			("3B-ww4c5e180e575a65lsy2b", "3年B組金八先生"),
+		];
+
+		for (input, expected) in vectors {
+			assert_eq!(decode(input).as_deref(), Some(expected), "{input}")
+		}
+
+		for input in ["ü", "a-é", "99999999999"] {
+			assert_eq!(decode(input), None, "{input}")
+		}
+	}
+
+	#[test]
+	fn encode_vectors() {
+		let vectors = [
+			("", ""),
+			("abc", "abc-"),
+			("bücher", "bcher-kva"),
+			("münchen", "mnchen-3ya"),
+			("中国", "fiqs8s"),
+			("例え", "r8jz45g"),
+			("テスト", "zckzah"),
+			("3年B組金八先生", "3B-ww4c5e180e575a65lsy2b"),
+		];
+
+		for (input, expected) in vectors {
+			assert_eq!(encode(input).as_deref(), Some(expected), "{input}");
+			assert_eq!(decode(expected).as_deref(), Some(input), "{input}")
+		}
+	}
+}
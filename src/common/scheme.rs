@@ -37,6 +37,41 @@ impl Scheme {
 	pub const URN: &Self = scheme!("urn");
 	pub const DATA: &Self = scheme!("data");
 	pub const MAILTO: &Self = scheme!("mailto");
+	pub const TEL: &Self = scheme!("tel");
+	pub const WS: &Self = scheme!("ws");
+	pub const WSS: &Self = scheme!("wss");
+
+	/// Known default ports, by scheme.
+	///
+	/// These are the default ports of the WHATWG URL Standard special
+	/// schemes (except `file`, which has no port).
+	pub const DEFAULT_PORTS: &'static [(&'static Self, u16)] = &[
+		(Self::FTP, 21),
+		(Self::HTTP, 80),
+		(Self::HTTPS, 443),
+		(Self::WS, 80),
+		(Self::WSS, 443),
+	];
+
+	/// Returns the default port of this scheme, if known.
+	///
+	/// The scheme is compared case-insensitively with the entries of
+	/// [`Self::DEFAULT_PORTS`].
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::Scheme;
+	///
+	/// assert_eq!(Scheme::new("HTTPS").unwrap().default_port(), Some(443));
+	/// assert_eq!(Scheme::new("urn").unwrap().default_port(), None);
+	/// ```
+	pub fn default_port(&self) -> Option<u16> {
+		Self::DEFAULT_PORTS
+			.iter()
+			.find(|(scheme, _)| *scheme == self)
+			.map(|(_, port)| *port)
+	}
 }
 
 impl PartialEq for Scheme {
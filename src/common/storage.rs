@@ -0,0 +1,255 @@
+use std::{rc::Rc, sync::Arc};
+
+/// Owned URI/IRI storage backend.
+///
+/// Implemented by the string types that can hold the bytes of an owned
+/// URI/IRI, such as [`String`], [`Box<str>`], [`Arc<str>`], [`Rc<str>`] or
+/// [`SmallVec`](smallvec::SmallVec) for inline storage of short values.
+///
+/// # Safety
+///
+/// Owned URIs/IRIs trust the storage to preserve their validated content.
+/// Implementations must guarantee that:
+/// - [`Storage::as_bytes`] always returns the same bytes as long as the
+///   storage is not mutably borrowed;
+/// - [`Storage::from_string`] and [`Storage::from_str`] create a storage
+///   whose bytes are exactly the bytes of the input string;
+/// - [`Storage::into_bytes`] returns exactly the stored bytes.
+pub unsafe trait Storage: Sized {
+	/// Returns the stored bytes.
+	fn as_bytes(&self) -> &[u8];
+
+	/// Creates a storage from the given string.
+	fn from_string(value: String) -> Self;
+
+	/// Creates a storage from the given string slice.
+	fn from_str(value: &str) -> Self {
+		Self::from_string(value.to_owned())
+	}
+
+	/// Returns the stored bytes as a byte buffer.
+	fn into_bytes(self) -> Vec<u8> {
+		self.as_bytes().to_vec()
+	}
+}
+
+/// Mutable owned URI/IRI storage backend.
+///
+/// Storage whose content can be moved in and out of a byte buffer, allowing
+/// in-place mutation.
+///
+/// # Safety
+///
+/// [`StorageMut::take_bytes`] must return exactly the stored bytes, and
+/// after [`StorageMut::set_bytes`] the storage bytes must be exactly the
+/// input bytes.
+///
+/// Since owned URIs/IRIs rely on this, the trait is unsafe to implement:
+///
+/// ```compile_fail,E0200
+/// use iref::{Storage, StorageMut};
+///
+/// struct Empty;
+///
+/// unsafe impl Storage for Empty {
+///     fn as_bytes(&self) -> &[u8] {
+///         b""
+///     }
+///
+///     fn from_string(_: String) -> Self {
+///         Empty
+///     }
+/// }
+///
+/// impl StorageMut for Empty {
+///     fn take_bytes(&mut self) -> Vec<u8> {
+///         b"not a URI".to_vec()
+///     }
+///
+///     unsafe fn set_bytes(&mut self, _: Vec<u8>) {}
+/// }
+/// ```
+pub unsafe trait StorageMut: Storage {
+	/// Moves the stored bytes out of the storage, leaving it empty.
+	fn take_bytes(&mut self) -> Vec<u8>;
+
+	/// Replaces the stored bytes.
+	///
+	/// # Safety
+	///
+	/// The input bytes must be valid UTF-8.
+	unsafe fn set_bytes(&mut self, bytes: Vec<u8>);
+}
+
+unsafe impl Storage for String {
+	fn as_bytes(&self) -> &[u8] {
+		self.as_bytes()
+	}
+
+	fn from_string(value: String) -> Self {
+		value
+	}
+
+	fn into_bytes(self) -> Vec<u8> {
+		self.into_bytes()
+	}
+}
+
+unsafe impl StorageMut for String {
+	fn take_bytes(&mut self) -> Vec<u8> {
+		std::mem::take(self).into_bytes()
+	}
+
+	unsafe fn set_bytes(&mut self, bytes: Vec<u8>) {
+		*self = unsafe { String::from_utf8_unchecked(bytes) }
+	}
+}
+
+unsafe impl Storage for Vec<u8> {
+	fn as_bytes(&self) -> &[u8] {
+		self
+	}
+
+	fn from_string(value: String) -> Self {
+		value.into_bytes()
+	}
+
+	fn into_bytes(self) -> Vec<u8> {
+		self
+	}
+}
+
+unsafe impl StorageMut for Vec<u8> {
+	fn take_bytes(&mut self) -> Vec<u8> {
+		std::mem::take(self)
+	}
+
+	unsafe fn set_bytes(&mut self, bytes: Vec<u8>) {
+		*self = bytes
+	}
+}
+
+unsafe impl Storage for Box<str> {
+	fn as_bytes(&self) -> &[u8] {
+		str::as_bytes(self)
+	}
+
+	fn from_string(value: String) -> Self {
+		value.into_boxed_str()
+	}
+
+	fn from_str(value: &str) -> Self {
+		value.into()
+	}
+
+	fn into_bytes(self) -> Vec<u8> {
+		self.into_string().into_bytes()
+	}
+}
+
+unsafe impl StorageMut for Box<str> {
+	fn take_bytes(&mut self) -> Vec<u8> {
+		std::mem::take(self).into_bytes()
+	}
+
+	unsafe fn set_bytes(&mut self, bytes: Vec<u8>) {
+		*self = unsafe { String::from_utf8_unchecked(bytes) }.into_boxed_str()
+	}
+}
+
+unsafe impl Storage for Arc<str> {
+	fn as_bytes(&self) -> &[u8] {
+		str::as_bytes(self)
+	}
+
+	fn from_string(value: String) -> Self {
+		value.into()
+	}
+
+	fn from_str(value: &str) -> Self {
+		value.into()
+	}
+}
+
+unsafe impl Storage for Rc<str> {
+	fn as_bytes(&self) -> &[u8] {
+		str::as_bytes(self)
+	}
+
+	fn from_string(value: String) -> Self {
+		value.into()
+	}
+
+	fn from_str(value: &str) -> Self {
+		value.into()
+	}
+}
+
+unsafe impl<A: smallvec::Array<Item = u8>> Storage for smallvec::SmallVec<A> {
+	fn as_bytes(&self) -> &[u8] {
+		self
+	}
+
+	fn from_string(value: String) -> Self {
+		Self::from_vec(value.into_bytes())
+	}
+
+	fn from_str(value: &str) -> Self {
+		Self::from_slice(value.as_bytes())
+	}
+
+	fn into_bytes(self) -> Vec<u8> {
+		self.into_vec()
+	}
+}
+
+unsafe impl<A: smallvec::Array<Item = u8>> StorageMut for smallvec::SmallVec<A> {
+	fn take_bytes(&mut self) -> Vec<u8> {
+		std::mem::take(self).into_vec()
+	}
+
+	unsafe fn set_bytes(&mut self, bytes: Vec<u8>) {
+		*self = Self::from_vec(bytes);
+		if self.len() <= A::size() {
+			// Move back to inline storage.
+			self.shrink_to_fit()
+		}
+	}
+}
+
+/// Moves the content of the given storage into a buffer, calls `f` on it,
+/// and moves it back, even if `f` panics.
+///
+/// # Safety
+///
+/// `unwrap` must return valid UTF-8 bytes.
+pub(crate) unsafe fn modify<S: StorageMut, B, R>(
+	storage: &mut S,
+	wrap: impl FnOnce(Vec<u8>) -> B,
+	unwrap: fn(B) -> Vec<u8>,
+	f: impl FnOnce(&mut B) -> R,
+) -> R {
+	struct Guard<'a, S: StorageMut, B> {
+		storage: &'a mut S,
+		buffer: Option<B>,
+		unwrap: fn(B) -> Vec<u8>,
+	}
+
+	impl<S: StorageMut, B> Drop for Guard<'_, S, B> {
+		fn drop(&mut self) {
+			if let Some(buffer) = self.buffer.take() {
+				// SAFETY: guaranteed by the caller.
+				unsafe { self.storage.set_bytes((self.unwrap)(buffer)) }
+			}
+		}
+	}
+
+	let buffer = wrap(storage.take_bytes());
+	let mut guard = Guard {
+		storage,
+		buffer: Some(buffer),
+		unwrap,
+	};
+
+	f(guard.buffer.as_mut().unwrap())
+}
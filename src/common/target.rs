@@ -0,0 +1,138 @@
+use crate::{IpScope, Scheme, SchemeBuf};
+
+/// Policy restricting the targets a URI may designate.
+///
+/// This is meant to protect services fetching user-supplied URIs (such as
+/// webhooks) against server-side request forgery (SSRF): by default, only
+/// `http` and `https` URIs with a host that is not known to designate a
+/// loopback, private, link-local or otherwise non-global address are
+/// accepted.
+///
+/// Hosts are classified with [`Host::ip_scope`](crate::uri::Host::ip_scope),
+/// so IPv4 addresses written in the forms that web browsers accept
+/// (`2130706433`, `0x7f.1`, ...) and IPv4-mapped IPv6 addresses are
+/// recognized. The `localhost` name and its subdomains are considered
+/// loopback addresses, as specified by [RFC 6761].
+///
+/// Other registered names are not resolved: a domain name may still resolve
+/// to an internal address. Addresses obtained by resolving a name must also
+/// be checked (with [`IpScope::of_ipv4`] and [`IpScope::of_ipv6`]) before
+/// connecting.
+///
+/// [RFC 6761]: <https://datatracker.ietf.org/doc/html/rfc6761#section-6.3>
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{IpScope, TargetError, TargetPolicy, Uri};
+///
+/// let policy = TargetPolicy::new();
+/// assert!(policy.check_uri(Uri::new("https://example.org/hook").unwrap()).is_ok());
+/// assert_eq!(
+///     policy.check_uri(Uri::new("http://0x7f.1/admin").unwrap()),
+///     Err(TargetError::Scope(IpScope::Loopback))
+/// );
+/// assert!(policy.check_uri(Uri::new("file:///etc/passwd").unwrap()).is_err());
+///
+/// let policy = TargetPolicy::new().allow_scope(IpScope::Private);
+/// assert!(policy.check_uri(Uri::new("http://10.0.0.1/").unwrap()).is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetPolicy {
+	schemes: Vec<SchemeBuf>,
+	scopes: Vec<IpScope>,
+}
+
+impl TargetPolicy {
+	/// Creates the default policy, allowing `http` and `https` URIs with a
+	/// host of [global](IpScope::Global) scope.
+	pub fn new() -> Self {
+		Self {
+			schemes: vec![Scheme::HTTP.to_owned(), Scheme::HTTPS.to_owned()],
+			scopes: vec![IpScope::Global],
+		}
+	}
+
+	/// Allows the given scheme.
+	pub fn allow_scheme(mut self, scheme: &Scheme) -> Self {
+		if !self.allows_scheme(scheme) {
+			self.schemes.push(scheme.to_owned())
+		}
+
+		self
+	}
+
+	/// Allows hosts of the given scope.
+	pub fn allow_scope(mut self, scope: IpScope) -> Self {
+		if !self.allows_scope(scope) {
+			self.scopes.push(scope)
+		}
+
+		self
+	}
+
+	/// Checks if the given scheme is allowed.
+	pub fn allows_scheme(&self, scheme: &Scheme) -> bool {
+		self.schemes.iter().any(|s| **s == *scheme)
+	}
+
+	/// Checks if hosts of the given scope are allowed.
+	pub fn allows_scope(&self, scope: IpScope) -> bool {
+		self.scopes.contains(&scope)
+	}
+
+	/// Checks the given scheme.
+	pub(crate) fn check_scheme(&self, scheme: &Scheme) -> Result<(), TargetError> {
+		if self.allows_scheme(scheme) {
+			Ok(())
+		} else {
+			Err(TargetError::Scheme(scheme.to_owned()))
+		}
+	}
+
+	/// Checks the given host scope.
+	pub(crate) fn check_scope(&self, scope: IpScope) -> Result<(), TargetError> {
+		if self.allows_scope(scope) {
+			Ok(())
+		} else {
+			Err(TargetError::Scope(scope))
+		}
+	}
+}
+
+impl Default for TargetPolicy {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Error returned when a URI is rejected by a [`TargetPolicy`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TargetError {
+	/// The scheme is not allowed.
+	#[error("scheme `{0}` is not allowed")]
+	Scheme(SchemeBuf),
+
+	/// The URI has no host.
+	#[error("missing host")]
+	MissingHost,
+
+	/// The host is an IP-literal that is not an IPv6 address (IPvFuture).
+	#[error("unsupported host `{0}`")]
+	UnsupportedHost(String),
+
+	/// The host designates an address whose scope is not allowed.
+	#[error("{0} address is not allowed")]
+	Scope(IpScope),
+}
+
+/// Checks if the given percent-decoded host is `localhost` or one of its
+/// subdomains.
+pub(crate) fn is_localhost(host: impl Iterator<Item = u8>) -> bool {
+	let mut host: Vec<u8> = host.map(|b| b.to_ascii_lowercase()).collect();
+	if host.last() == Some(&b'.') {
+		host.pop();
+	}
+
+	host == b"localhost" || host.ends_with(b".localhost")
+}
@@ -0,0 +1,179 @@
+//! Unicode character classes.
+use super::ascii_folding::ASCII_FOLDING;
+
+/// Checks if the given character is a bidirectional formatting character
+/// (`Bidi_Control` property).
+pub(crate) fn is_bidi_control(c: char) -> bool {
+	matches!(
+		c,
+		'\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+	)
+}
+
+/// Checks if the given character is invisible: a format character
+/// (`General_Category=Cf`) or a default ignorable code point.
+pub(crate) fn is_invisible(c: char) -> bool {
+	matches!(
+		c,
+		// Format characters.
+		'\u{00AD}'
+			| '\u{0600}'..='\u{0605}'
+			| '\u{061C}'
+			| '\u{06DD}'
+			| '\u{070F}'
+			| '\u{0890}'..='\u{0891}'
+			| '\u{08E2}'
+			| '\u{180E}'
+			| '\u{200B}'..='\u{200F}'
+			| '\u{202A}'..='\u{202E}'
+			| '\u{2060}'..='\u{2064}'
+			| '\u{2066}'..='\u{206F}'
+			| '\u{FEFF}'
+			| '\u{FFF9}'..='\u{FFFB}'
+			| '\u{110BD}'
+			| '\u{110CD}'
+			| '\u{13430}'..='\u{13438}'
+			| '\u{1BCA0}'..='\u{1BCA3}'
+			| '\u{1D173}'..='\u{1D17A}'
+			| '\u{E0001}'
+			| '\u{E0020}'..='\u{E007F}'
+			// Other default ignorable code points.
+			| '\u{034F}'
+			| '\u{115F}'..='\u{1160}'
+			| '\u{17B4}'..='\u{17B5}'
+			| '\u{180B}'..='\u{180D}'
+			| '\u{180F}'
+			| '\u{2065}'
+			| '\u{3164}'
+			| '\u{FE00}'..='\u{FE0F}'
+			| '\u{FFA0}'
+			| '\u{FFF0}'..='\u{FFF8}'
+			| '\u{E0000}'
+			| '\u{E0002}'..='\u{E001F}'
+			| '\u{E0080}'..='\u{E0FFF}'
+	)
+}
+
+/// Checks if the given character is an IRI `ucschar`, as defined by
+/// [RFC 3987](https://datatracker.ietf.org/doc/html/rfc3987#section-2.2).
+pub(crate) fn is_ucschar(c: char) -> bool {
+	matches!(
+		c,
+		'\u{A0}'..='\u{D7FF}'
+			| '\u{F900}'..='\u{FDCF}'
+			| '\u{FDF0}'..='\u{FFEF}'
+			| '\u{10000}'..='\u{1FFFD}'
+			| '\u{20000}'..='\u{2FFFD}'
+			| '\u{30000}'..='\u{3FFFD}'
+			| '\u{40000}'..='\u{4FFFD}'
+			| '\u{50000}'..='\u{5FFFD}'
+			| '\u{60000}'..='\u{6FFFD}'
+			| '\u{70000}'..='\u{7FFFD}'
+			| '\u{80000}'..='\u{8FFFD}'
+			| '\u{90000}'..='\u{9FFFD}'
+			| '\u{A0000}'..='\u{AFFFD}'
+			| '\u{B0000}'..='\u{BFFFD}'
+			| '\u{C0000}'..='\u{CFFFD}'
+			| '\u{D0000}'..='\u{DFFFD}'
+			| '\u{E1000}'..='\u{EFFFD}'
+	)
+}
+
+/// Checks if the given non-ASCII character looks like an ASCII delimiter,
+/// or is turned into one by compatibility normalization (NFKC), as done by
+/// web browsers on hosts.
+pub(crate) fn is_delimiter_lookalike(c: char) -> bool {
+	matches!(
+		c,
+		// Fullwidth ASCII variants and small form variants.
+		'\u{FF01}'..='\u{FF5E}'
+			| '\u{FE50}'..='\u{FE6B}'
+			// Full stops.
+			| '\u{3002}'
+			| '\u{FF61}'
+			| '\u{2024}'..='\u{2026}'
+			| '\u{2488}'..='\u{249B}'
+			| '\u{0660}'
+			| '\u{06F0}'
+			// Slashes.
+			| '\u{2044}'
+			| '\u{2215}'
+			| '\u{2216}'
+			| '\u{2100}'..='\u{2101}'
+			| '\u{2105}'..='\u{2106}'
+			// Colons.
+			| '\u{02D0}'
+			| '\u{0589}'
+			| '\u{05C3}'
+			| '\u{2236}'
+			| '\u{A789}'
+	)
+}
+
+/// Returns the ASCII mapping of the given non-ASCII character by UTS #46,
+/// if any.
+///
+/// Characters such as fullwidth letters and digits or ideographic full stops
+/// are mapped to their ASCII counterpart, and invisible characters are
+/// mapped to the empty string.
+pub(crate) fn fold_char(c: char) -> Option<&'static str> {
+	if is_invisible(c) {
+		Some("")
+	} else {
+		ASCII_FOLDING
+			.binary_search_by_key(&c, |(k, _)| *k)
+			.ok()
+			.map(|i| ASCII_FOLDING[i].1)
+	}
+}
+
+/// Folds the percent-decoded bytes of a host the way the UTS #46 mapping
+/// does, as far as ASCII is concerned.
+///
+/// The bytes are decoded as UTF-8. Characters mapped to ASCII by UTS #46
+/// (fullwidth digits, ideographic full stops, ...) are replaced by their
+/// mapping, and invisible characters are removed. Any other non-ASCII
+/// character, or invalid UTF-8 sequence, is replaced by the non-ASCII byte
+/// `0xFF`. ASCII bytes are unchanged.
+pub(crate) fn fold_host(bytes: impl Iterator<Item = u8>) -> impl Iterator<Item = u8> {
+	Utf8Chars(bytes.peekable()).flat_map(|c| {
+		let (byte, mapping) = match c {
+			Some(c) if c.is_ascii() => (Some(c as u8), ""),
+			Some(c) => match fold_char(c) {
+				Some(mapping) => (None, mapping),
+				None => (Some(0xff), ""),
+			},
+			None => (Some(0xff), ""),
+		};
+
+		byte.into_iter().chain(mapping.bytes())
+	})
+}
+
+/// Lossy UTF-8 decoder, yielding `None` for each invalid sequence.
+struct Utf8Chars<I: Iterator>(core::iter::Peekable<I>);
+
+impl<I: Iterator<Item = u8>> Iterator for Utf8Chars<I> {
+	type Item = Option<char>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let b = self.0.next()?;
+		let (len, min) = match b {
+			0x00..=0x7f => return Some(Some(b as char)),
+			0xc2..=0xdf => (1, 0x80),
+			0xe0..=0xef => (2, 0x800),
+			0xf0..=0xf4 => (3, 0x10000),
+			_ => return Some(None),
+		};
+
+		let mut c = (b & (0x7f >> (len + 1))) as u32;
+		for _ in 0..len {
+			match self.0.next_if(|b| b & 0xc0 == 0x80) {
+				Some(b) => c = c << 6 | (b & 0x3f) as u32,
+				None => return Some(None),
+			}
+		}
+
+		Some(char::from_u32(c).filter(|_| c >= min))
+	}
+}
@@ -0,0 +1,380 @@
+//! Compact IRIs (CURIEs).
+//!
+//! A compact IRI has the form `prefix:suffix`, where `prefix` is bound to a
+//! namespace IRI in a [`PrefixMap`]. Compact IRIs are used by JSON-LD,
+//! Turtle, SPARQL and RDFa.
+use std::collections::BTreeMap;
+
+use crate::{Iri, IriBuf, iri::InvalidIri};
+
+/// Invalid prefix error.
+///
+/// This error is raised by [`PrefixMap::insert`] when the prefix is not a
+/// valid Turtle `PN_PREFIX` (or the empty prefix).
+#[derive(Debug, thiserror::Error)]
+#[error("invalid prefix: {0}")]
+pub struct InvalidPrefix<T>(pub T);
+
+/// Compact IRI expansion error.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ExpandError {
+	/// The input has no `:` separator.
+	#[error("missing `:` separator")]
+	NotCompact,
+
+	/// The input is a blank node identifier (`_:`).
+	#[error("blank node identifier")]
+	BlankNode,
+
+	/// The suffix starts with `//`, meaning the input is an absolute IRI
+	/// rather than a compact IRI.
+	#[error("absolute IRI")]
+	AbsoluteIri,
+
+	/// The prefix is not bound in the prefix map.
+	#[error("unknown prefix `{0}`")]
+	UnknownPrefix(String),
+
+	/// The expanded value is not a valid IRI.
+	#[error("invalid expanded IRI: {0}")]
+	InvalidIri(String),
+}
+
+/// Prefix map, binding prefixes to namespace IRIs.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Iri, IriBuf, curie::PrefixMap};
+///
+/// let mut map = PrefixMap::new();
+/// map.insert("foaf", IriBuf::new("http://xmlns.com/foaf/0.1/".to_owned()).unwrap()).unwrap();
+///
+/// let iri = map.expand("foaf:name").unwrap();
+/// assert_eq!(iri, "http://xmlns.com/foaf/0.1/name");
+///
+/// assert_eq!(map.compact(&iri), Some(("foaf", "name")));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PrefixMap {
+	prefixes: BTreeMap<String, IriBuf>,
+}
+
+impl PrefixMap {
+	/// Creates a new empty prefix map.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the number of bound prefixes.
+	pub fn len(&self) -> usize {
+		self.prefixes.len()
+	}
+
+	/// Checks if the map has no bound prefix.
+	pub fn is_empty(&self) -> bool {
+		self.prefixes.is_empty()
+	}
+
+	/// Binds the given prefix to the given namespace, returning the
+	/// previously bound namespace, if any.
+	///
+	/// The prefix must be empty or a valid Turtle `PN_PREFIX`, which excludes
+	/// the `_` blank node prefix.
+	pub fn insert<P: Into<String>>(
+		&mut self,
+		prefix: P,
+		namespace: IriBuf,
+	) -> Result<Option<IriBuf>, InvalidPrefix<String>> {
+		let prefix = prefix.into();
+		if is_prefix(&prefix) {
+			Ok(self.prefixes.insert(prefix, namespace))
+		} else {
+			Err(InvalidPrefix(prefix))
+		}
+	}
+
+	/// Removes the given prefix binding, returning the namespace it was
+	/// bound to, if any.
+	pub fn remove(&mut self, prefix: &str) -> Option<IriBuf> {
+		self.prefixes.remove(prefix)
+	}
+
+	/// Returns the namespace bound to the given prefix, if any.
+	pub fn get(&self, prefix: &str) -> Option<&Iri> {
+		self.prefixes.get(prefix).map(IriBuf::as_iri)
+	}
+
+	/// Returns an iterator over the prefix bindings, ordered by prefix.
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &Iri)> {
+		self.prefixes.iter().map(|(p, n)| (p.as_str(), n.as_iri()))
+	}
+
+	/// Expands the given compact IRI.
+	///
+	/// The input is split on its first `:`. Inputs whose suffix starts
+	/// with `//` are absolute IRIs (such as `http://example.org`) and are
+	/// rejected with [`ExpandError::AbsoluteIri`], even if the prefix is
+	/// bound.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::{IriBuf, curie::{PrefixMap, ExpandError}};
+	///
+	/// let mut map = PrefixMap::new();
+	/// map.insert("ex", IriBuf::new("http://example.org/".to_owned()).unwrap()).unwrap();
+	///
+	/// assert_eq!(map.expand("ex:a/b").unwrap(), "http://example.org/a/b");
+	/// assert_eq!(map.expand("ex://a"), Err(ExpandError::AbsoluteIri));
+	/// assert_eq!(map.expand("foo:a"), Err(ExpandError::UnknownPrefix("foo".to_owned())));
+	/// ```
+	pub fn expand(&self, compact: &str) -> Result<IriBuf, ExpandError> {
+		let (prefix, suffix) = compact.split_once(':').ok_or(ExpandError::NotCompact)?;
+
+		if prefix == "_" {
+			return Err(ExpandError::BlankNode);
+		}
+
+		if suffix.starts_with("//") {
+			return Err(ExpandError::AbsoluteIri);
+		}
+
+		let namespace = self
+			.prefixes
+			.get(prefix)
+			.ok_or_else(|| ExpandError::UnknownPrefix(prefix.to_owned()))?;
+
+		let mut result = String::with_capacity(namespace.len() + suffix.len());
+		result.push_str(namespace.as_str());
+		result.push_str(suffix);
+
+		IriBuf::new(result).map_err(|InvalidIri(s)| ExpandError::InvalidIri(s))
+	}
+
+	/// Compacts the given IRI, returning the prefix and suffix.
+	///
+	/// The namespace must be a textual prefix of the IRI. The longest
+	/// matching namespace whose suffix is a legal local name (Turtle
+	/// `PN_LOCAL`, without backslash escapes) is selected. If several
+	/// prefixes are bound to this namespace, the first one in prefix order
+	/// is used. Returns `None` if no namespace matches.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::{Iri, IriBuf, curie::PrefixMap};
+	///
+	/// let mut map = PrefixMap::new();
+	/// map.insert("ex", IriBuf::new("http://example.org/".to_owned()).unwrap()).unwrap();
+	/// map.insert("exv", IriBuf::new("http://example.org/vocab#".to_owned()).unwrap()).unwrap();
+	///
+	/// let iri = Iri::new("http://example.org/vocab#Person").unwrap();
+	/// assert_eq!(map.compact(iri), Some(("exv", "Person")));
+	///
+	/// // `a/b` is not a legal local name.
+	/// let iri = Iri::new("http://example.org/a/b").unwrap();
+	/// assert_eq!(map.compact(iri), None);
+	/// ```
+	pub fn compact<'a>(&'a self, iri: &'a Iri) -> Option<(&'a str, &'a str)> {
+		let mut best: Option<(&str, &str)> = None;
+
+		for (prefix, namespace) in &self.prefixes {
+			if let Some(suffix) = iri.as_str().strip_prefix(namespace.as_str()) {
+				let longer = best.is_none_or(|(_, s)| suffix.len() < s.len());
+				if longer && is_local_name(suffix) {
+					best = Some((prefix, suffix))
+				}
+			}
+		}
+
+		best
+	}
+}
+
+impl<P: Into<String>> FromIterator<(P, IriBuf)> for PrefixMap {
+	/// Collects prefix bindings, ignoring invalid prefixes.
+	fn from_iter<T: IntoIterator<Item = (P, IriBuf)>>(iter: T) -> Self {
+		let mut result = Self::new();
+		for (prefix, namespace) in iter {
+			let _ = result.insert(prefix, namespace);
+		}
+
+		result
+	}
+}
+
+/// Checks `PN_CHARS_BASE`.
+fn is_pn_chars_base(c: char) -> bool {
+	matches!(c,
+		'A'..='Z'
+		| 'a'..='z'
+		| '\u{00C0}'..='\u{00D6}'
+		| '\u{00D8}'..='\u{00F6}'
+		| '\u{00F8}'..='\u{02FF}'
+		| '\u{0370}'..='\u{037D}'
+		| '\u{037F}'..='\u{1FFF}'
+		| '\u{200C}'..='\u{200D}'
+		| '\u{2070}'..='\u{218F}'
+		| '\u{2C00}'..='\u{2FEF}'
+		| '\u{3001}'..='\u{D7FF}'
+		| '\u{F900}'..='\u{FDCF}'
+		| '\u{FDF0}'..='\u{FFFD}'
+		| '\u{10000}'..='\u{EFFFF}'
+	)
+}
+
+/// Checks `PN_CHARS_U`.
+fn is_pn_chars_u(c: char) -> bool {
+	c == '_' || is_pn_chars_base(c)
+}
+
+/// Checks `PN_CHARS`.
+fn is_pn_chars(c: char) -> bool {
+	is_pn_chars_u(c)
+		|| c.is_ascii_digit()
+		|| matches!(c, '-' | '\u{00B7}' | '\u{0300}'..='\u{036F}' | '\u{203F}'..='\u{2040}')
+}
+
+/// Checks `"" | PN_PREFIX`.
+fn is_prefix(prefix: &str) -> bool {
+	let mut chars = prefix.chars();
+	match chars.next() {
+		None => true,
+		Some(c) => {
+			is_pn_chars_base(c)
+				&& chars.all(|c| is_pn_chars(c) || c == '.')
+				&& !prefix.ends_with('.')
+		}
+	}
+}
+
+/// Checks `"" | PN_LOCAL`, without `PN_LOCAL_ESC` escapes.
+fn is_local_name(suffix: &str) -> bool {
+	let bytes = suffix.as_bytes();
+	let mut chars = suffix.char_indices();
+	while let Some((i, c)) = chars.next() {
+		let valid = match c {
+			'%' => {
+				// `PERCENT`, already validated by the IRI grammar.
+				chars.next();
+				chars.next();
+				true
+			}
+			':' => true,
+			'.' => i > 0 && i + 1 < bytes.len(),
+			c if c.is_ascii_digit() => true,
+			c if i == 0 => is_pn_chars_u(c),
+			c => is_pn_chars(c),
+		};
+
+		if !valid {
+			return false;
+		}
+	}
+
+	true
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn map() -> PrefixMap {
+		[
+			("", "http://example.org/default#"),
+			("ex", "http://example.org/"),
+			("exv", "http://example.org/vocab#"),
+			("foaf", "http://xmlns.com/foaf/0.1/"),
+			("http", "http://example.com/http-ns/"),
+		]
+		.into_iter()
+		.map(|(p, n)| (p, IriBuf::new(n.to_owned()).unwrap()))
+		.collect()
+	}
+
+	#[test]
+	fn insert() {
+		let mut map = PrefixMap::new();
+		let ns = IriBuf::new("http://example.org/".to_owned()).unwrap();
+		for prefix in ["", "a", "ex.v", "é-1", "a_b"] {
+			assert!(map.insert(prefix, ns.clone()).is_ok(), "{prefix}")
+		}
+
+		for prefix in ["_", "1a", "a.", "a:b", "a b", "-a"] {
+			assert!(map.insert(prefix, ns.clone()).is_err(), "{prefix}")
+		}
+	}
+
+	#[test]
+	fn expand() {
+		let map = map();
+		let vectors = [
+			("foaf:name", "http://xmlns.com/foaf/0.1/name"),
+			("foaf:", "http://xmlns.com/foaf/0.1/"),
+			(":x", "http://example.org/default#x"),
+			("ex:a:b", "http://example.org/a:b"),
+			("http:x", "http://example.com/http-ns/x"),
+		];
+
+		for (input, expected) in vectors {
+			assert_eq!(map.expand(input).unwrap(), expected, "{input}")
+		}
+
+		assert_eq!(map.expand("foaf"), Err(ExpandError::NotCompact));
+		assert_eq!(map.expand("_:b0"), Err(ExpandError::BlankNode));
+		assert_eq!(
+			map.expand("http://example.org/"),
+			Err(ExpandError::AbsoluteIri)
+		);
+		assert_eq!(
+			map.expand("dc:title"),
+			Err(ExpandError::UnknownPrefix("dc".to_owned()))
+		);
+		assert_eq!(
+			map.expand("ex:a b"),
+			Err(ExpandError::InvalidIri("http://example.org/a b".to_owned()))
+		);
+	}
+
+	#[test]
+	fn compact() {
+		let map = map();
+		let vectors = [
+			("http://xmlns.com/foaf/0.1/name", Some(("foaf", "name"))),
+			("http://xmlns.com/foaf/0.1/", Some(("foaf", ""))),
+			("http://example.org/vocab#Person", Some(("exv", "Person"))),
+			("http://example.org/vocab", Some(("ex", "vocab"))),
+			("http://example.org/default#x", Some(("", "x"))),
+			("http://example.org/a%20b", Some(("ex", "a%20b"))),
+			("http://example.org/a.b", Some(("ex", "a.b"))),
+			("http://example.org/123", Some(("ex", "123"))),
+			("http://example.org/a:b", Some(("ex", "a:b"))),
+			("http://example.org/a/b", None),
+			("http://example.org/a.", None),
+			("http://example.org/.a", None),
+			("http://example.org/-a", None),
+			("http://example.org/a?q", None),
+			("http://example.net/a", None),
+		];
+
+		for (input, expected) in vectors {
+			let iri = Iri::new(input).unwrap();
+			assert_eq!(map.compact(iri), expected, "{input}")
+		}
+	}
+
+	#[test]
+	fn round_trip() {
+		let map = map();
+		for input in [
+			"http://xmlns.com/foaf/0.1/knows",
+			"http://example.org/vocab#p",
+			"http://example.com/http-ns/x",
+		] {
+			let iri = Iri::new(input).unwrap();
+			let (prefix, suffix) = map.compact(iri).unwrap();
+			assert_eq!(map.expand(&format!("{prefix}:{suffix}")).unwrap(), iri)
+		}
+	}
+}
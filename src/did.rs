@@ -0,0 +1,605 @@
+//! W3C Decentralized Identifiers ([DID Core](https://www.w3.org/TR/did-core/)).
+//!
+//! A [`Did`] is a URI of the form `did:<method-name>:<method-specific-id>`.
+//! A [`DidUrl`] extends it with an optional path, query and fragment. Both
+//! types are always valid URIs (and IRIs), and can be converted from and to
+//! them without reallocation.
+use pct_str::PctStr;
+use str_newtype::StrNewType;
+
+use crate::{
+	Iri, Uri,
+	uri::{Fragment, Path, Query},
+};
+
+#[cfg(feature = "std")]
+use crate::{IriBuf, UriBuf};
+
+/// Decentralized Identifier (DID).
+///
+/// # Example
+///
+/// ```rust
+/// use iref::did::Did;
+///
+/// let did = Did::new("did:example:123456789abcdefghi").unwrap();
+/// assert_eq!(did.method(), "example");
+/// assert_eq!(did.method_specific_id(), "123456789abcdefghi");
+/// assert_eq!(did.as_uri().scheme(), "did");
+/// ```
+#[derive(StrNewType, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[newtype(name = "DID", ord([u8], &[u8], str, &str))]
+#[cfg_attr(
+	feature = "std",
+	newtype(ord(Vec<u8>, String), owned(DidBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))
+)]
+#[cfg_attr(feature = "serde", newtype(serde))]
+pub struct Did(str);
+
+/// Parses a [`Did`] at compile time.
+#[macro_export]
+macro_rules! did {
+	($value:literal) => {
+		match $crate::did::Did::from_str($value) {
+			Ok(value) => value,
+			Err(_) => panic!("invalid DID"),
+		}
+	};
+}
+
+impl Did {
+	/// Checks that the input string is a valid DID.
+	pub const fn validate_str(input: &str) -> bool {
+		Self::validate_bytes(input.as_bytes())
+	}
+
+	/// Checks that the input bytes are a valid DID.
+	pub const fn validate_bytes(input: &[u8]) -> bool {
+		match scan_did(input) {
+			Some(end) => end == input.len(),
+			None => false,
+		}
+	}
+
+	/// Returns the method name.
+	pub fn method(&self) -> &str {
+		method(self.as_str())
+	}
+
+	/// Returns the method-specific identifier.
+	pub fn method_specific_id(&self) -> &str {
+		&self.as_str()[5 + self.method().len()..]
+	}
+
+	/// Returns this DID as a DID URL.
+	pub fn as_did_url(&self) -> &DidUrl {
+		unsafe { DidUrl::new_unchecked(self.as_str()) }
+	}
+
+	/// Returns this DID as a URI.
+	pub fn as_uri(&self) -> &Uri {
+		unsafe { Uri::new_unchecked(self.as_str()) }
+	}
+
+	/// Returns this DID as an IRI.
+	pub fn as_iri(&self) -> &Iri {
+		unsafe { Iri::new_unchecked(self.as_str()) }
+	}
+}
+
+/// DID URL.
+///
+/// A [`Did`] followed by an optional path, query and fragment.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::did::DidUrl;
+///
+/// let url = DidUrl::new("did:example:123/path?service=agent&versionId=1#key-1").unwrap();
+/// assert_eq!(url.did(), "did:example:123");
+/// assert_eq!(url.path(), "/path");
+/// assert_eq!(url.service().unwrap(), "agent");
+/// assert_eq!(url.version_id().unwrap(), "1");
+/// assert_eq!(url.fragment().unwrap(), "key-1");
+/// ```
+#[derive(StrNewType, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[newtype(name = "DID URL", ord([u8], &[u8], str, &str))]
+#[cfg_attr(
+	feature = "std",
+	newtype(ord(Vec<u8>, String), owned(DidUrlBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))
+)]
+#[cfg_attr(feature = "serde", newtype(serde))]
+pub struct DidUrl(str);
+
+/// Parses a [`DidUrl`] at compile time.
+#[macro_export]
+macro_rules! did_url {
+	($value:literal) => {
+		match $crate::did::DidUrl::from_str($value) {
+			Ok(value) => value,
+			Err(_) => panic!("invalid DID URL"),
+		}
+	};
+}
+
+impl DidUrl {
+	/// Checks that the input string is a valid DID URL.
+	pub const fn validate_str(input: &str) -> bool {
+		Self::validate_bytes(input.as_bytes())
+	}
+
+	/// Checks that the input bytes are a valid DID URL.
+	pub const fn validate_bytes(input: &[u8]) -> bool {
+		let Some(mut i) = scan_did(input) else {
+			return false;
+		};
+
+		while i < input.len() && input[i] == b'/' {
+			i = match scan_pchars(input, i + 1, false) {
+				Some(j) => j,
+				None => return false,
+			};
+		}
+
+		if i < input.len() && input[i] == b'?' {
+			i = match scan_pchars(input, i + 1, true) {
+				Some(j) => j,
+				None => return false,
+			};
+		}
+
+		if i < input.len() && input[i] == b'#' {
+			i = match scan_pchars(input, i + 1, true) {
+				Some(j) => j,
+				None => return false,
+			};
+		}
+
+		i == input.len()
+	}
+
+	/// Returns the DID part of this DID URL.
+	pub fn did(&self) -> &Did {
+		let end = scan_did(self.as_bytes()).unwrap();
+		unsafe { Did::new_unchecked(&self.as_str()[..end]) }
+	}
+
+	/// Returns the method name.
+	pub fn method(&self) -> &str {
+		method(self.as_str())
+	}
+
+	/// Returns the method-specific identifier.
+	pub fn method_specific_id(&self) -> &str {
+		let did = self.did().as_str();
+		&did[5 + self.method().len()..]
+	}
+
+	/// Returns the path, which is empty or starts with `/`.
+	///
+	/// This is the DID URL path, following the method-specific identifier,
+	/// not the URI path (which includes the method name and identifier).
+	pub fn path(&self) -> &Path {
+		let start = self.did().len();
+		let rest = &self.as_str()[start..];
+		let end = rest.find(['?', '#']).unwrap_or(rest.len());
+		unsafe { Path::new_unchecked(&rest[..end]) }
+	}
+
+	/// Returns the query, if any.
+	pub fn query(&self) -> Option<&Query> {
+		self.as_uri().query()
+	}
+
+	/// Returns the fragment, if any.
+	pub fn fragment(&self) -> Option<&Fragment> {
+		self.as_uri().fragment()
+	}
+
+	/// Returns an iterator over the `name=value` parameters of the query.
+	///
+	/// Parameters are separated by `&`. A parameter without `=` has an
+	/// empty value.
+	pub fn parameters(&self) -> DidUrlParameters<'_> {
+		DidUrlParameters(self.query().map(|q| q.as_str().split('&')))
+	}
+
+	/// Returns the value of the first query parameter with the given name,
+	/// if any.
+	pub fn parameter(&self, name: &str) -> Option<&PctStr> {
+		self.parameters()
+			.find_map(|(n, value)| (n == name).then_some(value))
+	}
+
+	/// Returns the value of the `service` parameter, if any.
+	pub fn service(&self) -> Option<&PctStr> {
+		self.parameter("service")
+	}
+
+	/// Returns the value of the `relativeRef` parameter, if any.
+	pub fn relative_ref(&self) -> Option<&PctStr> {
+		self.parameter("relativeRef")
+	}
+
+	/// Returns the value of the `versionId` parameter, if any.
+	pub fn version_id(&self) -> Option<&PctStr> {
+		self.parameter("versionId")
+	}
+
+	/// Returns the value of the `versionTime` parameter, if any.
+	pub fn version_time(&self) -> Option<&PctStr> {
+		self.parameter("versionTime")
+	}
+
+	/// Returns the value of the `hl` (hashlink) parameter, if any.
+	pub fn hl(&self) -> Option<&PctStr> {
+		self.parameter("hl")
+	}
+
+	/// Returns this DID URL as a URI.
+	pub fn as_uri(&self) -> &Uri {
+		unsafe { Uri::new_unchecked(self.as_str()) }
+	}
+
+	/// Returns this DID URL as an IRI.
+	pub fn as_iri(&self) -> &Iri {
+		unsafe { Iri::new_unchecked(self.as_str()) }
+	}
+}
+
+/// Iterator over the query parameters of a [`DidUrl`].
+///
+/// See [`DidUrl::parameters`].
+pub struct DidUrlParameters<'a>(Option<core::str::Split<'a, char>>);
+
+impl<'a> Iterator for DidUrlParameters<'a> {
+	type Item = (&'a str, &'a PctStr);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let p = self.0.as_mut()?.next()?;
+		let (name, value) = p.split_once('=').unwrap_or((p, ""));
+		Some((name, unsafe { PctStr::new_unchecked(value) }))
+	}
+}
+
+macro_rules! conversions {
+	($($ty:ident: $error:ident, $buf:ident),*) => {
+		$(
+			impl AsRef<Uri> for $ty {
+				fn as_ref(&self) -> &Uri {
+					self.as_uri()
+				}
+			}
+
+			impl AsRef<Iri> for $ty {
+				fn as_ref(&self) -> &Iri {
+					self.as_iri()
+				}
+			}
+
+			impl<'a> From<&'a $ty> for &'a Uri {
+				fn from(value: &'a $ty) -> Self {
+					value.as_uri()
+				}
+			}
+
+			impl<'a> From<&'a $ty> for &'a Iri {
+				fn from(value: &'a $ty) -> Self {
+					value.as_iri()
+				}
+			}
+
+			impl<'a> TryFrom<&'a Uri> for &'a $ty {
+				type Error = $error<&'a Uri>;
+
+				fn try_from(value: &'a Uri) -> Result<Self, Self::Error> {
+					$ty::new(value.as_str()).map_err(|_| $error(value))
+				}
+			}
+
+			impl<'a> TryFrom<&'a Iri> for &'a $ty {
+				type Error = $error<&'a Iri>;
+
+				fn try_from(value: &'a Iri) -> Result<Self, Self::Error> {
+					$ty::new(value.as_str()).map_err(|_| $error(value))
+				}
+			}
+
+			#[cfg(feature = "std")]
+			impl $buf {
+				/// Converts this value into an owned URI, without reallocation.
+				pub fn into_uri(self) -> UriBuf {
+					unsafe { UriBuf::new_unchecked(self.0) }
+				}
+
+				/// Converts this value into an owned IRI, without reallocation.
+				pub fn into_iri(self) -> IriBuf {
+					unsafe { IriBuf::new_unchecked(self.0) }
+				}
+			}
+
+			#[cfg(feature = "std")]
+			impl AsRef<Uri> for $buf {
+				fn as_ref(&self) -> &Uri {
+					self.as_uri()
+				}
+			}
+
+			#[cfg(feature = "std")]
+			impl AsRef<Iri> for $buf {
+				fn as_ref(&self) -> &Iri {
+					self.as_iri()
+				}
+			}
+
+			#[cfg(feature = "std")]
+			impl From<$buf> for UriBuf {
+				fn from(value: $buf) -> Self {
+					value.into_uri()
+				}
+			}
+
+			#[cfg(feature = "std")]
+			impl From<$buf> for IriBuf {
+				fn from(value: $buf) -> Self {
+					value.into_iri()
+				}
+			}
+
+			#[cfg(feature = "std")]
+			impl TryFrom<UriBuf> for $buf {
+				type Error = $error<UriBuf>;
+
+				fn try_from(value: UriBuf) -> Result<Self, Self::Error> {
+					if $ty::validate_bytes(value.as_bytes()) {
+						Ok(unsafe { Self::new_unchecked(value.into_bytes()) })
+					} else {
+						Err($error(value))
+					}
+				}
+			}
+
+			#[cfg(feature = "std")]
+			impl TryFrom<IriBuf> for $buf {
+				type Error = $error<IriBuf>;
+
+				fn try_from(value: IriBuf) -> Result<Self, Self::Error> {
+					if $ty::validate_bytes(value.as_bytes()) {
+						Ok(unsafe { Self::new_unchecked(value.into_bytes()) })
+					} else {
+						Err($error(value))
+					}
+				}
+			}
+		)*
+	};
+}
+
+conversions!(Did: InvalidDid, DidBuf, DidUrl: InvalidDidUrl, DidUrlBuf);
+
+#[cfg(feature = "std")]
+impl DidBuf {
+	/// Converts this DID into a DID URL, without reallocation.
+	pub fn into_did_url(self) -> DidUrlBuf {
+		unsafe { DidUrlBuf::new_unchecked(self.0) }
+	}
+}
+
+#[cfg(feature = "std")]
+impl From<DidBuf> for DidUrlBuf {
+	fn from(value: DidBuf) -> Self {
+		value.into_did_url()
+	}
+}
+
+/// Returns the method name of a valid DID or DID URL.
+fn method(did: &str) -> &str {
+	let rest = &did[4..];
+	&rest[..rest.find(':').unwrap()]
+}
+
+/// Scans the DID at the start of the given bytes, returning its end.
+///
+/// ```abnf
+/// did                = "did:" method-name ":" method-specific-id
+/// method-name        = 1*method-char
+/// method-char        = %x61-7A / DIGIT
+/// method-specific-id = *( *idchar ":" ) 1*idchar
+/// idchar             = ALPHA / DIGIT / "." / "-" / "_" / pct-encoded
+/// ```
+const fn scan_did(bytes: &[u8]) -> Option<usize> {
+	if bytes.len() < 4
+		|| bytes[0] != b'd'
+		|| bytes[1] != b'i'
+		|| bytes[2] != b'd'
+		|| bytes[3] != b':'
+	{
+		return None;
+	}
+
+	let mut i = 4;
+	while i < bytes.len() && (bytes[i].is_ascii_lowercase() || bytes[i].is_ascii_digit()) {
+		i += 1
+	}
+
+	if i == 4 || i >= bytes.len() || bytes[i] != b':' {
+		return None;
+	}
+
+	i += 1;
+	let mut ends_with_idchar = false;
+	while i < bytes.len() {
+		match bytes[i] {
+			b':' => {
+				ends_with_idchar = false;
+				i += 1
+			}
+			b'%' => {
+				if !is_pct_encoded(bytes, i) {
+					return None;
+				}
+
+				ends_with_idchar = true;
+				i += 3
+			}
+			b if b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b'_') => {
+				ends_with_idchar = true;
+				i += 1
+			}
+			_ => break,
+		}
+	}
+
+	if ends_with_idchar { Some(i) } else { None }
+}
+
+/// Scans `*pchar` (or `*( pchar / "/" / "?" )` if `query` is `true`) from
+/// the given offset, returning the end offset.
+const fn scan_pchars(bytes: &[u8], mut i: usize, query: bool) -> Option<usize> {
+	while i < bytes.len() {
+		match bytes[i] {
+			b'%' => {
+				if !is_pct_encoded(bytes, i) {
+					return None;
+				}
+
+				i += 3
+			}
+			b'/' | b'?' if query => i += 1,
+			b if b.is_ascii_alphanumeric()
+				|| matches!(
+					b,
+					b'-' | b'.'
+						| b'_' | b'~' | b'!'
+						| b'$' | b'&' | b'\''
+						| b'(' | b')' | b'*'
+						| b'+' | b',' | b';'
+						| b'=' | b':' | b'@'
+				) =>
+			{
+				i += 1
+			}
+			_ => break,
+		}
+	}
+
+	Some(i)
+}
+
+const fn is_pct_encoded(bytes: &[u8], i: usize) -> bool {
+	i + 2 < bytes.len() && bytes[i + 1].is_ascii_hexdigit() && bytes[i + 2].is_ascii_hexdigit()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn valid_did() {
+		let vectors = [
+			"did:example:123456789abcdefghi",
+			"did:web:example.com",
+			"did:web:example.com%3A8080:user:alice",
+			"did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK",
+			"did:3:a_b-c.d",
+			"did:example::a",
+		];
+
+		for input in vectors {
+			assert!(Did::new(input).is_ok(), "{input}");
+			assert!(DidUrl::new(input).is_ok(), "{input}");
+			assert!(Uri::new(input).is_ok(), "{input}")
+		}
+	}
+
+	#[test]
+	fn invalid_did() {
+		let vectors = [
+			"",
+			"did:",
+			"did::abc",
+			"did:Example:abc",
+			"did:example",
+			"did:example:",
+			"did:example:abc:",
+			"did:example:a%2",
+			"did:example:a%zz",
+			"did:example:a b",
+			"DID:example:abc",
+			"did:example:abc/path",
+		];
+
+		for input in vectors {
+			assert!(Did::new(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn did_url() {
+		let vectors = [
+			"did:example:123/path/to/resource",
+			"did:example:123?service=agent&relativeRef=/credentials%23degree",
+			"did:example:123?versionTime=2021-05-10T17:00:00Z",
+			"did:example:123#public-key-0",
+			"did:example:123/?#",
+			"did:example:123//a",
+		];
+
+		for input in vectors {
+			let url = DidUrl::new(input).unwrap();
+			assert_eq!(url.did(), "did:example:123");
+			assert!(Uri::new(input).is_ok(), "{input}")
+		}
+
+		let invalid = [
+			"did:example:123:/path",
+			"did:example:123 /path",
+			"did:example:123?a b",
+			"did:example:123#a#b",
+			"did:example:123/%",
+		];
+
+		for input in invalid {
+			assert!(DidUrl::new(input).is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn accessors() {
+		let url = DidUrl::new("did:example:abc:def/p?service=files&hl=zQm&x#f").unwrap();
+		assert_eq!(url.method(), "example");
+		assert_eq!(url.method_specific_id(), "abc:def");
+		assert_eq!(url.path(), "/p");
+		assert_eq!(url.query().unwrap(), "service=files&hl=zQm&x");
+		assert_eq!(url.fragment().unwrap(), "f");
+		assert_eq!(url.service().unwrap(), "files");
+		assert_eq!(url.hl().unwrap(), "zQm");
+		assert_eq!(url.parameter("x").unwrap(), "");
+		assert_eq!(url.version_id(), None);
+		assert_eq!(url.parameters().count(), 3);
+
+		let did = url.did();
+		assert_eq!(did.method(), "example");
+		assert_eq!(did.method_specific_id(), "abc:def");
+		assert_eq!(did.as_did_url().path(), "");
+	}
+
+	#[test]
+	fn conversions() {
+		let iri = Iri::new("did:example:123#key").unwrap();
+		assert!(<&Did>::try_from(iri).is_err());
+		let url: &DidUrl = iri.try_into().unwrap();
+		assert_eq!(url.as_iri(), iri);
+
+		let buf = DidBuf::new("did:example:123".to_owned()).unwrap();
+		let ptr = buf.as_str().as_ptr();
+		let uri = buf.into_uri();
+		assert_eq!(uri.as_str().as_ptr(), ptr);
+		let iri = uri.into_iri();
+		let did = DidBuf::try_from(iri).unwrap();
+		assert_eq!(did.into_did_url().as_str().as_ptr(), ptr);
+
+		assert!(DidUrlBuf::try_from(UriBuf::new("http://example.org".to_owned()).unwrap()).is_err())
+	}
+}
@@ -0,0 +1,366 @@
+//! URI/IRI extraction from free text.
+//!
+//! The functions of this module locate absolute URIs/IRIs in prose, logs or
+//! Markdown. Candidates start at a word boundary with a scheme followed by
+//! `:`, and end before the first character that cannot appear in a URI/IRI.
+//! Trailing punctuation is then dropped, as well as closing parentheses and
+//! brackets without a matching opening one, so that the URI in
+//! `(see http://example.org/a).` is `http://example.org/a`. Finally, each
+//! candidate is validated against the URI/IRI grammar.
+//!
+//! URIs/IRIs may also be delimited by angle brackets, optionally with a
+//! `URL:` prefix, as recommended by [RFC 3986 Appendix C][rfc]. In that case
+//! the whole content of the brackets is taken as is. Since the results are
+//! borrowed from the input text, delimited URIs/IRIs containing whitespace
+//! (such as a URI broken across lines) are ignored.
+//!
+//! [rfc]: <https://datatracker.ietf.org/doc/html/rfc3986#appendix-C>
+use core::ops::Range;
+
+use crate::{
+	Iri, Uri,
+	common::parse::{is_scheme_char, looks_like_scheme},
+};
+
+/// Finds the absolute URIs in the given text.
+///
+/// Returns the byte range of each URI in the text, with the URI itself.
+/// See the [module documentation](self) for details.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::extract::find_uris;
+///
+/// let text = "See <https://example.org/a b> and (http://example.com/x_(y)).";
+/// let uris: Vec<_> = find_uris(text).map(|(_, uri)| uri.as_str()).collect();
+/// assert_eq!(uris, ["http://example.com/x_(y)"]);
+///
+/// let text = "Docs: <URL:https://docs.rs/iref>, or https://crates.io/crates/iref.";
+/// let uris: Vec<_> = find_uris(text).collect();
+/// assert_eq!(uris[0].0, 11..31);
+/// assert_eq!(uris[1].1, "https://crates.io/crates/iref");
+/// ```
+pub fn find_uris(text: &str) -> impl Iterator<Item = (Range<usize>, &Uri)> {
+	Matches::new(text, Kind::Uri).map(move |range| {
+		let uri = unsafe { Uri::new_unchecked(&text[range.clone()]) };
+		(range, uri)
+	})
+}
+
+/// Finds the absolute IRIs in the given text.
+///
+/// Returns the byte range of each IRI in the text, with the IRI itself.
+/// See the [module documentation](self) for details.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::extract::find_iris;
+///
+/// let text = "Le « https://fr.wikipedia.org/wiki/Café », voilà.";
+/// let iris: Vec<_> = find_iris(text).map(|(_, iri)| iri.as_str()).collect();
+/// assert_eq!(iris, ["https://fr.wikipedia.org/wiki/Café"]);
+/// ```
+pub fn find_iris(text: &str) -> impl Iterator<Item = (Range<usize>, &Iri)> {
+	Matches::new(text, Kind::Iri).map(move |range| {
+		let iri = unsafe { Iri::new_unchecked(&text[range.clone()]) };
+		(range, iri)
+	})
+}
+
+/// Kind of identifier to find.
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+	Uri,
+	Iri,
+}
+
+impl Kind {
+	/// Checks if the given character may appear in an identifier.
+	fn is_allowed(self, c: char) -> bool {
+		match c {
+			'a'..='z' | 'A'..='Z' | '0'..='9' => true,
+			'-' | '.' | '_' | '~' | '%' => true,
+			':' | '/' | '?' | '#' | '[' | ']' | '@' => true,
+			'!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '=' => true,
+			c => matches!(self, Self::Iri) && !c.is_ascii() && !c.is_whitespace(),
+		}
+	}
+
+	fn validate(self, s: &str) -> bool {
+		match self {
+			Self::Uri => Uri::validate_str(s),
+			Self::Iri => Iri::validate_str(s),
+		}
+	}
+}
+
+/// Checks if the given character is punctuation that is unlikely to end an
+/// identifier.
+fn is_trailing_punctuation(c: char) -> bool {
+	matches!(
+		c,
+		'.' | ',' | ';' | ':' | '!' | '?' | '\'' | '*' | '»' | '’' | '”' | '›' | '。' | '、'
+	)
+}
+
+/// Iterator over the identifier ranges of a text.
+struct Matches<'a> {
+	text: &'a str,
+	offset: usize,
+	kind: Kind,
+}
+
+impl<'a> Matches<'a> {
+	fn new(text: &'a str, kind: Kind) -> Self {
+		Self {
+			text,
+			offset: 0,
+			kind,
+		}
+	}
+
+	/// Finds an identifier delimited by angle brackets, where `start` is
+	/// the position of `<`.
+	///
+	/// Returns the identifier range, if any, and the position following
+	/// `>`. Identifiers containing whitespace cannot be borrowed from the
+	/// text, and are skipped.
+	fn delimited(&self, start: usize) -> Option<(Option<Range<usize>>, usize)> {
+		let bytes = self.text.as_bytes();
+		let mut start = start + 1;
+		if bytes.len() >= start + 4 && bytes[start..(start + 4)].eq_ignore_ascii_case(b"URL:") {
+			start += 4
+		}
+
+		if !looks_like_scheme(&bytes[start..]) {
+			return None;
+		}
+
+		let mut end = start;
+		let mut whitespace = false;
+		loop {
+			match bytes.get(end)? {
+				b'>' => break,
+				b'<' => return None,
+				b => {
+					whitespace |= b.is_ascii_whitespace();
+					end += 1
+				}
+			}
+		}
+
+		let content = &self.text[start..end];
+		if whitespace {
+			Some((None, end + 1))
+		} else if self.kind.validate(content) {
+			Some((Some(start..end), end + 1))
+		} else {
+			None
+		}
+	}
+
+	/// Finds an undelimited identifier starting with a scheme at `start`.
+	///
+	/// Returns the end of the identifier, or, if there is none, the end of
+	/// the run of allowed characters starting at `start`. Identifiers
+	/// starting inside this run are not searched, so that each character is
+	/// scanned a bounded number of times.
+	fn undelimited(&self, start: usize) -> Result<usize, usize> {
+		let candidate = &self.text[start..];
+		let mut end = candidate.len();
+		if let Some((i, c)) = candidate
+			.char_indices()
+			.find(|(_, c)| !self.kind.is_allowed(*c))
+		{
+			if c.is_alphanumeric() {
+				// The identifier would be cut in the middle of a word.
+				return Err(start + i);
+			}
+
+			end = i
+		}
+
+		let run_end = start + end;
+
+		// Number of unclosed `(` and `[`, negative when there are more closing
+		// delimiters than opening ones.
+		let mut parens = delimiter_balance(&candidate[..end], '(', ')');
+		let mut brackets = delimiter_balance(&candidate[..end], '[', ']');
+
+		while let Some(c) = candidate[..end].chars().next_back() {
+			let trim = match c {
+				')' => parens < 0,
+				']' => brackets < 0,
+				c => is_trailing_punctuation(c),
+			};
+
+			if !trim {
+				break;
+			}
+
+			match c {
+				')' => parens += 1,
+				']' => brackets += 1,
+				_ => (),
+			}
+
+			end -= c.len_utf8()
+		}
+
+		if self.kind.validate(&candidate[..end]) {
+			Ok(start + end)
+		} else {
+			Err(run_end)
+		}
+	}
+}
+
+/// Returns the number of `open` characters minus the number of `close`
+/// characters in `s`.
+fn delimiter_balance(s: &str, open: char, close: char) -> isize {
+	let mut balance = 0;
+	for c in s.chars() {
+		if c == open {
+			balance += 1
+		} else if c == close {
+			balance -= 1
+		}
+	}
+
+	balance
+}
+
+impl Iterator for Matches<'_> {
+	type Item = Range<usize>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let bytes = self.text.as_bytes();
+		while self.offset < bytes.len() {
+			let i = self.offset;
+
+			if bytes[i] == b'<'
+				&& let Some((range, end)) = self.delimited(i)
+			{
+				self.offset = end;
+				match range {
+					Some(range) => return Some(range),
+					None => continue,
+				}
+			}
+
+			if bytes[i].is_ascii_alphabetic()
+				&& (i == 0 || !is_scheme_char(bytes[i - 1]))
+				&& looks_like_scheme(&bytes[i..])
+			{
+				match self.undelimited(i) {
+					Ok(end) => {
+						self.offset = end;
+						return Some(i..end);
+					}
+					Err(run_end) => {
+						self.offset = run_end.max(i + 1);
+						continue;
+					}
+				}
+			}
+
+			self.offset += 1
+		}
+
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn uris() {
+		let vectors: [(&str, &[&str]); _] = [
+			("", &[]),
+			("no URI here: really", &[]),
+			("http://example.org", &["http://example.org"]),
+			("Go to http://example.org/a.", &["http://example.org/a"]),
+			(
+				"(see http://example.org/a), or http://example.org/b?",
+				&["http://example.org/a", "http://example.org/b"],
+			),
+			(
+				"https://en.wikipedia.org/wiki/Rust_(programming_language)",
+				&["https://en.wikipedia.org/wiki/Rust_(programming_language)"],
+			),
+			(
+				"[link](https://example.org/x) and [other]: https://example.org/y",
+				&["https://example.org/x", "https://example.org/y"],
+			),
+			("'http://[::1]:8080/'", &["http://[::1]:8080/"]),
+			("**https://example.org/**", &["https://example.org/"]),
+			("<http://example.org/a b>", &[]),
+			("<http://example.org/a\n  b> <http://c>", &["http://c"]),
+			("1 < 2, see http://a", &["http://a"]),
+			("<URL:mailto:a@example.org>", &["mailto:a@example.org"]),
+			("<urn:isbn:0451450523>.", &["urn:isbn:0451450523"]),
+			("<not a URI> urn:x", &["urn:x"]),
+			("xhttp://a http://b%zz http://c", &["xhttp://a", "http://c"]),
+			("a+b:c 1a:b", &["a+b:c"]),
+			("http://example.org/café", &[]),
+			(
+				"GET /index.html status=200 ref=https://example.org/?q=1",
+				&["https://example.org/?q=1"],
+			),
+		];
+
+		for (text, expected) in vectors {
+			let uris: Vec<_> = find_uris(text)
+				.map(|(range, uri)| {
+					assert_eq!(&text[range], uri.as_str());
+					uri.as_str()
+				})
+				.collect();
+			assert_eq!(uris, expected, "{text}")
+		}
+	}
+
+	#[test]
+	fn iris() {
+		let vectors: [(&str, &[&str]); _] = [
+			("http://example.org/café", &["http://example.org/café"]),
+			("“http://例え.jp/パス”。", &["http://例え.jp/パス"]),
+			("http://example.org/a\u{3000}b", &["http://example.org/a"]),
+			("<http://example.org/é>", &["http://example.org/é"]),
+		];
+
+		for (text, expected) in vectors {
+			let iris: Vec<_> = find_iris(text)
+				.map(|(range, iri)| {
+					assert_eq!(&text[range], iri.as_str());
+					iri.as_str()
+				})
+				.collect();
+			assert_eq!(iris, expected, "{text}")
+		}
+	}
+
+	#[test]
+	fn closing_delimiters() {
+		let n = 100_000;
+		let text = format!("(http://a/(b){}", ")".repeat(n));
+		let uris: Vec<_> = find_uris(&text).map(|(_, uri)| uri.as_str()).collect();
+		assert_eq!(uris, ["http://a/(b)"]);
+
+		let text = format!("http://a/{}{}", "(".repeat(n), ")".repeat(n + 1));
+		let uris: Vec<_> = find_uris(&text).map(|(range, _)| range.end).collect();
+		assert_eq!(uris, [text.len() - 1])
+	}
+
+	#[test]
+	fn invalid_runs() {
+		let n = 1_000_000;
+		let text = format!("{}%zz http://a", "a:/".repeat(n));
+		let uris: Vec<_> = find_uris(&text).map(|(_, uri)| uri.as_str()).collect();
+		assert_eq!(uris, ["http://a"])
+	}
+}
@@ -0,0 +1,385 @@
+//! IRI interning.
+use std::{
+	collections::{BTreeSet, HashMap},
+	ops::Bound,
+	sync::RwLock,
+};
+
+use crate::Iri;
+
+/// Size of the storage chunks allocated by an [`IriInterner`].
+const CHUNK_CAPACITY: usize = 4096;
+
+/// Interned IRI identifier.
+///
+/// Identifiers are only meaningful for the [`IriInterner`] that returned
+/// them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IriId(u32);
+
+impl IriId {
+	/// Returns the index of this identifier, in order of interning.
+	pub fn index(&self) -> usize {
+		self.0 as usize
+	}
+}
+
+/// IRI interner deduplication mode.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Dedup {
+	/// Equivalent IRIs, according to the [`PartialEq`] implementation of
+	/// [`Iri`], share the same identifier. The first interned form is the
+	/// one returned by [`IriInterner::resolve`].
+	#[default]
+	Equivalence,
+
+	/// Only byte-identical IRIs share the same identifier.
+	Exact,
+}
+
+/// IRI interner options.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InternerOptions {
+	/// Deduplication mode.
+	pub dedup: Dedup,
+
+	/// Reuse the storage of an already interned IRI when the new IRI is a
+	/// prefix of it (for instance a namespace interned after its terms).
+	///
+	/// This saves memory at the cost of an additional ordered index.
+	pub prefix_sharing: bool,
+}
+
+/// IRI interner.
+///
+/// Deduplicates IRIs and hands out [`IriId`]s that resolve back to `&Iri` in
+/// constant time. Interned IRIs are stored in large append-only chunks and
+/// are never freed before the interner itself.
+///
+/// The interner is thread-safe: it can be shared between threads using an
+/// [`Arc`](std::sync::Arc), and interning only requires a shared reference.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Iri, interner::IriInterner};
+///
+/// let interner = IriInterner::new();
+/// let a = interner.intern(Iri::new("https://example.org/foo").unwrap());
+/// let b = interner.intern(Iri::new("HTTPS://example.org/%66oo").unwrap());
+///
+/// assert_eq!(a, b);
+/// assert_eq!(interner.resolve(a), "https://example.org/foo");
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct IriInterner {
+	options: InternerOptions,
+	inner: RwLock<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+	/// Storage chunks.
+	///
+	/// Chunks are never reallocated: a string is only pushed to a chunk if
+	/// it fits in its remaining capacity.
+	chunks: Vec<String>,
+
+	/// Interned IRIs, pointing into `chunks`.
+	///
+	/// The `'static` lifetime is a lie: the references are valid as long as
+	/// the chunks are, and never escape with this lifetime.
+	entries: Vec<&'static Iri>,
+
+	/// Deduplication index.
+	index: Index,
+
+	/// Stored IRIs, sorted by bytes, for prefix sharing.
+	sorted: BTreeSet<&'static str>,
+}
+
+#[derive(Debug)]
+enum Index {
+	Equivalence(HashMap<&'static Iri, IriId>),
+	Exact(HashMap<&'static str, IriId>),
+}
+
+impl Default for Index {
+	fn default() -> Self {
+		Self::Equivalence(HashMap::new())
+	}
+}
+
+impl Index {
+	fn new(dedup: Dedup) -> Self {
+		match dedup {
+			Dedup::Equivalence => Self::Equivalence(HashMap::new()),
+			Dedup::Exact => Self::Exact(HashMap::new()),
+		}
+	}
+
+	fn get(&self, iri: &Iri) -> Option<IriId> {
+		match self {
+			Self::Equivalence(map) => map.get(iri).copied(),
+			Self::Exact(map) => map.get(iri.as_str()).copied(),
+		}
+	}
+
+	fn insert(&mut self, iri: &'static Iri, id: IriId) {
+		match self {
+			Self::Equivalence(map) => map.insert(iri, id),
+			Self::Exact(map) => map.insert(iri.as_str(), id),
+		};
+	}
+}
+
+impl IriInterner {
+	/// Creates a new interner deduplicating equivalent IRIs, without prefix
+	/// sharing.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates a new interner with the given options.
+	pub fn with_options(options: InternerOptions) -> Self {
+		Self {
+			options,
+			inner: RwLock::new(Inner {
+				index: Index::new(options.dedup),
+				..Default::default()
+			}),
+		}
+	}
+
+	/// Returns the options of this interner.
+	pub fn options(&self) -> InternerOptions {
+		self.options
+	}
+
+	/// Returns the number of interned IRIs.
+	pub fn len(&self) -> usize {
+		self.inner.read().unwrap().entries.len()
+	}
+
+	/// Checks if no IRI has been interned yet.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns the number of bytes used to store the interned IRIs.
+	pub fn stored_bytes(&self) -> usize {
+		self.inner
+			.read()
+			.unwrap()
+			.chunks
+			.iter()
+			.map(String::len)
+			.sum()
+	}
+
+	/// Returns the identifier of the given IRI, if it has been interned.
+	pub fn get(&self, iri: &Iri) -> Option<IriId> {
+		self.inner.read().unwrap().index.get(iri)
+	}
+
+	/// Interns the given IRI, returning its identifier.
+	///
+	/// # Panics
+	///
+	/// Panics if more than `u32::MAX` IRIs are interned.
+	pub fn intern(&self, iri: &Iri) -> IriId {
+		if let Some(id) = self.get(iri) {
+			return id;
+		}
+
+		let mut inner = self.inner.write().unwrap();
+
+		// Another thread may have interned it in the meantime.
+		if let Some(id) = inner.index.get(iri) {
+			return id;
+		}
+
+		let id = IriId(
+			inner
+				.entries
+				.len()
+				.try_into()
+				.expect("too many interned IRIs"),
+		);
+
+		let shared = if self.options.prefix_sharing {
+			inner
+				.sorted
+				.range::<str, _>((Bound::Included(iri.as_str()), Bound::Unbounded))
+				.next()
+				.copied()
+				.filter(|s| s.starts_with(iri.as_str()))
+				.map(|s| &s[..iri.len()])
+		} else {
+			None
+		};
+
+		let stored = match shared {
+			Some(stored) => stored,
+			None => {
+				let stored = inner.store(iri.as_str());
+				if self.options.prefix_sharing {
+					inner.sorted.insert(stored);
+				}
+
+				stored
+			}
+		};
+
+		// SAFETY: `stored` is a copy of `iri`.
+		let stored = unsafe { Iri::new_unchecked(stored) };
+		inner.entries.push(stored);
+		inner.index.insert(stored, id);
+		id
+	}
+
+	/// Returns the IRI with the given identifier, if any.
+	pub fn try_resolve(&self, id: IriId) -> Option<&Iri> {
+		let iri: &'static Iri = self
+			.inner
+			.read()
+			.unwrap()
+			.entries
+			.get(id.index())
+			.copied()?;
+		// Shorten the lifetime to the interner's.
+		Some(iri)
+	}
+
+	/// Returns the IRI with the given identifier.
+	///
+	/// # Panics
+	///
+	/// Panics if the identifier was not returned by this interner.
+	pub fn resolve(&self, id: IriId) -> &Iri {
+		self.try_resolve(id).expect("unknown IRI identifier")
+	}
+}
+
+impl Inner {
+	/// Copies the given string into the chunks.
+	fn store(&mut self, value: &str) -> &'static str {
+		let fits = self
+			.chunks
+			.last()
+			.is_some_and(|chunk| chunk.capacity() - chunk.len() >= value.len());
+
+		if !fits {
+			self.chunks
+				.push(String::with_capacity(CHUNK_CAPACITY.max(value.len())));
+		}
+
+		let chunk = self.chunks.last_mut().unwrap();
+		let start = chunk.len();
+		chunk.push_str(value);
+
+		// SAFETY: the chunk has enough capacity for `value` and is
+		//         never reallocated nor dropped before the interner.
+		unsafe { &*(&chunk[start..] as *const str) }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use super::*;
+
+	fn iri(s: &str) -> &Iri {
+		Iri::new(s).unwrap()
+	}
+
+	#[test]
+	fn equivalence() {
+		let interner = IriInterner::new();
+		let a = interner.intern(iri("http://example.org/a"));
+		let b = interner.intern(iri("http://example.org/b"));
+		assert_ne!(a, b);
+		assert_eq!(interner.intern(iri("HTTP://example.org/%61")), a);
+		assert_eq!(interner.intern(iri("http://example.org/./a")), a);
+		assert_eq!(interner.get(iri("http://example.org/b")), Some(b));
+		assert_eq!(interner.get(iri("http://example.org/c")), None);
+		assert_eq!(interner.resolve(a), "http://example.org/a");
+		assert_eq!(interner.len(), 2);
+	}
+
+	#[test]
+	fn exact() {
+		let interner = IriInterner::with_options(InternerOptions {
+			dedup: Dedup::Exact,
+			..Default::default()
+		});
+
+		let a = interner.intern(iri("http://example.org/a"));
+		let b = interner.intern(iri("HTTP://example.org/a"));
+		assert_ne!(a, b);
+		assert_eq!(interner.intern(iri("http://example.org/a")), a);
+		assert_eq!(interner.resolve(b).as_str(), "HTTP://example.org/a");
+	}
+
+	#[test]
+	fn resolve() {
+		let interner = IriInterner::new();
+		let ids: Vec<_> = (0..2000)
+			.map(|i| interner.intern(iri(&format!("http://example.org/{i}"))))
+			.collect();
+
+		assert!(interner.inner.read().unwrap().chunks.len() > 1);
+		for (i, id) in ids.into_iter().enumerate() {
+			assert_eq!(id.index(), i);
+			assert_eq!(
+				interner.resolve(id).as_str(),
+				format!("http://example.org/{i}")
+			)
+		}
+
+		let long = format!("http://example.org/{}", "a".repeat(2 * CHUNK_CAPACITY));
+		let id = interner.intern(iri(&long));
+		assert_eq!(interner.resolve(id).as_str(), long);
+		assert_eq!(interner.try_resolve(IriId(u32::MAX)), None);
+	}
+
+	#[test]
+	fn prefix_sharing() {
+		let interner = IriInterner::with_options(InternerOptions {
+			prefix_sharing: true,
+			..Default::default()
+		});
+
+		let term = interner.intern(iri("http://xmlns.com/foaf/0.1/name"));
+		let bytes = interner.stored_bytes();
+		let ns = interner.intern(iri("http://xmlns.com/foaf/0.1/"));
+		assert_ne!(term, ns);
+		assert_eq!(interner.stored_bytes(), bytes);
+		assert_eq!(interner.resolve(ns), "http://xmlns.com/foaf/0.1/");
+		assert_eq!(interner.resolve(term), "http://xmlns.com/foaf/0.1/name");
+
+		interner.intern(iri("http://xmlns.com/other"));
+		assert!(interner.stored_bytes() > bytes);
+	}
+
+	#[test]
+	fn threads() {
+		let interner = Arc::new(IriInterner::new());
+		let handles: Vec<_> = (0..4)
+			.map(|_| {
+				let interner = interner.clone();
+				std::thread::spawn(move || {
+					(0..100)
+						.map(|i| interner.intern(iri(&format!("http://example.org/{i}"))))
+						.collect::<Vec<_>>()
+				})
+			})
+			.collect();
+
+		let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+		assert!(results.windows(2).all(|w| w[0] == w[1]));
+		assert_eq!(interner.len(), 100)
+	}
+}
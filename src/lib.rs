@@ -209,7 +209,19 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod common;
+#[cfg(feature = "std")]
+pub mod curie;
+pub mod did;
+pub mod extract;
+#[cfg(feature = "std")]
+pub mod interner;
 pub mod iri;
+#[cfg(feature = "psl")]
+pub mod psl;
+#[cfg(feature = "security")]
+pub mod security;
+#[cfg(feature = "std")]
+pub mod trie;
 pub mod uri;
 mod uri_iri;
 
@@ -0,0 +1,329 @@
+//! Public Suffix List.
+//!
+//! The [Public Suffix List] (PSL) lists the domains under which Internet
+//! users can directly register names, such as `com` or `co.uk`. It is used to
+//! find the registrable domain of a host (`example.co.uk` for
+//! `www.example.co.uk`), which defines cookie scopes and "sites".
+//!
+//! A snapshot of the list, provided by the [`iref-psl-data`] crate, is
+//! compiled into the crate and available with [`PublicSuffixList::builtin`].
+//! Other versions of the list can be loaded with
+//! [`PublicSuffixList::parse`].
+//!
+//! Domains are matched label by label, after converting Punycode labels
+//! (`xn--...`) into Unicode and lowercasing, so that rules apply to both the
+//! Unicode form of a domain (as found in IRIs) and its Punycode form (as found
+//! in URIs).
+//!
+//! The list is distributed under the terms of the Mozilla Public License,
+//! v. 2.0, which also applies to the [`iref-psl-data`] crate.
+//!
+//! [Public Suffix List]: <https://publicsuffix.org/>
+//! [`iref-psl-data`]: <https://docs.rs/iref-psl-data>
+use std::{collections::HashSet, sync::OnceLock};
+
+use crate::common::punycode;
+
+/// Public Suffix List snapshot compiled into the crate.
+const BUILTIN: &str = iref_psl_data::DATA;
+
+/// Public Suffix List.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::psl::PublicSuffixList;
+///
+/// let list = PublicSuffixList::builtin();
+/// assert_eq!(list.public_suffix("www.example.co.uk"), Some("co.uk"));
+/// assert_eq!(list.registrable_domain("www.example.co.uk"), Some("example.co.uk"));
+/// assert_eq!(list.registrable_domain("co.uk"), None);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PublicSuffixList {
+	/// Normal rules.
+	rules: HashSet<String>,
+
+	/// Wildcard rules, without the leading `*.` label.
+	wildcards: HashSet<String>,
+
+	/// Exception rules, without the leading `!`.
+	exceptions: HashSet<String>,
+}
+
+impl PublicSuffixList {
+	/// Parses a list in the [PSL format].
+	///
+	/// Comments, blank lines and invalid rules are ignored. Rules from both
+	/// the ICANN and private sections of the list are used.
+	///
+	/// [PSL format]: <https://github.com/publicsuffix/list/wiki/Format>
+	pub fn parse(data: &str) -> Self {
+		let mut result = Self::default();
+
+		for line in data.lines() {
+			let Some(rule) = line.split_whitespace().next() else {
+				continue;
+			};
+
+			if rule.starts_with("//") {
+				continue;
+			}
+
+			if let Some(rule) = rule.strip_prefix('!') {
+				if let Some(rule) = normalize(rule) {
+					result.exceptions.insert(rule);
+				}
+			} else if let Some(rule) = rule.strip_prefix("*.") {
+				if let Some(rule) = normalize(rule) {
+					result.wildcards.insert(rule);
+				}
+			} else if let Some(rule) = normalize(rule) {
+				result.rules.insert(rule);
+			}
+		}
+
+		result
+	}
+
+	/// Returns the list snapshot compiled into the crate.
+	///
+	/// The list is parsed on first use.
+	pub fn builtin() -> &'static Self {
+		static LIST: OnceLock<PublicSuffixList> = OnceLock::new();
+		LIST.get_or_init(|| Self::parse(BUILTIN))
+	}
+
+	/// Returns the number of labels of the public suffix of the given
+	/// normalized labels.
+	fn suffix_len(&self, labels: &[String]) -> usize {
+		// Default rule: `*`.
+		let mut result = 1;
+
+		let mut suffix = String::new();
+		for (i, label) in labels.iter().rev().enumerate() {
+			if i > 0 {
+				suffix.insert(0, '.')
+			}
+
+			suffix.insert_str(0, label);
+
+			let len = i + 1;
+			if self.exceptions.contains(&suffix) {
+				// Exception rules prevail.
+				return len - 1;
+			}
+
+			if self.rules.contains(&suffix) {
+				result = result.max(len)
+			}
+
+			if len < labels.len() && self.wildcards.contains(&suffix) {
+				result = result.max(len + 1)
+			}
+		}
+
+		result
+	}
+
+	/// Returns the public suffix of the given domain.
+	///
+	/// The result is a suffix of the input. A trailing dot, if any, is
+	/// not part of the result. Returns `None` if the domain is empty or
+	/// has an empty label.
+	pub fn public_suffix<'a>(&self, domain: &'a str) -> Option<&'a str> {
+		let (domain, labels) = split(domain)?;
+		let len = self.suffix_len(&labels);
+		Some(last_labels(domain, len))
+	}
+
+	/// Returns the registrable domain of the given domain: its public
+	/// suffix and the label before it.
+	///
+	/// The result is a suffix of the input. A trailing dot, if any, is
+	/// not part of the result. Returns `None` if the domain is empty, has an
+	/// empty label, or is a public suffix.
+	pub fn registrable_domain<'a>(&self, domain: &'a str) -> Option<&'a str> {
+		let (domain, labels) = split(domain)?;
+		let len = self.suffix_len(&labels) + 1;
+		if len <= labels.len() {
+			Some(last_labels(domain, len))
+		} else {
+			None
+		}
+	}
+}
+
+/// Normalizes a domain label, decoding Punycode and lowercasing.
+pub(crate) fn normalize_label(label: &str) -> Option<String> {
+	let label = match label.get(..4) {
+		Some(prefix) if prefix.eq_ignore_ascii_case("xn--") => punycode::decode(&label[4..])?,
+		_ => label.to_owned(),
+	};
+
+	Some(label.to_lowercase())
+}
+
+/// Normalizes a domain, label by label.
+pub(crate) fn normalize(domain: &str) -> Option<String> {
+	let (_, labels) = split(domain)?;
+	Some(labels.join("."))
+}
+
+/// Removes the trailing dot of the given domain, and returns its normalized
+/// labels.
+fn split(domain: &str) -> Option<(&str, Vec<String>)> {
+	let domain = domain.strip_suffix('.').unwrap_or(domain);
+	let labels = domain
+		.split('.')
+		.map(|label| {
+			if label.is_empty() {
+				None
+			} else {
+				normalize_label(label)
+			}
+		})
+		.collect::<Option<Vec<_>>>()?;
+
+	Some((domain, labels))
+}
+
+/// Returns the last `n` labels of the given domain.
+fn last_labels(domain: &str, n: usize) -> &str {
+	match domain.rmatch_indices('.').nth(n - 1) {
+		Some((i, _)) => &domain[(i + 1)..],
+		None => domain,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Test vectors from the PSL project (`tests/test_psl.txt`).
+	#[test]
+	fn registrable_domain() {
+		let list = PublicSuffixList::builtin();
+		let vectors = [
+			// Unlisted TLD.
+			("example", None),
+			("example.example", Some("example.example")),
+			("b.example.example", Some("example.example")),
+			// TLD with only one rule.
+			("biz", None),
+			("domain.biz", Some("domain.biz")),
+			("a.b.domain.biz", Some("domain.biz")),
+			// TLD with some two-level rules.
+			("com", None),
+			("example.com", Some("example.com")),
+			("b.example.com", Some("example.com")),
+			("uk.com", None),
+			("example.uk.com", Some("example.uk.com")),
+			("b.example.uk.com", Some("example.uk.com")),
+			("test.ac", Some("test.ac")),
+			// TLD with only one wildcard rule.
+			("mm", None),
+			("c.mm", None),
+			("b.c.mm", Some("b.c.mm")),
+			("a.b.c.mm", Some("b.c.mm")),
+			// More complex TLD.
+			("jp", None),
+			("test.jp", Some("test.jp")),
+			("www.test.jp", Some("test.jp")),
+			("ac.jp", None),
+			("test.ac.jp", Some("test.ac.jp")),
+			("kyoto.jp", None),
+			("test.kyoto.jp", Some("test.kyoto.jp")),
+			("ide.kyoto.jp", None),
+			("b.ide.kyoto.jp", Some("b.ide.kyoto.jp")),
+			("c.kobe.jp", None),
+			("b.c.kobe.jp", Some("b.c.kobe.jp")),
+			("city.kobe.jp", Some("city.kobe.jp")),
+			("www.city.kobe.jp", Some("city.kobe.jp")),
+			// TLD with a wildcard rule and exceptions.
+			("ck", None),
+			("test.ck", None),
+			("b.test.ck", Some("b.test.ck")),
+			("www.ck", Some("www.ck")),
+			("www.www.ck", Some("www.ck")),
+			// US K12.
+			("us", None),
+			("test.us", Some("test.us")),
+			("k12.ak.us", None),
+			("test.k12.ak.us", Some("test.k12.ak.us")),
+			// IDN labels.
+			("食狮.com.cn", Some("食狮.com.cn")),
+			("食狮.公司.cn", Some("食狮.公司.cn")),
+			("www.食狮.公司.cn", Some("食狮.公司.cn")),
+			("公司.cn", None),
+			("中国", None),
+			("食狮.中国", Some("食狮.中国")),
+			// Punycode labels.
+			("xn--85x722f.com.cn", Some("xn--85x722f.com.cn")),
+			(
+				"xn--85x722f.xn--55qx5d.cn",
+				Some("xn--85x722f.xn--55qx5d.cn"),
+			),
+			(
+				"www.xn--85x722f.xn--55qx5d.cn",
+				Some("xn--85x722f.xn--55qx5d.cn"),
+			),
+			("xn--55qx5d.cn", None),
+			("xn--fiqs8s", None),
+			("www.xn--85x722f.xn--fiqs8s", Some("xn--85x722f.xn--fiqs8s")),
+			// Case and trailing dot.
+			("WWW.Example.COM", Some("Example.COM")),
+			("www.example.com.", Some("example.com")),
+			// Invalid domains.
+			("", None),
+			(".com", None),
+			("a..com", None),
+		];
+
+		for (input, expected) in vectors {
+			assert_eq!(list.registrable_domain(input), expected, "{input}")
+		}
+	}
+
+	#[test]
+	fn public_suffix() {
+		let list = PublicSuffixList::builtin();
+		let vectors = [
+			("example", Some("example")),
+			("www.example.co.uk", Some("co.uk")),
+			("a.b.c.mm", Some("c.mm")),
+			("www.ck", Some("ck")),
+			("www.xn--85x722f.xn--55qx5d.cn", Some("xn--55qx5d.cn")),
+			("a..b", None),
+		];
+
+		for (input, expected) in vectors {
+			assert_eq!(list.public_suffix(input), expected, "{input}")
+		}
+	}
+
+	#[test]
+	fn iri_same_site() {
+		use crate::{Iri, iri::same_site};
+
+		let a = Iri::new("https://www.食狮.公司.cn/").unwrap();
+		let b = Iri::new("https://XN--85X722F.xn--55qx5d.cn/").unwrap();
+		let c = Iri::new("https://其他.公司.cn/").unwrap();
+		assert_eq!(
+			a.authority().unwrap().host().registrable_domain().unwrap(),
+			"食狮.公司.cn"
+		);
+		assert!(same_site(a, b));
+		assert!(!same_site(a, c))
+	}
+
+	#[test]
+	fn parse() {
+		let list =
+			PublicSuffixList::parse("// comment\n\ncom\n*.example\n!www.example  trailing\n");
+		assert_eq!(list.registrable_domain("a.com"), Some("a.com"));
+		assert_eq!(list.registrable_domain("a.b.example"), Some("a.b.example"));
+		assert_eq!(list.registrable_domain("www.example"), Some("www.example"));
+		assert_eq!(list.registrable_domain("b.example"), None);
+	}
+}
@@ -0,0 +1,1572 @@
+//! Characters confusable with ASCII characters.
+//!
+//! Generated by `scripts/gen_confusables.py` from the [UTS #39] confusables
+//! data, version 16.0.0, keeping the non-ASCII characters whose prototype
+//! is a single printable ASCII character. Do not edit.
+//!
+//! The Unicode data is distributed under the terms of the Unicode License
+//! v3 (see `LICENSE-UNICODE`).
+//!
+//! [UTS #39]: <https://www.unicode.org/reports/tr39/#Confusable_Detection>
+
+/// Confusable characters and their ASCII prototype, sorted by character.
+const CONFUSABLES: [(char, char); 1545] = [
+	('\u{00B4}', '\''),  // ACUTE ACCENT
+	('\u{00B8}', ','),   // CEDILLA
+	('\u{00D7}', 'x'),   // MULTIPLICATION SIGN
+	('\u{0131}', 'i'),   // LATIN SMALL LETTER DOTLESS I
+	('\u{017F}', 'f'),   // LATIN SMALL LETTER LONG S
+	('\u{0184}', 'b'),   // LATIN CAPITAL LETTER TONE SIX
+	('\u{018D}', 'g'),   // LATIN SMALL LETTER TURNED DELTA
+	('\u{0196}', 'l'),   // LATIN CAPITAL LETTER IOTA
+	('\u{01A6}', 'R'),   // LATIN LETTER YR
+	('\u{01A7}', '2'),   // LATIN CAPITAL LETTER TONE TWO
+	('\u{01B7}', '3'),   // LATIN CAPITAL LETTER EZH
+	('\u{01BC}', '5'),   // LATIN CAPITAL LETTER TONE FIVE
+	('\u{01BD}', 's'),   // LATIN SMALL LETTER TONE FIVE
+	('\u{01C0}', 'l'),   // LATIN LETTER DENTAL CLICK
+	('\u{01C3}', '!'),   // LATIN LETTER RETROFLEX CLICK
+	('\u{021C}', '3'),   // LATIN CAPITAL LETTER YOGH
+	('\u{0222}', '8'),   // LATIN CAPITAL LETTER OU
+	('\u{0223}', '8'),   // LATIN SMALL LETTER OU
+	('\u{0241}', '?'),   // LATIN CAPITAL LETTER GLOTTAL STOP
+	('\u{0251}', 'a'),   // LATIN SMALL LETTER ALPHA
+	('\u{0261}', 'g'),   // LATIN SMALL LETTER SCRIPT G
+	('\u{0263}', 'y'),   // LATIN SMALL LETTER GAMMA
+	('\u{0269}', 'i'),   // LATIN SMALL LETTER IOTA
+	('\u{026A}', 'i'),   // LATIN LETTER SMALL CAPITAL I
+	('\u{026F}', 'w'),   // LATIN SMALL LETTER TURNED M
+	('\u{028B}', 'u'),   // LATIN SMALL LETTER V WITH HOOK
+	('\u{028F}', 'y'),   // LATIN LETTER SMALL CAPITAL Y
+	('\u{0294}', '?'),   // LATIN LETTER GLOTTAL STOP
+	('\u{02B9}', '\''),  // MODIFIER LETTER PRIME
+	('\u{02BB}', '\''),  // MODIFIER LETTER TURNED COMMA
+	('\u{02BC}', '\''),  // MODIFIER LETTER APOSTROPHE
+	('\u{02BD}', '\''),  // MODIFIER LETTER REVERSED COMMA
+	('\u{02BE}', '\''),  // MODIFIER LETTER RIGHT HALF RING
+	('\u{02C2}', '<'),   // MODIFIER LETTER LEFT ARROWHEAD
+	('\u{02C3}', '>'),   // MODIFIER LETTER RIGHT ARROWHEAD
+	('\u{02C4}', '^'),   // MODIFIER LETTER UP ARROWHEAD
+	('\u{02C6}', '^'),   // MODIFIER LETTER CIRCUMFLEX ACCENT
+	('\u{02C8}', '\''),  // MODIFIER LETTER VERTICAL LINE
+	('\u{02CA}', '\''),  // MODIFIER LETTER ACUTE ACCENT
+	('\u{02CB}', '\''),  // MODIFIER LETTER GRAVE ACCENT
+	('\u{02D0}', ':'),   // MODIFIER LETTER TRIANGULAR COLON
+	('\u{02D7}', '-'),   // MODIFIER LETTER MINUS SIGN
+	('\u{02DB}', 'i'),   // OGONEK
+	('\u{02DC}', '~'),   // SMALL TILDE
+	('\u{02F4}', '\''),  // MODIFIER LETTER MIDDLE GRAVE ACCENT
+	('\u{02F8}', ':'),   // MODIFIER LETTER RAISED COLON
+	('\u{0374}', '\''),  // GREEK NUMERAL SIGN
+	('\u{037A}', 'i'),   // GREEK YPOGEGRAMMENI
+	('\u{037E}', ';'),   // GREEK QUESTION MARK
+	('\u{037F}', 'J'),   // GREEK CAPITAL LETTER YOT
+	('\u{0384}', '\''),  // GREEK TONOS
+	('\u{0391}', 'A'),   // GREEK CAPITAL LETTER ALPHA
+	('\u{0392}', 'B'),   // GREEK CAPITAL LETTER BETA
+	('\u{0395}', 'E'),   // GREEK CAPITAL LETTER EPSILON
+	('\u{0396}', 'Z'),   // GREEK CAPITAL LETTER ZETA
+	('\u{0397}', 'H'),   // GREEK CAPITAL LETTER ETA
+	('\u{0399}', 'l'),   // GREEK CAPITAL LETTER IOTA
+	('\u{039A}', 'K'),   // GREEK CAPITAL LETTER KAPPA
+	('\u{039C}', 'M'),   // GREEK CAPITAL LETTER MU
+	('\u{039D}', 'N'),   // GREEK CAPITAL LETTER NU
+	('\u{039F}', 'O'),   // GREEK CAPITAL LETTER OMICRON
+	('\u{03A1}', 'P'),   // GREEK CAPITAL LETTER RHO
+	('\u{03A4}', 'T'),   // GREEK CAPITAL LETTER TAU
+	('\u{03A5}', 'Y'),   // GREEK CAPITAL LETTER UPSILON
+	('\u{03A7}', 'X'),   // GREEK CAPITAL LETTER CHI
+	('\u{03B1}', 'a'),   // GREEK SMALL LETTER ALPHA
+	('\u{03B3}', 'y'),   // GREEK SMALL LETTER GAMMA
+	('\u{03B9}', 'i'),   // GREEK SMALL LETTER IOTA
+	('\u{03BD}', 'v'),   // GREEK SMALL LETTER NU
+	('\u{03BF}', 'o'),   // GREEK SMALL LETTER OMICRON
+	('\u{03C1}', 'p'),   // GREEK SMALL LETTER RHO
+	('\u{03C3}', 'o'),   // GREEK SMALL LETTER SIGMA
+	('\u{03C5}', 'u'),   // GREEK SMALL LETTER UPSILON
+	('\u{03D2}', 'Y'),   // GREEK UPSILON WITH HOOK SYMBOL
+	('\u{03DC}', 'F'),   // GREEK LETTER DIGAMMA
+	('\u{03E8}', '2'),   // COPTIC CAPITAL LETTER HORI
+	('\u{03F1}', 'p'),   // GREEK RHO SYMBOL
+	('\u{03F2}', 'c'),   // GREEK LUNATE SIGMA SYMBOL
+	('\u{03F3}', 'j'),   // GREEK LETTER YOT
+	('\u{03F9}', 'C'),   // GREEK CAPITAL LUNATE SIGMA SYMBOL
+	('\u{03FA}', 'M'),   // GREEK CAPITAL LETTER SAN
+	('\u{0405}', 'S'),   // CYRILLIC CAPITAL LETTER DZE
+	('\u{0406}', 'l'),   // CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I
+	('\u{0408}', 'J'),   // CYRILLIC CAPITAL LETTER JE
+	('\u{0410}', 'A'),   // CYRILLIC CAPITAL LETTER A
+	('\u{0412}', 'B'),   // CYRILLIC CAPITAL LETTER VE
+	('\u{0415}', 'E'),   // CYRILLIC CAPITAL LETTER IE
+	('\u{0417}', '3'),   // CYRILLIC CAPITAL LETTER ZE
+	('\u{041A}', 'K'),   // CYRILLIC CAPITAL LETTER KA
+	('\u{041C}', 'M'),   // CYRILLIC CAPITAL LETTER EM
+	('\u{041D}', 'H'),   // CYRILLIC CAPITAL LETTER EN
+	('\u{041E}', 'O'),   // CYRILLIC CAPITAL LETTER O
+	('\u{0420}', 'P'),   // CYRILLIC CAPITAL LETTER ER
+	('\u{0421}', 'C'),   // CYRILLIC CAPITAL LETTER ES
+	('\u{0422}', 'T'),   // CYRILLIC CAPITAL LETTER TE
+	('\u{0423}', 'Y'),   // CYRILLIC CAPITAL LETTER U
+	('\u{0425}', 'X'),   // CYRILLIC CAPITAL LETTER HA
+	('\u{042C}', 'b'),   // CYRILLIC CAPITAL LETTER SOFT SIGN
+	('\u{0430}', 'a'),   // CYRILLIC SMALL LETTER A
+	('\u{0431}', '6'),   // CYRILLIC SMALL LETTER BE
+	('\u{0433}', 'r'),   // CYRILLIC SMALL LETTER GHE
+	('\u{0435}', 'e'),   // CYRILLIC SMALL LETTER IE
+	('\u{043E}', 'o'),   // CYRILLIC SMALL LETTER O
+	('\u{0440}', 'p'),   // CYRILLIC SMALL LETTER ER
+	('\u{0441}', 'c'),   // CYRILLIC SMALL LETTER ES
+	('\u{0443}', 'y'),   // CYRILLIC SMALL LETTER U
+	('\u{0445}', 'x'),   // CYRILLIC SMALL LETTER HA
+	('\u{0455}', 's'),   // CYRILLIC SMALL LETTER DZE
+	('\u{0456}', 'i'),   // CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I
+	('\u{0458}', 'j'),   // CYRILLIC SMALL LETTER JE
+	('\u{0461}', 'w'),   // CYRILLIC SMALL LETTER OMEGA
+	('\u{0474}', 'V'),   // CYRILLIC CAPITAL LETTER IZHITSA
+	('\u{0475}', 'v'),   // CYRILLIC SMALL LETTER IZHITSA
+	('\u{04AE}', 'Y'),   // CYRILLIC CAPITAL LETTER STRAIGHT U
+	('\u{04AF}', 'y'),   // CYRILLIC SMALL LETTER STRAIGHT U
+	('\u{04BB}', 'h'),   // CYRILLIC SMALL LETTER SHHA
+	('\u{04BD}', 'e'),   // CYRILLIC SMALL LETTER ABKHASIAN CHE
+	('\u{04C0}', 'l'),   // CYRILLIC LETTER PALOCHKA
+	('\u{04CF}', 'i'),   // CYRILLIC SMALL LETTER PALOCHKA
+	('\u{04E0}', '3'),   // CYRILLIC CAPITAL LETTER ABKHASIAN DZE
+	('\u{0501}', 'd'),   // CYRILLIC SMALL LETTER KOMI DE
+	('\u{050C}', 'G'),   // CYRILLIC CAPITAL LETTER KOMI SJE
+	('\u{051B}', 'q'),   // CYRILLIC SMALL LETTER QA
+	('\u{051C}', 'W'),   // CYRILLIC CAPITAL LETTER WE
+	('\u{051D}', 'w'),   // CYRILLIC SMALL LETTER WE
+	('\u{054D}', 'U'),   // ARMENIAN CAPITAL LETTER SEH
+	('\u{054F}', 'S'),   // ARMENIAN CAPITAL LETTER TIWN
+	('\u{0555}', 'O'),   // ARMENIAN CAPITAL LETTER OH
+	('\u{055A}', '\''),  // ARMENIAN APOSTROPHE
+	('\u{055D}', '\''),  // ARMENIAN COMMA
+	('\u{0561}', 'w'),   // ARMENIAN SMALL LETTER AYB
+	('\u{0563}', 'q'),   // ARMENIAN SMALL LETTER GIM
+	('\u{0566}', 'q'),   // ARMENIAN SMALL LETTER ZA
+	('\u{0570}', 'h'),   // ARMENIAN SMALL LETTER HO
+	('\u{0578}', 'n'),   // ARMENIAN SMALL LETTER VO
+	('\u{057C}', 'n'),   // ARMENIAN SMALL LETTER RA
+	('\u{057D}', 'u'),   // ARMENIAN SMALL LETTER SEH
+	('\u{0581}', 'g'),   // ARMENIAN SMALL LETTER CO
+	('\u{0584}', 'f'),   // ARMENIAN SMALL LETTER KEH
+	('\u{0585}', 'o'),   // ARMENIAN SMALL LETTER OH
+	('\u{0589}', ':'),   // ARMENIAN FULL STOP
+	('\u{05C0}', 'l'),   // HEBREW PUNCTUATION PASEQ
+	('\u{05C3}', ':'),   // HEBREW PUNCTUATION SOF PASUQ
+	('\u{05D5}', 'l'),   // HEBREW LETTER VAV
+	('\u{05D8}', 'v'),   // HEBREW LETTER TET
+	('\u{05D9}', '\''),  // HEBREW LETTER YOD
+	('\u{05DF}', 'l'),   // HEBREW LETTER FINAL NUN
+	('\u{05E1}', 'o'),   // HEBREW LETTER SAMEKH
+	('\u{05F3}', '\''),  // HEBREW PUNCTUATION GERESH
+	('\u{060D}', ','),   // ARABIC DATE SEPARATOR
+	('\u{0627}', 'l'),   // ARABIC LETTER ALEF
+	('\u{0647}', 'o'),   // ARABIC LETTER HEH
+	('\u{0660}', '.'),   // ARABIC-INDIC DIGIT ZERO
+	('\u{0661}', 'l'),   // ARABIC-INDIC DIGIT ONE
+	('\u{0665}', 'o'),   // ARABIC-INDIC DIGIT FIVE
+	('\u{0667}', 'V'),   // ARABIC-INDIC DIGIT SEVEN
+	('\u{066B}', ','),   // ARABIC DECIMAL SEPARATOR
+	('\u{066D}', '*'),   // ARABIC FIVE POINTED STAR
+	('\u{06BE}', 'o'),   // ARABIC LETTER HEH DOACHASHMEE
+	('\u{06C1}', 'o'),   // ARABIC LETTER HEH GOAL
+	('\u{06D4}', '-'),   // ARABIC FULL STOP
+	('\u{06D5}', 'o'),   // ARABIC LETTER AE
+	('\u{06F0}', '.'),   // EXTENDED ARABIC-INDIC DIGIT ZERO
+	('\u{06F1}', 'l'),   // EXTENDED ARABIC-INDIC DIGIT ONE
+	('\u{06F5}', 'o'),   // EXTENDED ARABIC-INDIC DIGIT FIVE
+	('\u{06F7}', 'V'),   // EXTENDED ARABIC-INDIC DIGIT SEVEN
+	('\u{0701}', '.'),   // SYRIAC SUPRALINEAR FULL STOP
+	('\u{0702}', '.'),   // SYRIAC SUBLINEAR FULL STOP
+	('\u{0703}', ':'),   // SYRIAC SUPRALINEAR COLON
+	('\u{0704}', ':'),   // SYRIAC SUBLINEAR COLON
+	('\u{07C0}', 'O'),   // NKO DIGIT ZERO
+	('\u{07CA}', 'l'),   // NKO LETTER A
+	('\u{07F4}', '\''),  // NKO HIGH TONE APOSTROPHE
+	('\u{07F5}', '\''),  // NKO LOW TONE APOSTROPHE
+	('\u{07FA}', '_'),   // NKO LAJANYALAN
+	('\u{0903}', ':'),   // DEVANAGARI SIGN VISARGA
+	('\u{0966}', 'o'),   // DEVANAGARI DIGIT ZERO
+	('\u{097D}', '?'),   // DEVANAGARI LETTER GLOTTAL STOP
+	('\u{09E6}', 'O'),   // BENGALI DIGIT ZERO
+	('\u{09EA}', '8'),   // BENGALI DIGIT FOUR
+	('\u{09ED}', '9'),   // BENGALI DIGIT SEVEN
+	('\u{0A66}', 'o'),   // GURMUKHI DIGIT ZERO
+	('\u{0A67}', '9'),   // GURMUKHI DIGIT ONE
+	('\u{0A6A}', '8'),   // GURMUKHI DIGIT FOUR
+	('\u{0A83}', ':'),   // GUJARATI SIGN VISARGA
+	('\u{0AE6}', 'o'),   // GUJARATI DIGIT ZERO
+	('\u{0B03}', '8'),   // ORIYA SIGN VISARGA
+	('\u{0B20}', 'O'),   // ORIYA LETTER TTHA
+	('\u{0B66}', 'O'),   // ORIYA DIGIT ZERO
+	('\u{0B68}', '9'),   // ORIYA DIGIT TWO
+	('\u{0BE6}', 'o'),   // TAMIL DIGIT ZERO
+	('\u{0C02}', 'o'),   // TELUGU SIGN ANUSVARA
+	('\u{0C66}', 'o'),   // TELUGU DIGIT ZERO
+	('\u{0C82}', 'o'),   // KANNADA SIGN ANUSVARA
+	('\u{0CE6}', 'o'),   // KANNADA DIGIT ZERO
+	('\u{0D02}', 'o'),   // MALAYALAM SIGN ANUSVARA
+	('\u{0D20}', 'o'),   // MALAYALAM LETTER TTHA
+	('\u{0D66}', 'o'),   // MALAYALAM DIGIT ZERO
+	('\u{0D6D}', '9'),   // MALAYALAM DIGIT SEVEN
+	('\u{0D82}', 'o'),   // SINHALA SIGN ANUSVARAYA
+	('\u{0E50}', 'o'),   // THAI DIGIT ZERO
+	('\u{0ED0}', 'o'),   // LAO DIGIT ZERO
+	('\u{101D}', 'o'),   // MYANMAR LETTER WA
+	('\u{1040}', 'o'),   // MYANMAR DIGIT ZERO
+	('\u{10E7}', 'y'),   // GEORGIAN LETTER QAR
+	('\u{10FF}', 'o'),   // GEORGIAN LETTER LABIAL SIGN
+	('\u{1200}', 'U'),   // ETHIOPIC SYLLABLE HA
+	('\u{12D0}', 'O'),   // ETHIOPIC SYLLABLE PHARYNGEAL A
+	('\u{13A0}', 'D'),   // CHEROKEE LETTER A
+	('\u{13A1}', 'R'),   // CHEROKEE LETTER E
+	('\u{13A2}', 'T'),   // CHEROKEE LETTER I
+	('\u{13A5}', 'i'),   // CHEROKEE LETTER V
+	('\u{13A9}', 'Y'),   // CHEROKEE LETTER GI
+	('\u{13AA}', 'A'),   // CHEROKEE LETTER GO
+	('\u{13AB}', 'J'),   // CHEROKEE LETTER GU
+	('\u{13AC}', 'E'),   // CHEROKEE LETTER GV
+	('\u{13AE}', '?'),   // CHEROKEE LETTER HE
+	('\u{13B3}', 'W'),   // CHEROKEE LETTER LA
+	('\u{13B7}', 'M'),   // CHEROKEE LETTER LU
+	('\u{13BB}', 'H'),   // CHEROKEE LETTER MI
+	('\u{13BD}', 'Y'),   // CHEROKEE LETTER MU
+	('\u{13C0}', 'G'),   // CHEROKEE LETTER NAH
+	('\u{13C2}', 'h'),   // CHEROKEE LETTER NI
+	('\u{13C3}', 'Z'),   // CHEROKEE LETTER NO
+	('\u{13CE}', '4'),   // CHEROKEE LETTER SE
+	('\u{13CF}', 'b'),   // CHEROKEE LETTER SI
+	('\u{13D2}', 'R'),   // CHEROKEE LETTER SV
+	('\u{13D4}', 'W'),   // CHEROKEE LETTER TA
+	('\u{13D5}', 'S'),   // CHEROKEE LETTER DE
+	('\u{13D9}', 'V'),   // CHEROKEE LETTER DO
+	('\u{13DA}', 'S'),   // CHEROKEE LETTER DU
+	('\u{13DE}', 'L'),   // CHEROKEE LETTER TLE
+	('\u{13DF}', 'C'),   // CHEROKEE LETTER TLI
+	('\u{13E2}', 'P'),   // CHEROKEE LETTER TLV
+	('\u{13E6}', 'K'),   // CHEROKEE LETTER TSO
+	('\u{13E7}', 'd'),   // CHEROKEE LETTER TSU
+	('\u{13EE}', '6'),   // CHEROKEE LETTER WV
+	('\u{13F3}', 'G'),   // CHEROKEE LETTER YU
+	('\u{13F4}', 'B'),   // CHEROKEE LETTER YV
+	('\u{1400}', '='),   // CANADIAN SYLLABICS HYPHEN
+	('\u{142F}', 'V'),   // CANADIAN SYLLABICS PE
+	('\u{1433}', '>'),   // CANADIAN SYLLABICS PO
+	('\u{1438}', '<'),   // CANADIAN SYLLABICS PA
+	('\u{144A}', '\''),  // CANADIAN SYLLABICS WEST-CREE P
+	('\u{144C}', 'U'),   // CANADIAN SYLLABICS TE
+	('\u{146D}', 'P'),   // CANADIAN SYLLABICS KI
+	('\u{146F}', 'd'),   // CANADIAN SYLLABICS KO
+	('\u{1472}', 'b'),   // CANADIAN SYLLABICS KA
+	('\u{148D}', 'J'),   // CANADIAN SYLLABICS CO
+	('\u{14AA}', 'L'),   // CANADIAN SYLLABICS MA
+	('\u{14BF}', '2'),   // CANADIAN SYLLABICS SAYISI M
+	('\u{1541}', 'x'),   // CANADIAN SYLLABICS SAYISI YI
+	('\u{157C}', 'H'),   // CANADIAN SYLLABICS NUNAVUT H
+	('\u{157D}', 'x'),   // CANADIAN SYLLABICS HK
+	('\u{1587}', 'R'),   // CANADIAN SYLLABICS TLHI
+	('\u{15AF}', 'b'),   // CANADIAN SYLLABICS AIVILIK B
+	('\u{15B4}', 'F'),   // CANADIAN SYLLABICS BLACKFOOT WE
+	('\u{15C5}', 'A'),   // CANADIAN SYLLABICS CARRIER GHO
+	('\u{15DE}', 'D'),   // CANADIAN SYLLABICS CARRIER THE
+	('\u{15EA}', 'D'),   // CANADIAN SYLLABICS CARRIER PE
+	('\u{15F0}', 'M'),   // CANADIAN SYLLABICS CARRIER GO
+	('\u{15F7}', 'B'),   // CANADIAN SYLLABICS CARRIER KHE
+	('\u{166D}', 'X'),   // CANADIAN SYLLABICS CHI SIGN
+	('\u{166E}', 'x'),   // CANADIAN SYLLABICS FULL STOP
+	('\u{16B2}', '<'),   // RUNIC LETTER KAUNA
+	('\u{16B7}', 'X'),   // RUNIC LETTER GEBO GYFU G
+	('\u{16C1}', 'l'),   // RUNIC LETTER ISAZ IS ISS I
+	('\u{16CC}', '\''),  // RUNIC LETTER SHORT-TWIG-SOL S
+	('\u{16D5}', 'K'),   // RUNIC LETTER OPEN-P
+	('\u{16D6}', 'M'),   // RUNIC LETTER EHWAZ EH E
+	('\u{16EC}', ':'),   // RUNIC MULTIPLE PUNCTUATION
+	('\u{16ED}', '+'),   // RUNIC CROSS PUNCTUATION
+	('\u{1735}', '/'),   // PHILIPPINE SINGLE PUNCTUATION
+	('\u{1803}', ':'),   // MONGOLIAN FULL STOP
+	('\u{1809}', ':'),   // MONGOLIAN MANCHU FULL STOP
+	('\u{1D04}', 'c'),   // LATIN LETTER SMALL CAPITAL C
+	('\u{1D0F}', 'o'),   // LATIN LETTER SMALL CAPITAL O
+	('\u{1D11}', 'o'),   // LATIN SMALL LETTER SIDEWAYS O
+	('\u{1D1C}', 'u'),   // LATIN LETTER SMALL CAPITAL U
+	('\u{1D20}', 'v'),   // LATIN LETTER SMALL CAPITAL V
+	('\u{1D21}', 'w'),   // LATIN LETTER SMALL CAPITAL W
+	('\u{1D22}', 'z'),   // LATIN LETTER SMALL CAPITAL Z
+	('\u{1D26}', 'r'),   // GREEK LETTER SMALL CAPITAL GAMMA
+	('\u{1D83}', 'g'),   // LATIN SMALL LETTER G WITH PALATAL HOOK
+	('\u{1D8C}', 'y'),   // LATIN SMALL LETTER V WITH PALATAL HOOK
+	('\u{1E9D}', 'f'),   // LATIN SMALL LETTER LONG S WITH HIGH STROKE
+	('\u{1EFF}', 'y'),   // LATIN SMALL LETTER Y WITH LOOP
+	('\u{1FBD}', '\''),  // GREEK KORONIS
+	('\u{1FBE}', 'i'),   // GREEK PROSGEGRAMMENI
+	('\u{1FBF}', '\''),  // GREEK PSILI
+	('\u{1FC0}', '~'),   // GREEK PERISPOMENI
+	('\u{1FEF}', '\''),  // GREEK VARIA
+	('\u{1FFD}', '\''),  // GREEK OXIA
+	('\u{1FFE}', '\''),  // GREEK DASIA
+	('\u{2010}', '-'),   // HYPHEN
+	('\u{2011}', '-'),   // NON-BREAKING HYPHEN
+	('\u{2012}', '-'),   // FIGURE DASH
+	('\u{2013}', '-'),   // EN DASH
+	('\u{2018}', '\''),  // LEFT SINGLE QUOTATION MARK
+	('\u{2019}', '\''),  // RIGHT SINGLE QUOTATION MARK
+	('\u{201A}', ','),   // SINGLE LOW-9 QUOTATION MARK
+	('\u{201B}', '\''),  // SINGLE HIGH-REVERSED-9 QUOTATION MARK
+	('\u{2024}', '.'),   // ONE DOT LEADER
+	('\u{2032}', '\''),  // PRIME
+	('\u{2035}', '\''),  // REVERSED PRIME
+	('\u{2039}', '<'),   // SINGLE LEFT-POINTING ANGLE QUOTATION MARK
+	('\u{203A}', '>'),   // SINGLE RIGHT-POINTING ANGLE QUOTATION MARK
+	('\u{2041}', '/'),   // CARET INSERTION POINT
+	('\u{2043}', '-'),   // HYPHEN BULLET
+	('\u{2044}', '/'),   // FRACTION SLASH
+	('\u{204E}', '*'),   // LOW ASTERISK
+	('\u{2053}', '~'),   // SWUNG DASH
+	('\u{205A}', ':'),   // TWO DOT PUNCTUATION
+	('\u{2102}', 'C'),   // DOUBLE-STRUCK CAPITAL C
+	('\u{210A}', 'g'),   // SCRIPT SMALL G
+	('\u{210B}', 'H'),   // SCRIPT CAPITAL H
+	('\u{210C}', 'H'),   // BLACK-LETTER CAPITAL H
+	('\u{210D}', 'H'),   // DOUBLE-STRUCK CAPITAL H
+	('\u{210E}', 'h'),   // PLANCK CONSTANT
+	('\u{2110}', 'l'),   // SCRIPT CAPITAL I
+	('\u{2111}', 'l'),   // BLACK-LETTER CAPITAL I
+	('\u{2112}', 'L'),   // SCRIPT CAPITAL L
+	('\u{2113}', 'l'),   // SCRIPT SMALL L
+	('\u{2115}', 'N'),   // DOUBLE-STRUCK CAPITAL N
+	('\u{2119}', 'P'),   // DOUBLE-STRUCK CAPITAL P
+	('\u{211A}', 'Q'),   // DOUBLE-STRUCK CAPITAL Q
+	('\u{211B}', 'R'),   // SCRIPT CAPITAL R
+	('\u{211C}', 'R'),   // BLACK-LETTER CAPITAL R
+	('\u{211D}', 'R'),   // DOUBLE-STRUCK CAPITAL R
+	('\u{2124}', 'Z'),   // DOUBLE-STRUCK CAPITAL Z
+	('\u{2128}', 'Z'),   // BLACK-LETTER CAPITAL Z
+	('\u{212A}', 'K'),   // KELVIN SIGN
+	('\u{212C}', 'B'),   // SCRIPT CAPITAL B
+	('\u{212D}', 'C'),   // BLACK-LETTER CAPITAL C
+	('\u{212E}', 'e'),   // ESTIMATED SYMBOL
+	('\u{212F}', 'e'),   // SCRIPT SMALL E
+	('\u{2130}', 'E'),   // SCRIPT CAPITAL E
+	('\u{2131}', 'F'),   // SCRIPT CAPITAL F
+	('\u{2133}', 'M'),   // SCRIPT CAPITAL M
+	('\u{2134}', 'o'),   // SCRIPT SMALL O
+	('\u{2139}', 'i'),   // INFORMATION SOURCE
+	('\u{213D}', 'y'),   // DOUBLE-STRUCK SMALL GAMMA
+	('\u{2145}', 'D'),   // DOUBLE-STRUCK ITALIC CAPITAL D
+	('\u{2146}', 'd'),   // DOUBLE-STRUCK ITALIC SMALL D
+	('\u{2147}', 'e'),   // DOUBLE-STRUCK ITALIC SMALL E
+	('\u{2148}', 'i'),   // DOUBLE-STRUCK ITALIC SMALL I
+	('\u{2149}', 'j'),   // DOUBLE-STRUCK ITALIC SMALL J
+	('\u{2160}', 'l'),   // ROMAN NUMERAL ONE
+	('\u{2164}', 'V'),   // ROMAN NUMERAL FIVE
+	('\u{2169}', 'X'),   // ROMAN NUMERAL TEN
+	('\u{216C}', 'L'),   // ROMAN NUMERAL FIFTY
+	('\u{216D}', 'C'),   // ROMAN NUMERAL ONE HUNDRED
+	('\u{216E}', 'D'),   // ROMAN NUMERAL FIVE HUNDRED
+	('\u{216F}', 'M'),   // ROMAN NUMERAL ONE THOUSAND
+	('\u{2170}', 'i'),   // SMALL ROMAN NUMERAL ONE
+	('\u{2174}', 'v'),   // SMALL ROMAN NUMERAL FIVE
+	('\u{2179}', 'x'),   // SMALL ROMAN NUMERAL TEN
+	('\u{217C}', 'l'),   // SMALL ROMAN NUMERAL FIFTY
+	('\u{217D}', 'c'),   // SMALL ROMAN NUMERAL ONE HUNDRED
+	('\u{217E}', 'd'),   // SMALL ROMAN NUMERAL FIVE HUNDRED
+	('\u{2212}', '-'),   // MINUS SIGN
+	('\u{2215}', '/'),   // DIVISION SLASH
+	('\u{2216}', '\\'),  // SET MINUS
+	('\u{2217}', '*'),   // ASTERISK OPERATOR
+	('\u{2223}', 'l'),   // DIVIDES
+	('\u{2228}', 'v'),   // LOGICAL OR
+	('\u{222A}', 'U'),   // UNION
+	('\u{2236}', ':'),   // RATIO
+	('\u{223C}', '~'),   // TILDE OPERATOR
+	('\u{22A4}', 'T'),   // DOWN TACK
+	('\u{22C1}', 'v'),   // N-ARY LOGICAL OR
+	('\u{22C3}', 'U'),   // N-ARY UNION
+	('\u{22FF}', 'E'),   // Z NOTATION BAG MEMBERSHIP
+	('\u{2373}', 'i'),   // APL FUNCTIONAL SYMBOL IOTA
+	('\u{2374}', 'p'),   // APL FUNCTIONAL SYMBOL RHO
+	('\u{237A}', 'a'),   // APL FUNCTIONAL SYMBOL ALPHA
+	('\u{23FD}', 'l'),   // POWER ON SYMBOL
+	('\u{2571}', '/'),   // BOX DRAWINGS LIGHT DIAGONAL UPPER RIGHT TO LOWER LEFT
+	('\u{2573}', 'X'),   // BOX DRAWINGS LIGHT DIAGONAL CROSS
+	('\u{2768}', '('),   // MEDIUM LEFT PARENTHESIS ORNAMENT
+	('\u{2769}', ')'),   // MEDIUM RIGHT PARENTHESIS ORNAMENT
+	('\u{276E}', '<'),   // HEAVY LEFT-POINTING ANGLE QUOTATION MARK ORNAMENT
+	('\u{276F}', '>'),   // HEAVY RIGHT-POINTING ANGLE QUOTATION MARK ORNAMENT
+	('\u{2772}', '('),   // LIGHT LEFT TORTOISE SHELL BRACKET ORNAMENT
+	('\u{2773}', ')'),   // LIGHT RIGHT TORTOISE SHELL BRACKET ORNAMENT
+	('\u{2774}', '{'),   // MEDIUM LEFT CURLY BRACKET ORNAMENT
+	('\u{2775}', '}'),   // MEDIUM RIGHT CURLY BRACKET ORNAMENT
+	('\u{2795}', '+'),   // HEAVY PLUS SIGN
+	('\u{2796}', '-'),   // HEAVY MINUS SIGN
+	('\u{27CB}', '/'),   // MATHEMATICAL RISING DIAGONAL
+	('\u{27CD}', '\\'),  // MATHEMATICAL FALLING DIAGONAL
+	('\u{27D9}', 'T'),   // LARGE DOWN TACK
+	('\u{292B}', 'x'),   // RISING DIAGONAL CROSSING FALLING DIAGONAL
+	('\u{292C}', 'x'),   // FALLING DIAGONAL CROSSING RISING DIAGONAL
+	('\u{29F5}', '\\'),  // REVERSE SOLIDUS OPERATOR
+	('\u{29F8}', '/'),   // BIG SOLIDUS
+	('\u{29F9}', '\\'),  // BIG REVERSE SOLIDUS
+	('\u{2A2F}', 'x'),   // VECTOR OR CROSS PRODUCT
+	('\u{2C85}', 'r'),   // COPTIC SMALL LETTER GAMMA
+	('\u{2C8E}', 'H'),   // COPTIC CAPITAL LETTER HATE
+	('\u{2C92}', 'l'),   // COPTIC CAPITAL LETTER IAUDA
+	('\u{2C94}', 'K'),   // COPTIC CAPITAL LETTER KAPA
+	('\u{2C98}', 'M'),   // COPTIC CAPITAL LETTER MI
+	('\u{2C9A}', 'N'),   // COPTIC CAPITAL LETTER NI
+	('\u{2C9E}', 'O'),   // COPTIC CAPITAL LETTER O
+	('\u{2C9F}', 'o'),   // COPTIC SMALL LETTER O
+	('\u{2CA2}', 'P'),   // COPTIC CAPITAL LETTER RO
+	('\u{2CA3}', 'p'),   // COPTIC SMALL LETTER RO
+	('\u{2CA4}', 'C'),   // COPTIC CAPITAL LETTER SIMA
+	('\u{2CA5}', 'c'),   // COPTIC SMALL LETTER SIMA
+	('\u{2CA6}', 'T'),   // COPTIC CAPITAL LETTER TAU
+	('\u{2CA8}', 'Y'),   // COPTIC CAPITAL LETTER UA
+	('\u{2CAC}', 'X'),   // COPTIC CAPITAL LETTER KHI
+	('\u{2CBA}', '-'),   // COPTIC CAPITAL LETTER DIALECT-P NI
+	('\u{2CC6}', '/'),   // COPTIC CAPITAL LETTER OLD COPTIC ESH
+	('\u{2CCA}', '9'),   // COPTIC CAPITAL LETTER DIALECT-P HORI
+	('\u{2CCC}', '3'),   // COPTIC CAPITAL LETTER OLD COPTIC HORI
+	('\u{2CD0}', 'L'),   // COPTIC CAPITAL LETTER L-SHAPED HA
+	('\u{2CD2}', '6'),   // COPTIC CAPITAL LETTER OLD COPTIC HEI
+	('\u{2D38}', 'V'),   // TIFINAGH LETTER YADH
+	('\u{2D39}', 'E'),   // TIFINAGH LETTER YADD
+	('\u{2D4F}', 'l'),   // TIFINAGH LETTER YAN
+	('\u{2D51}', '!'),   // TIFINAGH LETTER TUAREG YANG
+	('\u{2D54}', 'O'),   // TIFINAGH LETTER YAR
+	('\u{2D55}', 'Q'),   // TIFINAGH LETTER YARR
+	('\u{2D5D}', 'X'),   // TIFINAGH LETTER YATH
+	('\u{2E40}', '='),   // DOUBLE HYPHEN
+	('\u{2F02}', '\\'),  // KANGXI RADICAL DOT
+	('\u{2F03}', '/'),   // KANGXI RADICAL SLASH
+	('\u{3007}', 'O'),   // IDEOGRAPHIC NUMBER ZERO
+	('\u{3014}', '('),   // LEFT TORTOISE SHELL BRACKET
+	('\u{3015}', ')'),   // RIGHT TORTOISE SHELL BRACKET
+	('\u{3033}', '/'),   // VERTICAL KANA REPEAT MARK UPPER HALF
+	('\u{30A0}', '='),   // KATAKANA-HIRAGANA DOUBLE HYPHEN
+	('\u{30CE}', '/'),   // KATAKANA LETTER NO
+	('\u{31D3}', '/'),   // CJK STROKE SP
+	('\u{31D4}', '\\'),  // CJK STROKE D
+	('\u{4E36}', '\\'),  // CJK UNIFIED IDEOGRAPH-4E36
+	('\u{4E3F}', '/'),   // CJK UNIFIED IDEOGRAPH-4E3F
+	('\u{A4D0}', 'B'),   // LISU LETTER BA
+	('\u{A4D1}', 'P'),   // LISU LETTER PA
+	('\u{A4D2}', 'd'),   // LISU LETTER PHA
+	('\u{A4D3}', 'D'),   // LISU LETTER DA
+	('\u{A4D4}', 'T'),   // LISU LETTER TA
+	('\u{A4D6}', 'G'),   // LISU LETTER GA
+	('\u{A4D7}', 'K'),   // LISU LETTER KA
+	('\u{A4D9}', 'J'),   // LISU LETTER JA
+	('\u{A4DA}', 'C'),   // LISU LETTER CA
+	('\u{A4DC}', 'Z'),   // LISU LETTER DZA
+	('\u{A4DD}', 'F'),   // LISU LETTER TSA
+	('\u{A4DF}', 'M'),   // LISU LETTER MA
+	('\u{A4E0}', 'N'),   // LISU LETTER NA
+	('\u{A4E1}', 'L'),   // LISU LETTER LA
+	('\u{A4E2}', 'S'),   // LISU LETTER SA
+	('\u{A4E3}', 'R'),   // LISU LETTER ZHA
+	('\u{A4E6}', 'V'),   // LISU LETTER HA
+	('\u{A4E7}', 'H'),   // LISU LETTER XA
+	('\u{A4EA}', 'W'),   // LISU LETTER WA
+	('\u{A4EB}', 'X'),   // LISU LETTER SHA
+	('\u{A4EC}', 'Y'),   // LISU LETTER YA
+	('\u{A4EE}', 'A'),   // LISU LETTER A
+	('\u{A4F0}', 'E'),   // LISU LETTER E
+	('\u{A4F2}', 'l'),   // LISU LETTER I
+	('\u{A4F3}', 'O'),   // LISU LETTER O
+	('\u{A4F4}', 'U'),   // LISU LETTER U
+	('\u{A4F8}', '.'),   // LISU LETTER TONE MYA TI
+	('\u{A4F9}', ','),   // LISU LETTER TONE NA PO
+	('\u{A4FD}', ':'),   // LISU LETTER TONE MYA JEU
+	('\u{A4FF}', '='),   // LISU PUNCTUATION FULL STOP
+	('\u{A60E}', '.'),   // VAI FULL STOP
+	('\u{A644}', '2'),   // CYRILLIC CAPITAL LETTER REVERSED DZE
+	('\u{A647}', 'i'),   // CYRILLIC SMALL LETTER IOTA
+	('\u{A6DF}', 'V'),   // BAMUM LETTER KO
+	('\u{A6EB}', '?'),   // BAMUM LETTER NTUU
+	('\u{A6EF}', '2'),   // BAMUM LETTER KOGHOM
+	('\u{A731}', 's'),   // LATIN LETTER SMALL CAPITAL S
+	('\u{A75A}', '2'),   // LATIN CAPITAL LETTER R ROTUNDA
+	('\u{A76A}', '3'),   // LATIN CAPITAL LETTER ET
+	('\u{A76E}', '9'),   // LATIN CAPITAL LETTER CON
+	('\u{A778}', '&'),   // LATIN SMALL LETTER UM
+	('\u{A789}', ':'),   // MODIFIER LETTER COLON
+	('\u{A78C}', '\''),  // LATIN SMALL LETTER SALTILLO
+	('\u{A798}', 'F'),   // LATIN CAPITAL LETTER F WITH STROKE
+	('\u{A799}', 'f'),   // LATIN SMALL LETTER F WITH STROKE
+	('\u{A79F}', 'u'),   // LATIN SMALL LETTER VOLAPUK UE
+	('\u{A7AB}', '3'),   // LATIN CAPITAL LETTER REVERSED OPEN E
+	('\u{A7B2}', 'J'),   // LATIN CAPITAL LETTER J WITH CROSSED-TAIL
+	('\u{A7B3}', 'X'),   // LATIN CAPITAL LETTER CHI
+	('\u{A7B4}', 'B'),   // LATIN CAPITAL LETTER BETA
+	('\u{AB32}', 'e'),   // LATIN SMALL LETTER BLACKLETTER E
+	('\u{AB35}', 'f'),   // LATIN SMALL LETTER LENIS F
+	('\u{AB3D}', 'o'),   // LATIN SMALL LETTER BLACKLETTER O
+	('\u{AB47}', 'r'),   // LATIN SMALL LETTER R WITHOUT HANDLE
+	('\u{AB48}', 'r'),   // LATIN SMALL LETTER DOUBLE R
+	('\u{AB4E}', 'u'),   // LATIN SMALL LETTER U WITH SHORT RIGHT LEG
+	('\u{AB52}', 'u'),   // LATIN SMALL LETTER U WITH LEFT HOOK
+	('\u{AB5A}', 'y'),   // LATIN SMALL LETTER Y WITH SHORT RIGHT LEG
+	('\u{AB75}', 'i'),   // CHEROKEE SMALL LETTER V
+	('\u{AB81}', 'r'),   // CHEROKEE SMALL LETTER HU
+	('\u{AB83}', 'w'),   // CHEROKEE SMALL LETTER LA
+	('\u{AB93}', 'z'),   // CHEROKEE SMALL LETTER NO
+	('\u{ABA9}', 'v'),   // CHEROKEE SMALL LETTER DO
+	('\u{ABAA}', 's'),   // CHEROKEE SMALL LETTER DU
+	('\u{ABAF}', 'c'),   // CHEROKEE SMALL LETTER TLI
+	('\u{FBA6}', 'o'),   // ARABIC LETTER HEH GOAL ISOLATED FORM
+	('\u{FBA7}', 'o'),   // ARABIC LETTER HEH GOAL FINAL FORM
+	('\u{FBA8}', 'o'),   // ARABIC LETTER HEH GOAL INITIAL FORM
+	('\u{FBA9}', 'o'),   // ARABIC LETTER HEH GOAL MEDIAL FORM
+	('\u{FBAA}', 'o'),   // ARABIC LETTER HEH DOACHASHMEE ISOLATED FORM
+	('\u{FBAB}', 'o'),   // ARABIC LETTER HEH DOACHASHMEE FINAL FORM
+	('\u{FBAC}', 'o'),   // ARABIC LETTER HEH DOACHASHMEE INITIAL FORM
+	('\u{FBAD}', 'o'),   // ARABIC LETTER HEH DOACHASHMEE MEDIAL FORM
+	('\u{FD3E}', '('),   // ORNATE LEFT PARENTHESIS
+	('\u{FD3F}', ')'),   // ORNATE RIGHT PARENTHESIS
+	('\u{FE30}', ':'),   // PRESENTATION FORM FOR VERTICAL TWO DOT LEADER
+	('\u{FE4D}', '_'),   // DASHED LOW LINE
+	('\u{FE4E}', '_'),   // CENTRELINE LOW LINE
+	('\u{FE4F}', '_'),   // WAVY LOW LINE
+	('\u{FE58}', '-'),   // SMALL EM DASH
+	('\u{FE68}', '\\'),  // SMALL REVERSE SOLIDUS
+	('\u{FE8D}', 'l'),   // ARABIC LETTER ALEF ISOLATED FORM
+	('\u{FE8E}', 'l'),   // ARABIC LETTER ALEF FINAL FORM
+	('\u{FEE9}', 'o'),   // ARABIC LETTER HEH ISOLATED FORM
+	('\u{FEEA}', 'o'),   // ARABIC LETTER HEH FINAL FORM
+	('\u{FEEB}', 'o'),   // ARABIC LETTER HEH INITIAL FORM
+	('\u{FEEC}', 'o'),   // ARABIC LETTER HEH MEDIAL FORM
+	('\u{FF01}', '!'),   // FULLWIDTH EXCLAMATION MARK
+	('\u{FF07}', '\''),  // FULLWIDTH APOSTROPHE
+	('\u{FF1A}', ':'),   // FULLWIDTH COLON
+	('\u{FF21}', 'A'),   // FULLWIDTH LATIN CAPITAL LETTER A
+	('\u{FF22}', 'B'),   // FULLWIDTH LATIN CAPITAL LETTER B
+	('\u{FF23}', 'C'),   // FULLWIDTH LATIN CAPITAL LETTER C
+	('\u{FF25}', 'E'),   // FULLWIDTH LATIN CAPITAL LETTER E
+	('\u{FF28}', 'H'),   // FULLWIDTH LATIN CAPITAL LETTER H
+	('\u{FF29}', 'l'),   // FULLWIDTH LATIN CAPITAL LETTER I
+	('\u{FF2A}', 'J'),   // FULLWIDTH LATIN CAPITAL LETTER J
+	('\u{FF2B}', 'K'),   // FULLWIDTH LATIN CAPITAL LETTER K
+	('\u{FF2D}', 'M'),   // FULLWIDTH LATIN CAPITAL LETTER M
+	('\u{FF2E}', 'N'),   // FULLWIDTH LATIN CAPITAL LETTER N
+	('\u{FF2F}', 'O'),   // FULLWIDTH LATIN CAPITAL LETTER O
+	('\u{FF30}', 'P'),   // FULLWIDTH LATIN CAPITAL LETTER P
+	('\u{FF33}', 'S'),   // FULLWIDTH LATIN CAPITAL LETTER S
+	('\u{FF34}', 'T'),   // FULLWIDTH LATIN CAPITAL LETTER T
+	('\u{FF38}', 'X'),   // FULLWIDTH LATIN CAPITAL LETTER X
+	('\u{FF39}', 'Y'),   // FULLWIDTH LATIN CAPITAL LETTER Y
+	('\u{FF3A}', 'Z'),   // FULLWIDTH LATIN CAPITAL LETTER Z
+	('\u{FF3B}', '('),   // FULLWIDTH LEFT SQUARE BRACKET
+	('\u{FF3C}', '\\'),  // FULLWIDTH REVERSE SOLIDUS
+	('\u{FF3D}', ')'),   // FULLWIDTH RIGHT SQUARE BRACKET
+	('\u{FF40}', '\''),  // FULLWIDTH GRAVE ACCENT
+	('\u{FF41}', 'a'),   // FULLWIDTH LATIN SMALL LETTER A
+	('\u{FF43}', 'c'),   // FULLWIDTH LATIN SMALL LETTER C
+	('\u{FF45}', 'e'),   // FULLWIDTH LATIN SMALL LETTER E
+	('\u{FF47}', 'g'),   // FULLWIDTH LATIN SMALL LETTER G
+	('\u{FF48}', 'h'),   // FULLWIDTH LATIN SMALL LETTER H
+	('\u{FF49}', 'i'),   // FULLWIDTH LATIN SMALL LETTER I
+	('\u{FF4A}', 'j'),   // FULLWIDTH LATIN SMALL LETTER J
+	('\u{FF4C}', 'l'),   // FULLWIDTH LATIN SMALL LETTER L
+	('\u{FF4F}', 'o'),   // FULLWIDTH LATIN SMALL LETTER O
+	('\u{FF50}', 'p'),   // FULLWIDTH LATIN SMALL LETTER P
+	('\u{FF53}', 's'),   // FULLWIDTH LATIN SMALL LETTER S
+	('\u{FF56}', 'v'),   // FULLWIDTH LATIN SMALL LETTER V
+	('\u{FF58}', 'x'),   // FULLWIDTH LATIN SMALL LETTER X
+	('\u{FF59}', 'y'),   // FULLWIDTH LATIN SMALL LETTER Y
+	('\u{FFE8}', 'l'),   // HALFWIDTH FORMS LIGHT VERTICAL
+	('\u{10282}', 'B'),  // LYCIAN LETTER B
+	('\u{10286}', 'E'),  // LYCIAN LETTER I
+	('\u{10287}', 'F'),  // LYCIAN LETTER W
+	('\u{1028A}', 'l'),  // LYCIAN LETTER J
+	('\u{10290}', 'X'),  // LYCIAN LETTER MM
+	('\u{10292}', 'O'),  // LYCIAN LETTER U
+	('\u{10295}', 'P'),  // LYCIAN LETTER R
+	('\u{10296}', 'S'),  // LYCIAN LETTER S
+	('\u{10297}', 'T'),  // LYCIAN LETTER T
+	('\u{1029B}', '+'),  // LYCIAN LETTER H
+	('\u{102A0}', 'A'),  // CARIAN LETTER A
+	('\u{102A1}', 'B'),  // CARIAN LETTER P2
+	('\u{102A2}', 'C'),  // CARIAN LETTER D
+	('\u{102A5}', 'F'),  // CARIAN LETTER R
+	('\u{102AB}', 'O'),  // CARIAN LETTER O
+	('\u{102B0}', 'M'),  // CARIAN LETTER S
+	('\u{102B1}', 'T'),  // CARIAN LETTER C-18
+	('\u{102B2}', 'Y'),  // CARIAN LETTER U
+	('\u{102B4}', 'X'),  // CARIAN LETTER X
+	('\u{102CF}', 'H'),  // CARIAN LETTER E2
+	('\u{102F5}', 'Z'),  // COPTIC EPACT NUMBER THREE HUNDRED
+	('\u{10301}', 'B'),  // OLD ITALIC LETTER BE
+	('\u{10302}', 'C'),  // OLD ITALIC LETTER KE
+	('\u{10309}', 'l'),  // OLD ITALIC LETTER I
+	('\u{10311}', 'M'),  // OLD ITALIC LETTER SHE
+	('\u{10315}', 'T'),  // OLD ITALIC LETTER TE
+	('\u{10317}', 'X'),  // OLD ITALIC LETTER EKS
+	('\u{1031A}', '8'),  // OLD ITALIC LETTER EF
+	('\u{1031F}', '*'),  // OLD ITALIC LETTER ESS
+	('\u{10320}', 'l'),  // OLD ITALIC NUMERAL ONE
+	('\u{10322}', 'X'),  // OLD ITALIC NUMERAL TEN
+	('\u{10404}', 'O'),  // DESERET CAPITAL LETTER LONG O
+	('\u{10415}', 'C'),  // DESERET CAPITAL LETTER CHEE
+	('\u{1041B}', 'L'),  // DESERET CAPITAL LETTER ETH
+	('\u{10420}', 'S'),  // DESERET CAPITAL LETTER ZHEE
+	('\u{1042C}', 'o'),  // DESERET SMALL LETTER LONG O
+	('\u{1043D}', 'c'),  // DESERET SMALL LETTER CHEE
+	('\u{10448}', 's'),  // DESERET SMALL LETTER ZHEE
+	('\u{104B4}', 'R'),  // OSAGE CAPITAL LETTER BRA
+	('\u{104C2}', 'O'),  // OSAGE CAPITAL LETTER O
+	('\u{104CE}', 'U'),  // OSAGE CAPITAL LETTER U
+	('\u{104D2}', '7'),  // OSAGE CAPITAL LETTER ZA
+	('\u{104EA}', 'o'),  // OSAGE SMALL LETTER O
+	('\u{104F6}', 'u'),  // OSAGE SMALL LETTER U
+	('\u{10513}', 'N'),  // ELBASAN LETTER NE
+	('\u{10516}', 'O'),  // ELBASAN LETTER O
+	('\u{10518}', 'K'),  // ELBASAN LETTER QE
+	('\u{1051C}', 'C'),  // ELBASAN LETTER SHE
+	('\u{1051D}', 'V'),  // ELBASAN LETTER TE
+	('\u{10525}', 'F'),  // ELBASAN LETTER GHE
+	('\u{10526}', 'L'),  // ELBASAN LETTER GHAMMA
+	('\u{10527}', 'X'),  // ELBASAN LETTER KHE
+	('\u{10A50}', '.'),  // KHAROSHTHI PUNCTUATION DOT
+	('\u{114D0}', 'O'),  // TIRHUTA DIGIT ZERO
+	('\u{11706}', 'v'),  // AHOM LETTER PA
+	('\u{1170A}', 'w'),  // AHOM LETTER JA
+	('\u{1170E}', 'w'),  // AHOM LETTER LA
+	('\u{1170F}', 'w'),  // AHOM LETTER SA
+	('\u{118A0}', 'V'),  // WARANG CITI CAPITAL LETTER NGAA
+	('\u{118A2}', 'F'),  // WARANG CITI CAPITAL LETTER WI
+	('\u{118A3}', 'L'),  // WARANG CITI CAPITAL LETTER YU
+	('\u{118A4}', 'Y'),  // WARANG CITI CAPITAL LETTER YA
+	('\u{118A6}', 'E'),  // WARANG CITI CAPITAL LETTER II
+	('\u{118A9}', 'Z'),  // WARANG CITI CAPITAL LETTER O
+	('\u{118AC}', '9'),  // WARANG CITI CAPITAL LETTER KO
+	('\u{118AE}', 'E'),  // WARANG CITI CAPITAL LETTER YUJ
+	('\u{118AF}', '4'),  // WARANG CITI CAPITAL LETTER UC
+	('\u{118B2}', 'L'),  // WARANG CITI CAPITAL LETTER TTE
+	('\u{118B5}', 'O'),  // WARANG CITI CAPITAL LETTER AT
+	('\u{118B8}', 'U'),  // WARANG CITI CAPITAL LETTER PU
+	('\u{118BB}', '5'),  // WARANG CITI CAPITAL LETTER HORR
+	('\u{118BC}', 'T'),  // WARANG CITI CAPITAL LETTER HAR
+	('\u{118C0}', 'v'),  // WARANG CITI SMALL LETTER NGAA
+	('\u{118C1}', 's'),  // WARANG CITI SMALL LETTER A
+	('\u{118C2}', 'F'),  // WARANG CITI SMALL LETTER WI
+	('\u{118C3}', 'i'),  // WARANG CITI SMALL LETTER YU
+	('\u{118C4}', 'z'),  // WARANG CITI SMALL LETTER YA
+	('\u{118C6}', '7'),  // WARANG CITI SMALL LETTER II
+	('\u{118C8}', 'o'),  // WARANG CITI SMALL LETTER E
+	('\u{118CA}', '3'),  // WARANG CITI SMALL LETTER ANG
+	('\u{118CC}', '9'),  // WARANG CITI SMALL LETTER KO
+	('\u{118D5}', '6'),  // WARANG CITI SMALL LETTER AT
+	('\u{118D6}', '9'),  // WARANG CITI SMALL LETTER AM
+	('\u{118D7}', 'o'),  // WARANG CITI SMALL LETTER BU
+	('\u{118D8}', 'u'),  // WARANG CITI SMALL LETTER PU
+	('\u{118DC}', 'y'),  // WARANG CITI SMALL LETTER HAR
+	('\u{118E0}', 'O'),  // WARANG CITI DIGIT ZERO
+	('\u{118E5}', 'Z'),  // WARANG CITI DIGIT FIVE
+	('\u{118E6}', 'W'),  // WARANG CITI DIGIT SIX
+	('\u{118E9}', 'C'),  // WARANG CITI DIGIT NINE
+	('\u{118EC}', 'X'),  // WARANG CITI NUMBER THIRTY
+	('\u{118EF}', 'W'),  // WARANG CITI NUMBER SIXTY
+	('\u{118F2}', 'C'),  // WARANG CITI NUMBER NINETY
+	('\u{16F08}', 'V'),  // MIAO LETTER VA
+	('\u{16F0A}', 'T'),  // MIAO LETTER TA
+	('\u{16F16}', 'L'),  // MIAO LETTER LA
+	('\u{16F28}', 'l'),  // MIAO LETTER GHA
+	('\u{16F35}', 'R'),  // MIAO LETTER ZHA
+	('\u{16F3A}', 'S'),  // MIAO LETTER SA
+	('\u{16F3B}', '3'),  // MIAO LETTER ZA
+	('\u{16F3F}', '>'),  // MIAO LETTER ARCHAIC ZZA
+	('\u{16F40}', 'A'),  // MIAO LETTER ZZYA
+	('\u{16F42}', 'U'),  // MIAO LETTER WA
+	('\u{16F43}', 'Y'),  // MIAO LETTER AH
+	('\u{16F51}', '\''), // MIAO SIGN ASPIRATION
+	('\u{16F52}', '\''), // MIAO SIGN REFORMED VOICING
+	('\u{1CCD6}', 'A'),
+	('\u{1CCD7}', 'B'),
+	('\u{1CCD8}', 'C'),
+	('\u{1CCD9}', 'D'),
+	('\u{1CCDA}', 'E'),
+	('\u{1CCDB}', 'F'),
+	('\u{1CCDC}', 'G'),
+	('\u{1CCDD}', 'H'),
+	('\u{1CCDE}', 'l'),
+	('\u{1CCDF}', 'J'),
+	('\u{1CCE0}', 'K'),
+	('\u{1CCE1}', 'L'),
+	('\u{1CCE2}', 'M'),
+	('\u{1CCE3}', 'N'),
+	('\u{1CCE4}', 'O'),
+	('\u{1CCE5}', 'P'),
+	('\u{1CCE6}', 'Q'),
+	('\u{1CCE7}', 'R'),
+	('\u{1CCE8}', 'S'),
+	('\u{1CCE9}', 'T'),
+	('\u{1CCEA}', 'U'),
+	('\u{1CCEB}', 'V'),
+	('\u{1CCEC}', 'W'),
+	('\u{1CCED}', 'X'),
+	('\u{1CCEE}', 'Y'),
+	('\u{1CCEF}', 'Z'),
+	('\u{1CCF0}', 'O'),
+	('\u{1CCF1}', 'l'),
+	('\u{1CCF2}', '2'),
+	('\u{1CCF3}', '3'),
+	('\u{1CCF4}', '4'),
+	('\u{1CCF5}', '5'),
+	('\u{1CCF6}', '6'),
+	('\u{1CCF7}', '7'),
+	('\u{1CCF8}', '8'),
+	('\u{1CCF9}', '9'),
+	('\u{1D114}', '{'),  // MUSICAL SYMBOL BRACE
+	('\u{1D16D}', '.'),  // MUSICAL SYMBOL COMBINING AUGMENTATION DOT
+	('\u{1D206}', '3'),  // GREEK VOCAL NOTATION SYMBOL-7
+	('\u{1D20D}', 'V'),  // GREEK VOCAL NOTATION SYMBOL-14
+	('\u{1D20F}', '\\'), // GREEK VOCAL NOTATION SYMBOL-16
+	('\u{1D212}', '7'),  // GREEK VOCAL NOTATION SYMBOL-19
+	('\u{1D213}', 'F'),  // GREEK VOCAL NOTATION SYMBOL-20
+	('\u{1D216}', 'R'),  // GREEK VOCAL NOTATION SYMBOL-23
+	('\u{1D22A}', 'L'),  // GREEK INSTRUMENTAL NOTATION SYMBOL-23
+	('\u{1D236}', '<'),  // GREEK INSTRUMENTAL NOTATION SYMBOL-40
+	('\u{1D237}', '>'),  // GREEK INSTRUMENTAL NOTATION SYMBOL-42
+	('\u{1D23A}', '/'),  // GREEK INSTRUMENTAL NOTATION SYMBOL-47
+	('\u{1D23B}', '\\'), // GREEK INSTRUMENTAL NOTATION SYMBOL-48
+	('\u{1D400}', 'A'),  // MATHEMATICAL BOLD CAPITAL A
+	('\u{1D401}', 'B'),  // MATHEMATICAL BOLD CAPITAL B
+	('\u{1D402}', 'C'),  // MATHEMATICAL BOLD CAPITAL C
+	('\u{1D403}', 'D'),  // MATHEMATICAL BOLD CAPITAL D
+	('\u{1D404}', 'E'),  // MATHEMATICAL BOLD CAPITAL E
+	('\u{1D405}', 'F'),  // MATHEMATICAL BOLD CAPITAL F
+	('\u{1D406}', 'G'),  // MATHEMATICAL BOLD CAPITAL G
+	('\u{1D407}', 'H'),  // MATHEMATICAL BOLD CAPITAL H
+	('\u{1D408}', 'l'),  // MATHEMATICAL BOLD CAPITAL I
+	('\u{1D409}', 'J'),  // MATHEMATICAL BOLD CAPITAL J
+	('\u{1D40A}', 'K'),  // MATHEMATICAL BOLD CAPITAL K
+	('\u{1D40B}', 'L'),  // MATHEMATICAL BOLD CAPITAL L
+	('\u{1D40C}', 'M'),  // MATHEMATICAL BOLD CAPITAL M
+	('\u{1D40D}', 'N'),  // MATHEMATICAL BOLD CAPITAL N
+	('\u{1D40E}', 'O'),  // MATHEMATICAL BOLD CAPITAL O
+	('\u{1D40F}', 'P'),  // MATHEMATICAL BOLD CAPITAL P
+	('\u{1D410}', 'Q'),  // MATHEMATICAL BOLD CAPITAL Q
+	('\u{1D411}', 'R'),  // MATHEMATICAL BOLD CAPITAL R
+	('\u{1D412}', 'S'),  // MATHEMATICAL BOLD CAPITAL S
+	('\u{1D413}', 'T'),  // MATHEMATICAL BOLD CAPITAL T
+	('\u{1D414}', 'U'),  // MATHEMATICAL BOLD CAPITAL U
+	('\u{1D415}', 'V'),  // MATHEMATICAL BOLD CAPITAL V
+	('\u{1D416}', 'W'),  // MATHEMATICAL BOLD CAPITAL W
+	('\u{1D417}', 'X'),  // MATHEMATICAL BOLD CAPITAL X
+	('\u{1D418}', 'Y'),  // MATHEMATICAL BOLD CAPITAL Y
+	('\u{1D419}', 'Z'),  // MATHEMATICAL BOLD CAPITAL Z
+	('\u{1D41A}', 'a'),  // MATHEMATICAL BOLD SMALL A
+	('\u{1D41B}', 'b'),  // MATHEMATICAL BOLD SMALL B
+	('\u{1D41C}', 'c'),  // MATHEMATICAL BOLD SMALL C
+	('\u{1D41D}', 'd'),  // MATHEMATICAL BOLD SMALL D
+	('\u{1D41E}', 'e'),  // MATHEMATICAL BOLD SMALL E
+	('\u{1D41F}', 'f'),  // MATHEMATICAL BOLD SMALL F
+	('\u{1D420}', 'g'),  // MATHEMATICAL BOLD SMALL G
+	('\u{1D421}', 'h'),  // MATHEMATICAL BOLD SMALL H
+	('\u{1D422}', 'i'),  // MATHEMATICAL BOLD SMALL I
+	('\u{1D423}', 'j'),  // MATHEMATICAL BOLD SMALL J
+	('\u{1D424}', 'k'),  // MATHEMATICAL BOLD SMALL K
+	('\u{1D425}', 'l'),  // MATHEMATICAL BOLD SMALL L
+	('\u{1D427}', 'n'),  // MATHEMATICAL BOLD SMALL N
+	('\u{1D428}', 'o'),  // MATHEMATICAL BOLD SMALL O
+	('\u{1D429}', 'p'),  // MATHEMATICAL BOLD SMALL P
+	('\u{1D42A}', 'q'),  // MATHEMATICAL BOLD SMALL Q
+	('\u{1D42B}', 'r'),  // MATHEMATICAL BOLD SMALL R
+	('\u{1D42C}', 's'),  // MATHEMATICAL BOLD SMALL S
+	('\u{1D42D}', 't'),  // MATHEMATICAL BOLD SMALL T
+	('\u{1D42E}', 'u'),  // MATHEMATICAL BOLD SMALL U
+	('\u{1D42F}', 'v'),  // MATHEMATICAL BOLD SMALL V
+	('\u{1D430}', 'w'),  // MATHEMATICAL BOLD SMALL W
+	('\u{1D431}', 'x'),  // MATHEMATICAL BOLD SMALL X
+	('\u{1D432}', 'y'),  // MATHEMATICAL BOLD SMALL Y
+	('\u{1D433}', 'z'),  // MATHEMATICAL BOLD SMALL Z
+	('\u{1D434}', 'A'),  // MATHEMATICAL ITALIC CAPITAL A
+	('\u{1D435}', 'B'),  // MATHEMATICAL ITALIC CAPITAL B
+	('\u{1D436}', 'C'),  // MATHEMATICAL ITALIC CAPITAL C
+	('\u{1D437}', 'D'),  // MATHEMATICAL ITALIC CAPITAL D
+	('\u{1D438}', 'E'),  // MATHEMATICAL ITALIC CAPITAL E
+	('\u{1D439}', 'F'),  // MATHEMATICAL ITALIC CAPITAL F
+	('\u{1D43A}', 'G'),  // MATHEMATICAL ITALIC CAPITAL G
+	('\u{1D43B}', 'H'),  // MATHEMATICAL ITALIC CAPITAL H
+	('\u{1D43C}', 'l'),  // MATHEMATICAL ITALIC CAPITAL I
+	('\u{1D43D}', 'J'),  // MATHEMATICAL ITALIC CAPITAL J
+	('\u{1D43E}', 'K'),  // MATHEMATICAL ITALIC CAPITAL K
+	('\u{1D43F}', 'L'),  // MATHEMATICAL ITALIC CAPITAL L
+	('\u{1D440}', 'M'),  // MATHEMATICAL ITALIC CAPITAL M
+	('\u{1D441}', 'N'),  // MATHEMATICAL ITALIC CAPITAL N
+	('\u{1D442}', 'O'),  // MATHEMATICAL ITALIC CAPITAL O
+	('\u{1D443}', 'P'),  // MATHEMATICAL ITALIC CAPITAL P
+	('\u{1D444}', 'Q'),  // MATHEMATICAL ITALIC CAPITAL Q
+	('\u{1D445}', 'R'),  // MATHEMATICAL ITALIC CAPITAL R
+	('\u{1D446}', 'S'),  // MATHEMATICAL ITALIC CAPITAL S
+	('\u{1D447}', 'T'),  // MATHEMATICAL ITALIC CAPITAL T
+	('\u{1D448}', 'U'),  // MATHEMATICAL ITALIC CAPITAL U
+	('\u{1D449}', 'V'),  // MATHEMATICAL ITALIC CAPITAL V
+	('\u{1D44A}', 'W'),  // MATHEMATICAL ITALIC CAPITAL W
+	('\u{1D44B}', 'X'),  // MATHEMATICAL ITALIC CAPITAL X
+	('\u{1D44C}', 'Y'),  // MATHEMATICAL ITALIC CAPITAL Y
+	('\u{1D44D}', 'Z'),  // MATHEMATICAL ITALIC CAPITAL Z
+	('\u{1D44E}', 'a'),  // MATHEMATICAL ITALIC SMALL A
+	('\u{1D44F}', 'b'),  // MATHEMATICAL ITALIC SMALL B
+	('\u{1D450}', 'c'),  // MATHEMATICAL ITALIC SMALL C
+	('\u{1D451}', 'd'),  // MATHEMATICAL ITALIC SMALL D
+	('\u{1D452}', 'e'),  // MATHEMATICAL ITALIC SMALL E
+	('\u{1D453}', 'f'),  // MATHEMATICAL ITALIC SMALL F
+	('\u{1D454}', 'g'),  // MATHEMATICAL ITALIC SMALL G
+	('\u{1D456}', 'i'),  // MATHEMATICAL ITALIC SMALL I
+	('\u{1D457}', 'j'),  // MATHEMATICAL ITALIC SMALL J
+	('\u{1D458}', 'k'),  // MATHEMATICAL ITALIC SMALL K
+	('\u{1D459}', 'l'),  // MATHEMATICAL ITALIC SMALL L
+	('\u{1D45B}', 'n'),  // MATHEMATICAL ITALIC SMALL N
+	('\u{1D45C}', 'o'),  // MATHEMATICAL ITALIC SMALL O
+	('\u{1D45D}', 'p'),  // MATHEMATICAL ITALIC SMALL P
+	('\u{1D45E}', 'q'),  // MATHEMATICAL ITALIC SMALL Q
+	('\u{1D45F}', 'r'),  // MATHEMATICAL ITALIC SMALL R
+	('\u{1D460}', 's'),  // MATHEMATICAL ITALIC SMALL S
+	('\u{1D461}', 't'),  // MATHEMATICAL ITALIC SMALL T
+	('\u{1D462}', 'u'),  // MATHEMATICAL ITALIC SMALL U
+	('\u{1D463}', 'v'),  // MATHEMATICAL ITALIC SMALL V
+	('\u{1D464}', 'w'),  // MATHEMATICAL ITALIC SMALL W
+	('\u{1D465}', 'x'),  // MATHEMATICAL ITALIC SMALL X
+	('\u{1D466}', 'y'),  // MATHEMATICAL ITALIC SMALL Y
+	('\u{1D467}', 'z'),  // MATHEMATICAL ITALIC SMALL Z
+	('\u{1D468}', 'A'),  // MATHEMATICAL BOLD ITALIC CAPITAL A
+	('\u{1D469}', 'B'),  // MATHEMATICAL BOLD ITALIC CAPITAL B
+	('\u{1D46A}', 'C'),  // MATHEMATICAL BOLD ITALIC CAPITAL C
+	('\u{1D46B}', 'D'),  // MATHEMATICAL BOLD ITALIC CAPITAL D
+	('\u{1D46C}', 'E'),  // MATHEMATICAL BOLD ITALIC CAPITAL E
+	('\u{1D46D}', 'F'),  // MATHEMATICAL BOLD ITALIC CAPITAL F
+	('\u{1D46E}', 'G'),  // MATHEMATICAL BOLD ITALIC CAPITAL G
+	('\u{1D46F}', 'H'),  // MATHEMATICAL BOLD ITALIC CAPITAL H
+	('\u{1D470}', 'l'),  // MATHEMATICAL BOLD ITALIC CAPITAL I
+	('\u{1D471}', 'J'),  // MATHEMATICAL BOLD ITALIC CAPITAL J
+	('\u{1D472}', 'K'),  // MATHEMATICAL BOLD ITALIC CAPITAL K
+	('\u{1D473}', 'L'),  // MATHEMATICAL BOLD ITALIC CAPITAL L
+	('\u{1D474}', 'M'),  // MATHEMATICAL BOLD ITALIC CAPITAL M
+	('\u{1D475}', 'N'),  // MATHEMATICAL BOLD ITALIC CAPITAL N
+	('\u{1D476}', 'O'),  // MATHEMATICAL BOLD ITALIC CAPITAL O
+	('\u{1D477}', 'P'),  // MATHEMATICAL BOLD ITALIC CAPITAL P
+	('\u{1D478}', 'Q'),  // MATHEMATICAL BOLD ITALIC CAPITAL Q
+	('\u{1D479}', 'R'),  // MATHEMATICAL BOLD ITALIC CAPITAL R
+	('\u{1D47A}', 'S'),  // MATHEMATICAL BOLD ITALIC CAPITAL S
+	('\u{1D47B}', 'T'),  // MATHEMATICAL BOLD ITALIC CAPITAL T
+	('\u{1D47C}', 'U'),  // MATHEMATICAL BOLD ITALIC CAPITAL U
+	('\u{1D47D}', 'V'),  // MATHEMATICAL BOLD ITALIC CAPITAL V
+	('\u{1D47E}', 'W'),  // MATHEMATICAL BOLD ITALIC CAPITAL W
+	('\u{1D47F}', 'X'),  // MATHEMATICAL BOLD ITALIC CAPITAL X
+	('\u{1D480}', 'Y'),  // MATHEMATICAL BOLD ITALIC CAPITAL Y
+	('\u{1D481}', 'Z'),  // MATHEMATICAL BOLD ITALIC CAPITAL Z
+	('\u{1D482}', 'a'),  // MATHEMATICAL BOLD ITALIC SMALL A
+	('\u{1D483}', 'b'),  // MATHEMATICAL BOLD ITALIC SMALL B
+	('\u{1D484}', 'c'),  // MATHEMATICAL BOLD ITALIC SMALL C
+	('\u{1D485}', 'd'),  // MATHEMATICAL BOLD ITALIC SMALL D
+	('\u{1D486}', 'e'),  // MATHEMATICAL BOLD ITALIC SMALL E
+	('\u{1D487}', 'f'),  // MATHEMATICAL BOLD ITALIC SMALL F
+	('\u{1D488}', 'g'),  // MATHEMATICAL BOLD ITALIC SMALL G
+	('\u{1D489}', 'h'),  // MATHEMATICAL BOLD ITALIC SMALL H
+	('\u{1D48A}', 'i'),  // MATHEMATICAL BOLD ITALIC SMALL I
+	('\u{1D48B}', 'j'),  // MATHEMATICAL BOLD ITALIC SMALL J
+	('\u{1D48C}', 'k'),  // MATHEMATICAL BOLD ITALIC SMALL K
+	('\u{1D48D}', 'l'),  // MATHEMATICAL BOLD ITALIC SMALL L
+	('\u{1D48F}', 'n'),  // MATHEMATICAL BOLD ITALIC SMALL N
+	('\u{1D490}', 'o'),  // MATHEMATICAL BOLD ITALIC SMALL O
+	('\u{1D491}', 'p'),  // MATHEMATICAL BOLD ITALIC SMALL P
+	('\u{1D492}', 'q'),  // MATHEMATICAL BOLD ITALIC SMALL Q
+	('\u{1D493}', 'r'),  // MATHEMATICAL BOLD ITALIC SMALL R
+	('\u{1D494}', 's'),  // MATHEMATICAL BOLD ITALIC SMALL S
+	('\u{1D495}', 't'),  // MATHEMATICAL BOLD ITALIC SMALL T
+	('\u{1D496}', 'u'),  // MATHEMATICAL BOLD ITALIC SMALL U
+	('\u{1D497}', 'v'),  // MATHEMATICAL BOLD ITALIC SMALL V
+	('\u{1D498}', 'w'),  // MATHEMATICAL BOLD ITALIC SMALL W
+	('\u{1D499}', 'x'),  // MATHEMATICAL BOLD ITALIC SMALL X
+	('\u{1D49A}', 'y'),  // MATHEMATICAL BOLD ITALIC SMALL Y
+	('\u{1D49B}', 'z'),  // MATHEMATICAL BOLD ITALIC SMALL Z
+	('\u{1D49C}', 'A'),  // MATHEMATICAL SCRIPT CAPITAL A
+	('\u{1D49E}', 'C'),  // MATHEMATICAL SCRIPT CAPITAL C
+	('\u{1D49F}', 'D'),  // MATHEMATICAL SCRIPT CAPITAL D
+	('\u{1D4A2}', 'G'),  // MATHEMATICAL SCRIPT CAPITAL G
+	('\u{1D4A5}', 'J'),  // MATHEMATICAL SCRIPT CAPITAL J
+	('\u{1D4A6}', 'K'),  // MATHEMATICAL SCRIPT CAPITAL K
+	('\u{1D4A9}', 'N'),  // MATHEMATICAL SCRIPT CAPITAL N
+	('\u{1D4AA}', 'O'),  // MATHEMATICAL SCRIPT CAPITAL O
+	('\u{1D4AB}', 'P'),  // MATHEMATICAL SCRIPT CAPITAL P
+	('\u{1D4AC}', 'Q'),  // MATHEMATICAL SCRIPT CAPITAL Q
+	('\u{1D4AE}', 'S'),  // MATHEMATICAL SCRIPT CAPITAL S
+	('\u{1D4AF}', 'T'),  // MATHEMATICAL SCRIPT CAPITAL T
+	('\u{1D4B0}', 'U'),  // MATHEMATICAL SCRIPT CAPITAL U
+	('\u{1D4B1}', 'V'),  // MATHEMATICAL SCRIPT CAPITAL V
+	('\u{1D4B2}', 'W'),  // MATHEMATICAL SCRIPT CAPITAL W
+	('\u{1D4B3}', 'X'),  // MATHEMATICAL SCRIPT CAPITAL X
+	('\u{1D4B4}', 'Y'),  // MATHEMATICAL SCRIPT CAPITAL Y
+	('\u{1D4B5}', 'Z'),  // MATHEMATICAL SCRIPT CAPITAL Z
+	('\u{1D4B6}', 'a'),  // MATHEMATICAL SCRIPT SMALL A
+	('\u{1D4B7}', 'b'),  // MATHEMATICAL SCRIPT SMALL B
+	('\u{1D4B8}', 'c'),  // MATHEMATICAL SCRIPT SMALL C
+	('\u{1D4B9}', 'd'),  // MATHEMATICAL SCRIPT SMALL D
+	('\u{1D4BB}', 'f'),  // MATHEMATICAL SCRIPT SMALL F
+	('\u{1D4BD}', 'h'),  // MATHEMATICAL SCRIPT SMALL H
+	('\u{1D4BE}', 'i'),  // MATHEMATICAL SCRIPT SMALL I
+	('\u{1D4BF}', 'j'),  // MATHEMATICAL SCRIPT SMALL J
+	('\u{1D4C0}', 'k'),  // MATHEMATICAL SCRIPT SMALL K
+	('\u{1D4C1}', 'l'),  // MATHEMATICAL SCRIPT SMALL L
+	('\u{1D4C3}', 'n'),  // MATHEMATICAL SCRIPT SMALL N
+	('\u{1D4C5}', 'p'),  // MATHEMATICAL SCRIPT SMALL P
+	('\u{1D4C6}', 'q'),  // MATHEMATICAL SCRIPT SMALL Q
+	('\u{1D4C7}', 'r'),  // MATHEMATICAL SCRIPT SMALL R
+	('\u{1D4C8}', 's'),  // MATHEMATICAL SCRIPT SMALL S
+	('\u{1D4C9}', 't'),  // MATHEMATICAL SCRIPT SMALL T
+	('\u{1D4CA}', 'u'),  // MATHEMATICAL SCRIPT SMALL U
+	('\u{1D4CB}', 'v'),  // MATHEMATICAL SCRIPT SMALL V
+	('\u{1D4CC}', 'w'),  // MATHEMATICAL SCRIPT SMALL W
+	('\u{1D4CD}', 'x'),  // MATHEMATICAL SCRIPT SMALL X
+	('\u{1D4CE}', 'y'),  // MATHEMATICAL SCRIPT SMALL Y
+	('\u{1D4CF}', 'z'),  // MATHEMATICAL SCRIPT SMALL Z
+	('\u{1D4D0}', 'A'),  // MATHEMATICAL BOLD SCRIPT CAPITAL A
+	('\u{1D4D1}', 'B'),  // MATHEMATICAL BOLD SCRIPT CAPITAL B
+	('\u{1D4D2}', 'C'),  // MATHEMATICAL BOLD SCRIPT CAPITAL C
+	('\u{1D4D3}', 'D'),  // MATHEMATICAL BOLD SCRIPT CAPITAL D
+	('\u{1D4D4}', 'E'),  // MATHEMATICAL BOLD SCRIPT CAPITAL E
+	('\u{1D4D5}', 'F'),  // MATHEMATICAL BOLD SCRIPT CAPITAL F
+	('\u{1D4D6}', 'G'),  // MATHEMATICAL BOLD SCRIPT CAPITAL G
+	('\u{1D4D7}', 'H'),  // MATHEMATICAL BOLD SCRIPT CAPITAL H
+	('\u{1D4D8}', 'l'),  // MATHEMATICAL BOLD SCRIPT CAPITAL I
+	('\u{1D4D9}', 'J'),  // MATHEMATICAL BOLD SCRIPT CAPITAL J
+	('\u{1D4DA}', 'K'),  // MATHEMATICAL BOLD SCRIPT CAPITAL K
+	('\u{1D4DB}', 'L'),  // MATHEMATICAL BOLD SCRIPT CAPITAL L
+	('\u{1D4DC}', 'M'),  // MATHEMATICAL BOLD SCRIPT CAPITAL M
+	('\u{1D4DD}', 'N'),  // MATHEMATICAL BOLD SCRIPT CAPITAL N
+	('\u{1D4DE}', 'O'),  // MATHEMATICAL BOLD SCRIPT CAPITAL O
+	('\u{1D4DF}', 'P'),  // MATHEMATICAL BOLD SCRIPT CAPITAL P
+	('\u{1D4E0}', 'Q'),  // MATHEMATICAL BOLD SCRIPT CAPITAL Q
+	('\u{1D4E1}', 'R'),  // MATHEMATICAL BOLD SCRIPT CAPITAL R
+	('\u{1D4E2}', 'S'),  // MATHEMATICAL BOLD SCRIPT CAPITAL S
+	('\u{1D4E3}', 'T'),  // MATHEMATICAL BOLD SCRIPT CAPITAL T
+	('\u{1D4E4}', 'U'),  // MATHEMATICAL BOLD SCRIPT CAPITAL U
+	('\u{1D4E5}', 'V'),  // MATHEMATICAL BOLD SCRIPT CAPITAL V
+	('\u{1D4E6}', 'W'),  // MATHEMATICAL BOLD SCRIPT CAPITAL W
+	('\u{1D4E7}', 'X'),  // MATHEMATICAL BOLD SCRIPT CAPITAL X
+	('\u{1D4E8}', 'Y'),  // MATHEMATICAL BOLD SCRIPT CAPITAL Y
+	('\u{1D4E9}', 'Z'),  // MATHEMATICAL BOLD SCRIPT CAPITAL Z
+	('\u{1D4EA}', 'a'),  // MATHEMATICAL BOLD SCRIPT SMALL A
+	('\u{1D4EB}', 'b'),  // MATHEMATICAL BOLD SCRIPT SMALL B
+	('\u{1D4EC}', 'c'),  // MATHEMATICAL BOLD SCRIPT SMALL C
+	('\u{1D4ED}', 'd'),  // MATHEMATICAL BOLD SCRIPT SMALL D
+	('\u{1D4EE}', 'e'),  // MATHEMATICAL BOLD SCRIPT SMALL E
+	('\u{1D4EF}', 'f'),  // MATHEMATICAL BOLD SCRIPT SMALL F
+	('\u{1D4F0}', 'g'),  // MATHEMATICAL BOLD SCRIPT SMALL G
+	('\u{1D4F1}', 'h'),  // MATHEMATICAL BOLD SCRIPT SMALL H
+	('\u{1D4F2}', 'i'),  // MATHEMATICAL BOLD SCRIPT SMALL I
+	('\u{1D4F3}', 'j'),  // MATHEMATICAL BOLD SCRIPT SMALL J
+	('\u{1D4F4}', 'k'),  // MATHEMATICAL BOLD SCRIPT SMALL K
+	('\u{1D4F5}', 'l'),  // MATHEMATICAL BOLD SCRIPT SMALL L
+	('\u{1D4F7}', 'n'),  // MATHEMATICAL BOLD SCRIPT SMALL N
+	('\u{1D4F8}', 'o'),  // MATHEMATICAL BOLD SCRIPT SMALL O
+	('\u{1D4F9}', 'p'),  // MATHEMATICAL BOLD SCRIPT SMALL P
+	('\u{1D4FA}', 'q'),  // MATHEMATICAL BOLD SCRIPT SMALL Q
+	('\u{1D4FB}', 'r'),  // MATHEMATICAL BOLD SCRIPT SMALL R
+	('\u{1D4FC}', 's'),  // MATHEMATICAL BOLD SCRIPT SMALL S
+	('\u{1D4FD}', 't'),  // MATHEMATICAL BOLD SCRIPT SMALL T
+	('\u{1D4FE}', 'u'),  // MATHEMATICAL BOLD SCRIPT SMALL U
+	('\u{1D4FF}', 'v'),  // MATHEMATICAL BOLD SCRIPT SMALL V
+	('\u{1D500}', 'w'),  // MATHEMATICAL BOLD SCRIPT SMALL W
+	('\u{1D501}', 'x'),  // MATHEMATICAL BOLD SCRIPT SMALL X
+	('\u{1D502}', 'y'),  // MATHEMATICAL BOLD SCRIPT SMALL Y
+	('\u{1D503}', 'z'),  // MATHEMATICAL BOLD SCRIPT SMALL Z
+	('\u{1D504}', 'A'),  // MATHEMATICAL FRAKTUR CAPITAL A
+	('\u{1D505}', 'B'),  // MATHEMATICAL FRAKTUR CAPITAL B
+	('\u{1D507}', 'D'),  // MATHEMATICAL FRAKTUR CAPITAL D
+	('\u{1D508}', 'E'),  // MATHEMATICAL FRAKTUR CAPITAL E
+	('\u{1D509}', 'F'),  // MATHEMATICAL FRAKTUR CAPITAL F
+	('\u{1D50A}', 'G'),  // MATHEMATICAL FRAKTUR CAPITAL G
+	('\u{1D50D}', 'J'),  // MATHEMATICAL FRAKTUR CAPITAL J
+	('\u{1D50E}', 'K'),  // MATHEMATICAL FRAKTUR CAPITAL K
+	('\u{1D50F}', 'L'),  // MATHEMATICAL FRAKTUR CAPITAL L
+	('\u{1D510}', 'M'),  // MATHEMATICAL FRAKTUR CAPITAL M
+	('\u{1D511}', 'N'),  // MATHEMATICAL FRAKTUR CAPITAL N
+	('\u{1D512}', 'O'),  // MATHEMATICAL FRAKTUR CAPITAL O
+	('\u{1D513}', 'P'),  // MATHEMATICAL FRAKTUR CAPITAL P
+	('\u{1D514}', 'Q'),  // MATHEMATICAL FRAKTUR CAPITAL Q
+	('\u{1D516}', 'S'),  // MATHEMATICAL FRAKTUR CAPITAL S
+	('\u{1D517}', 'T'),  // MATHEMATICAL FRAKTUR CAPITAL T
+	('\u{1D518}', 'U'),  // MATHEMATICAL FRAKTUR CAPITAL U
+	('\u{1D519}', 'V'),  // MATHEMATICAL FRAKTUR CAPITAL V
+	('\u{1D51A}', 'W'),  // MATHEMATICAL FRAKTUR CAPITAL W
+	('\u{1D51B}', 'X'),  // MATHEMATICAL FRAKTUR CAPITAL X
+	('\u{1D51C}', 'Y'),  // MATHEMATICAL FRAKTUR CAPITAL Y
+	('\u{1D51E}', 'a'),  // MATHEMATICAL FRAKTUR SMALL A
+	('\u{1D51F}', 'b'),  // MATHEMATICAL FRAKTUR SMALL B
+	('\u{1D520}', 'c'),  // MATHEMATICAL FRAKTUR SMALL C
+	('\u{1D521}', 'd'),  // MATHEMATICAL FRAKTUR SMALL D
+	('\u{1D522}', 'e'),  // MATHEMATICAL FRAKTUR SMALL E
+	('\u{1D523}', 'f'),  // MATHEMATICAL FRAKTUR SMALL F
+	('\u{1D524}', 'g'),  // MATHEMATICAL FRAKTUR SMALL G
+	('\u{1D525}', 'h'),  // MATHEMATICAL FRAKTUR SMALL H
+	('\u{1D526}', 'i'),  // MATHEMATICAL FRAKTUR SMALL I
+	('\u{1D527}', 'j'),  // MATHEMATICAL FRAKTUR SMALL J
+	('\u{1D528}', 'k'),  // MATHEMATICAL FRAKTUR SMALL K
+	('\u{1D529}', 'l'),  // MATHEMATICAL FRAKTUR SMALL L
+	('\u{1D52B}', 'n'),  // MATHEMATICAL FRAKTUR SMALL N
+	('\u{1D52C}', 'o'),  // MATHEMATICAL FRAKTUR SMALL O
+	('\u{1D52D}', 'p'),  // MATHEMATICAL FRAKTUR SMALL P
+	('\u{1D52E}', 'q'),  // MATHEMATICAL FRAKTUR SMALL Q
+	('\u{1D52F}', 'r'),  // MATHEMATICAL FRAKTUR SMALL R
+	('\u{1D530}', 's'),  // MATHEMATICAL FRAKTUR SMALL S
+	('\u{1D531}', 't'),  // MATHEMATICAL FRAKTUR SMALL T
+	('\u{1D532}', 'u'),  // MATHEMATICAL FRAKTUR SMALL U
+	('\u{1D533}', 'v'),  // MATHEMATICAL FRAKTUR SMALL V
+	('\u{1D534}', 'w'),  // MATHEMATICAL FRAKTUR SMALL W
+	('\u{1D535}', 'x'),  // MATHEMATICAL FRAKTUR SMALL X
+	('\u{1D536}', 'y'),  // MATHEMATICAL FRAKTUR SMALL Y
+	('\u{1D537}', 'z'),  // MATHEMATICAL FRAKTUR SMALL Z
+	('\u{1D538}', 'A'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL A
+	('\u{1D539}', 'B'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL B
+	('\u{1D53B}', 'D'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL D
+	('\u{1D53C}', 'E'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL E
+	('\u{1D53D}', 'F'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL F
+	('\u{1D53E}', 'G'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL G
+	('\u{1D540}', 'l'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL I
+	('\u{1D541}', 'J'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL J
+	('\u{1D542}', 'K'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL K
+	('\u{1D543}', 'L'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL L
+	('\u{1D544}', 'M'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL M
+	('\u{1D546}', 'O'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL O
+	('\u{1D54A}', 'S'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL S
+	('\u{1D54B}', 'T'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL T
+	('\u{1D54C}', 'U'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL U
+	('\u{1D54D}', 'V'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL V
+	('\u{1D54E}', 'W'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL W
+	('\u{1D54F}', 'X'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL X
+	('\u{1D550}', 'Y'),  // MATHEMATICAL DOUBLE-STRUCK CAPITAL Y
+	('\u{1D552}', 'a'),  // MATHEMATICAL DOUBLE-STRUCK SMALL A
+	('\u{1D553}', 'b'),  // MATHEMATICAL DOUBLE-STRUCK SMALL B
+	('\u{1D554}', 'c'),  // MATHEMATICAL DOUBLE-STRUCK SMALL C
+	('\u{1D555}', 'd'),  // MATHEMATICAL DOUBLE-STRUCK SMALL D
+	('\u{1D556}', 'e'),  // MATHEMATICAL DOUBLE-STRUCK SMALL E
+	('\u{1D557}', 'f'),  // MATHEMATICAL DOUBLE-STRUCK SMALL F
+	('\u{1D558}', 'g'),  // MATHEMATICAL DOUBLE-STRUCK SMALL G
+	('\u{1D559}', 'h'),  // MATHEMATICAL DOUBLE-STRUCK SMALL H
+	('\u{1D55A}', 'i'),  // MATHEMATICAL DOUBLE-STRUCK SMALL I
+	('\u{1D55B}', 'j'),  // MATHEMATICAL DOUBLE-STRUCK SMALL J
+	('\u{1D55C}', 'k'),  // MATHEMATICAL DOUBLE-STRUCK SMALL K
+	('\u{1D55D}', 'l'),  // MATHEMATICAL DOUBLE-STRUCK SMALL L
+	('\u{1D55F}', 'n'),  // MATHEMATICAL DOUBLE-STRUCK SMALL N
+	('\u{1D560}', 'o'),  // MATHEMATICAL DOUBLE-STRUCK SMALL O
+	('\u{1D561}', 'p'),  // MATHEMATICAL DOUBLE-STRUCK SMALL P
+	('\u{1D562}', 'q'),  // MATHEMATICAL DOUBLE-STRUCK SMALL Q
+	('\u{1D563}', 'r'),  // MATHEMATICAL DOUBLE-STRUCK SMALL R
+	('\u{1D564}', 's'),  // MATHEMATICAL DOUBLE-STRUCK SMALL S
+	('\u{1D565}', 't'),  // MATHEMATICAL DOUBLE-STRUCK SMALL T
+	('\u{1D566}', 'u'),  // MATHEMATICAL DOUBLE-STRUCK SMALL U
+	('\u{1D567}', 'v'),  // MATHEMATICAL DOUBLE-STRUCK SMALL V
+	('\u{1D568}', 'w'),  // MATHEMATICAL DOUBLE-STRUCK SMALL W
+	('\u{1D569}', 'x'),  // MATHEMATICAL DOUBLE-STRUCK SMALL X
+	('\u{1D56A}', 'y'),  // MATHEMATICAL DOUBLE-STRUCK SMALL Y
+	('\u{1D56B}', 'z'),  // MATHEMATICAL DOUBLE-STRUCK SMALL Z
+	('\u{1D56C}', 'A'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL A
+	('\u{1D56D}', 'B'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL B
+	('\u{1D56E}', 'C'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL C
+	('\u{1D56F}', 'D'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL D
+	('\u{1D570}', 'E'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL E
+	('\u{1D571}', 'F'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL F
+	('\u{1D572}', 'G'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL G
+	('\u{1D573}', 'H'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL H
+	('\u{1D574}', 'l'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL I
+	('\u{1D575}', 'J'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL J
+	('\u{1D576}', 'K'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL K
+	('\u{1D577}', 'L'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL L
+	('\u{1D578}', 'M'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL M
+	('\u{1D579}', 'N'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL N
+	('\u{1D57A}', 'O'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL O
+	('\u{1D57B}', 'P'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL P
+	('\u{1D57C}', 'Q'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL Q
+	('\u{1D57D}', 'R'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL R
+	('\u{1D57E}', 'S'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL S
+	('\u{1D57F}', 'T'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL T
+	('\u{1D580}', 'U'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL U
+	('\u{1D581}', 'V'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL V
+	('\u{1D582}', 'W'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL W
+	('\u{1D583}', 'X'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL X
+	('\u{1D584}', 'Y'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL Y
+	('\u{1D585}', 'Z'),  // MATHEMATICAL BOLD FRAKTUR CAPITAL Z
+	('\u{1D586}', 'a'),  // MATHEMATICAL BOLD FRAKTUR SMALL A
+	('\u{1D587}', 'b'),  // MATHEMATICAL BOLD FRAKTUR SMALL B
+	('\u{1D588}', 'c'),  // MATHEMATICAL BOLD FRAKTUR SMALL C
+	('\u{1D589}', 'd'),  // MATHEMATICAL BOLD FRAKTUR SMALL D
+	('\u{1D58A}', 'e'),  // MATHEMATICAL BOLD FRAKTUR SMALL E
+	('\u{1D58B}', 'f'),  // MATHEMATICAL BOLD FRAKTUR SMALL F
+	('\u{1D58C}', 'g'),  // MATHEMATICAL BOLD FRAKTUR SMALL G
+	('\u{1D58D}', 'h'),  // MATHEMATICAL BOLD FRAKTUR SMALL H
+	('\u{1D58E}', 'i'),  // MATHEMATICAL BOLD FRAKTUR SMALL I
+	('\u{1D58F}', 'j'),  // MATHEMATICAL BOLD FRAKTUR SMALL J
+	('\u{1D590}', 'k'),  // MATHEMATICAL BOLD FRAKTUR SMALL K
+	('\u{1D591}', 'l'),  // MATHEMATICAL BOLD FRAKTUR SMALL L
+	('\u{1D593}', 'n'),  // MATHEMATICAL BOLD FRAKTUR SMALL N
+	('\u{1D594}', 'o'),  // MATHEMATICAL BOLD FRAKTUR SMALL O
+	('\u{1D595}', 'p'),  // MATHEMATICAL BOLD FRAKTUR SMALL P
+	('\u{1D596}', 'q'),  // MATHEMATICAL BOLD FRAKTUR SMALL Q
+	('\u{1D597}', 'r'),  // MATHEMATICAL BOLD FRAKTUR SMALL R
+	('\u{1D598}', 's'),  // MATHEMATICAL BOLD FRAKTUR SMALL S
+	('\u{1D599}', 't'),  // MATHEMATICAL BOLD FRAKTUR SMALL T
+	('\u{1D59A}', 'u'),  // MATHEMATICAL BOLD FRAKTUR SMALL U
+	('\u{1D59B}', 'v'),  // MATHEMATICAL BOLD FRAKTUR SMALL V
+	('\u{1D59C}', 'w'),  // MATHEMATICAL BOLD FRAKTUR SMALL W
+	('\u{1D59D}', 'x'),  // MATHEMATICAL BOLD FRAKTUR SMALL X
+	('\u{1D59E}', 'y'),  // MATHEMATICAL BOLD FRAKTUR SMALL Y
+	('\u{1D59F}', 'z'),  // MATHEMATICAL BOLD FRAKTUR SMALL Z
+	('\u{1D5A0}', 'A'),  // MATHEMATICAL SANS-SERIF CAPITAL A
+	('\u{1D5A1}', 'B'),  // MATHEMATICAL SANS-SERIF CAPITAL B
+	('\u{1D5A2}', 'C'),  // MATHEMATICAL SANS-SERIF CAPITAL C
+	('\u{1D5A3}', 'D'),  // MATHEMATICAL SANS-SERIF CAPITAL D
+	('\u{1D5A4}', 'E'),  // MATHEMATICAL SANS-SERIF CAPITAL E
+	('\u{1D5A5}', 'F'),  // MATHEMATICAL SANS-SERIF CAPITAL F
+	('\u{1D5A6}', 'G'),  // MATHEMATICAL SANS-SERIF CAPITAL G
+	('\u{1D5A7}', 'H'),  // MATHEMATICAL SANS-SERIF CAPITAL H
+	('\u{1D5A8}', 'l'),  // MATHEMATICAL SANS-SERIF CAPITAL I
+	('\u{1D5A9}', 'J'),  // MATHEMATICAL SANS-SERIF CAPITAL J
+	('\u{1D5AA}', 'K'),  // MATHEMATICAL SANS-SERIF CAPITAL K
+	('\u{1D5AB}', 'L'),  // MATHEMATICAL SANS-SERIF CAPITAL L
+	('\u{1D5AC}', 'M'),  // MATHEMATICAL SANS-SERIF CAPITAL M
+	('\u{1D5AD}', 'N'),  // MATHEMATICAL SANS-SERIF CAPITAL N
+	('\u{1D5AE}', 'O'),  // MATHEMATICAL SANS-SERIF CAPITAL O
+	('\u{1D5AF}', 'P'),  // MATHEMATICAL SANS-SERIF CAPITAL P
+	('\u{1D5B0}', 'Q'),  // MATHEMATICAL SANS-SERIF CAPITAL Q
+	('\u{1D5B1}', 'R'),  // MATHEMATICAL SANS-SERIF CAPITAL R
+	('\u{1D5B2}', 'S'),  // MATHEMATICAL SANS-SERIF CAPITAL S
+	('\u{1D5B3}', 'T'),  // MATHEMATICAL SANS-SERIF CAPITAL T
+	('\u{1D5B4}', 'U'),  // MATHEMATICAL SANS-SERIF CAPITAL U
+	('\u{1D5B5}', 'V'),  // MATHEMATICAL SANS-SERIF CAPITAL V
+	('\u{1D5B6}', 'W'),  // MATHEMATICAL SANS-SERIF CAPITAL W
+	('\u{1D5B7}', 'X'),  // MATHEMATICAL SANS-SERIF CAPITAL X
+	('\u{1D5B8}', 'Y'),  // MATHEMATICAL SANS-SERIF CAPITAL Y
+	('\u{1D5B9}', 'Z'),  // MATHEMATICAL SANS-SERIF CAPITAL Z
+	('\u{1D5BA}', 'a'),  // MATHEMATICAL SANS-SERIF SMALL A
+	('\u{1D5BB}', 'b'),  // MATHEMATICAL SANS-SERIF SMALL B
+	('\u{1D5BC}', 'c'),  // MATHEMATICAL SANS-SERIF SMALL C
+	('\u{1D5BD}', 'd'),  // MATHEMATICAL SANS-SERIF SMALL D
+	('\u{1D5BE}', 'e'),  // MATHEMATICAL SANS-SERIF SMALL E
+	('\u{1D5BF}', 'f'),  // MATHEMATICAL SANS-SERIF SMALL F
+	('\u{1D5C0}', 'g'),  // MATHEMATICAL SANS-SERIF SMALL G
+	('\u{1D5C1}', 'h'),  // MATHEMATICAL SANS-SERIF SMALL H
+	('\u{1D5C2}', 'i'),  // MATHEMATICAL SANS-SERIF SMALL I
+	('\u{1D5C3}', 'j'),  // MATHEMATICAL SANS-SERIF SMALL J
+	('\u{1D5C4}', 'k'),  // MATHEMATICAL SANS-SERIF SMALL K
+	('\u{1D5C5}', 'l'),  // MATHEMATICAL SANS-SERIF SMALL L
+	('\u{1D5C7}', 'n'),  // MATHEMATICAL SANS-SERIF SMALL N
+	('\u{1D5C8}', 'o'),  // MATHEMATICAL SANS-SERIF SMALL O
+	('\u{1D5C9}', 'p'),  // MATHEMATICAL SANS-SERIF SMALL P
+	('\u{1D5CA}', 'q'),  // MATHEMATICAL SANS-SERIF SMALL Q
+	('\u{1D5CB}', 'r'),  // MATHEMATICAL SANS-SERIF SMALL R
+	('\u{1D5CC}', 's'),  // MATHEMATICAL SANS-SERIF SMALL S
+	('\u{1D5CD}', 't'),  // MATHEMATICAL SANS-SERIF SMALL T
+	('\u{1D5CE}', 'u'),  // MATHEMATICAL SANS-SERIF SMALL U
+	('\u{1D5CF}', 'v'),  // MATHEMATICAL SANS-SERIF SMALL V
+	('\u{1D5D0}', 'w'),  // MATHEMATICAL SANS-SERIF SMALL W
+	('\u{1D5D1}', 'x'),  // MATHEMATICAL SANS-SERIF SMALL X
+	('\u{1D5D2}', 'y'),  // MATHEMATICAL SANS-SERIF SMALL Y
+	('\u{1D5D3}', 'z'),  // MATHEMATICAL SANS-SERIF SMALL Z
+	('\u{1D5D4}', 'A'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL A
+	('\u{1D5D5}', 'B'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL B
+	('\u{1D5D6}', 'C'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL C
+	('\u{1D5D7}', 'D'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL D
+	('\u{1D5D8}', 'E'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL E
+	('\u{1D5D9}', 'F'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL F
+	('\u{1D5DA}', 'G'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL G
+	('\u{1D5DB}', 'H'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL H
+	('\u{1D5DC}', 'l'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL I
+	('\u{1D5DD}', 'J'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL J
+	('\u{1D5DE}', 'K'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL K
+	('\u{1D5DF}', 'L'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL L
+	('\u{1D5E0}', 'M'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL M
+	('\u{1D5E1}', 'N'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL N
+	('\u{1D5E2}', 'O'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL O
+	('\u{1D5E3}', 'P'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL P
+	('\u{1D5E4}', 'Q'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL Q
+	('\u{1D5E5}', 'R'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL R
+	('\u{1D5E6}', 'S'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL S
+	('\u{1D5E7}', 'T'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL T
+	('\u{1D5E8}', 'U'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL U
+	('\u{1D5E9}', 'V'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL V
+	('\u{1D5EA}', 'W'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL W
+	('\u{1D5EB}', 'X'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL X
+	('\u{1D5EC}', 'Y'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL Y
+	('\u{1D5ED}', 'Z'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL Z
+	('\u{1D5EE}', 'a'),  // MATHEMATICAL SANS-SERIF BOLD SMALL A
+	('\u{1D5EF}', 'b'),  // MATHEMATICAL SANS-SERIF BOLD SMALL B
+	('\u{1D5F0}', 'c'),  // MATHEMATICAL SANS-SERIF BOLD SMALL C
+	('\u{1D5F1}', 'd'),  // MATHEMATICAL SANS-SERIF BOLD SMALL D
+	('\u{1D5F2}', 'e'),  // MATHEMATICAL SANS-SERIF BOLD SMALL E
+	('\u{1D5F3}', 'f'),  // MATHEMATICAL SANS-SERIF BOLD SMALL F
+	('\u{1D5F4}', 'g'),  // MATHEMATICAL SANS-SERIF BOLD SMALL G
+	('\u{1D5F5}', 'h'),  // MATHEMATICAL SANS-SERIF BOLD SMALL H
+	('\u{1D5F6}', 'i'),  // MATHEMATICAL SANS-SERIF BOLD SMALL I
+	('\u{1D5F7}', 'j'),  // MATHEMATICAL SANS-SERIF BOLD SMALL J
+	('\u{1D5F8}', 'k'),  // MATHEMATICAL SANS-SERIF BOLD SMALL K
+	('\u{1D5F9}', 'l'),  // MATHEMATICAL SANS-SERIF BOLD SMALL L
+	('\u{1D5FB}', 'n'),  // MATHEMATICAL SANS-SERIF BOLD SMALL N
+	('\u{1D5FC}', 'o'),  // MATHEMATICAL SANS-SERIF BOLD SMALL O
+	('\u{1D5FD}', 'p'),  // MATHEMATICAL SANS-SERIF BOLD SMALL P
+	('\u{1D5FE}', 'q'),  // MATHEMATICAL SANS-SERIF BOLD SMALL Q
+	('\u{1D5FF}', 'r'),  // MATHEMATICAL SANS-SERIF BOLD SMALL R
+	('\u{1D600}', 's'),  // MATHEMATICAL SANS-SERIF BOLD SMALL S
+	('\u{1D601}', 't'),  // MATHEMATICAL SANS-SERIF BOLD SMALL T
+	('\u{1D602}', 'u'),  // MATHEMATICAL SANS-SERIF BOLD SMALL U
+	('\u{1D603}', 'v'),  // MATHEMATICAL SANS-SERIF BOLD SMALL V
+	('\u{1D604}', 'w'),  // MATHEMATICAL SANS-SERIF BOLD SMALL W
+	('\u{1D605}', 'x'),  // MATHEMATICAL SANS-SERIF BOLD SMALL X
+	('\u{1D606}', 'y'),  // MATHEMATICAL SANS-SERIF BOLD SMALL Y
+	('\u{1D607}', 'z'),  // MATHEMATICAL SANS-SERIF BOLD SMALL Z
+	('\u{1D608}', 'A'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL A
+	('\u{1D609}', 'B'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL B
+	('\u{1D60A}', 'C'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL C
+	('\u{1D60B}', 'D'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL D
+	('\u{1D60C}', 'E'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL E
+	('\u{1D60D}', 'F'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL F
+	('\u{1D60E}', 'G'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL G
+	('\u{1D60F}', 'H'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL H
+	('\u{1D610}', 'l'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL I
+	('\u{1D611}', 'J'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL J
+	('\u{1D612}', 'K'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL K
+	('\u{1D613}', 'L'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL L
+	('\u{1D614}', 'M'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL M
+	('\u{1D615}', 'N'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL N
+	('\u{1D616}', 'O'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL O
+	('\u{1D617}', 'P'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL P
+	('\u{1D618}', 'Q'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL Q
+	('\u{1D619}', 'R'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL R
+	('\u{1D61A}', 'S'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL S
+	('\u{1D61B}', 'T'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL T
+	('\u{1D61C}', 'U'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL U
+	('\u{1D61D}', 'V'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL V
+	('\u{1D61E}', 'W'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL W
+	('\u{1D61F}', 'X'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL X
+	('\u{1D620}', 'Y'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL Y
+	('\u{1D621}', 'Z'),  // MATHEMATICAL SANS-SERIF ITALIC CAPITAL Z
+	('\u{1D622}', 'a'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL A
+	('\u{1D623}', 'b'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL B
+	('\u{1D624}', 'c'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL C
+	('\u{1D625}', 'd'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL D
+	('\u{1D626}', 'e'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL E
+	('\u{1D627}', 'f'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL F
+	('\u{1D628}', 'g'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL G
+	('\u{1D629}', 'h'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL H
+	('\u{1D62A}', 'i'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL I
+	('\u{1D62B}', 'j'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL J
+	('\u{1D62C}', 'k'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL K
+	('\u{1D62D}', 'l'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL L
+	('\u{1D62F}', 'n'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL N
+	('\u{1D630}', 'o'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL O
+	('\u{1D631}', 'p'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL P
+	('\u{1D632}', 'q'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL Q
+	('\u{1D633}', 'r'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL R
+	('\u{1D634}', 's'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL S
+	('\u{1D635}', 't'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL T
+	('\u{1D636}', 'u'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL U
+	('\u{1D637}', 'v'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL V
+	('\u{1D638}', 'w'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL W
+	('\u{1D639}', 'x'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL X
+	('\u{1D63A}', 'y'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL Y
+	('\u{1D63B}', 'z'),  // MATHEMATICAL SANS-SERIF ITALIC SMALL Z
+	('\u{1D63C}', 'A'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL A
+	('\u{1D63D}', 'B'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL B
+	('\u{1D63E}', 'C'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL C
+	('\u{1D63F}', 'D'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL D
+	('\u{1D640}', 'E'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL E
+	('\u{1D641}', 'F'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL F
+	('\u{1D642}', 'G'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL G
+	('\u{1D643}', 'H'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL H
+	('\u{1D644}', 'l'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL I
+	('\u{1D645}', 'J'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL J
+	('\u{1D646}', 'K'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL K
+	('\u{1D647}', 'L'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL L
+	('\u{1D648}', 'M'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL M
+	('\u{1D649}', 'N'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL N
+	('\u{1D64A}', 'O'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL O
+	('\u{1D64B}', 'P'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL P
+	('\u{1D64C}', 'Q'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL Q
+	('\u{1D64D}', 'R'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL R
+	('\u{1D64E}', 'S'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL S
+	('\u{1D64F}', 'T'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL T
+	('\u{1D650}', 'U'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL U
+	('\u{1D651}', 'V'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL V
+	('\u{1D652}', 'W'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL W
+	('\u{1D653}', 'X'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL X
+	('\u{1D654}', 'Y'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL Y
+	('\u{1D655}', 'Z'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL Z
+	('\u{1D656}', 'a'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL A
+	('\u{1D657}', 'b'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL B
+	('\u{1D658}', 'c'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL C
+	('\u{1D659}', 'd'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL D
+	('\u{1D65A}', 'e'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL E
+	('\u{1D65B}', 'f'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL F
+	('\u{1D65C}', 'g'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL G
+	('\u{1D65D}', 'h'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL H
+	('\u{1D65E}', 'i'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL I
+	('\u{1D65F}', 'j'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL J
+	('\u{1D660}', 'k'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL K
+	('\u{1D661}', 'l'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL L
+	('\u{1D663}', 'n'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL N
+	('\u{1D664}', 'o'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL O
+	('\u{1D665}', 'p'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL P
+	('\u{1D666}', 'q'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL Q
+	('\u{1D667}', 'r'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL R
+	('\u{1D668}', 's'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL S
+	('\u{1D669}', 't'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL T
+	('\u{1D66A}', 'u'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL U
+	('\u{1D66B}', 'v'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL V
+	('\u{1D66C}', 'w'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL W
+	('\u{1D66D}', 'x'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL X
+	('\u{1D66E}', 'y'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL Y
+	('\u{1D66F}', 'z'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL Z
+	('\u{1D670}', 'A'),  // MATHEMATICAL MONOSPACE CAPITAL A
+	('\u{1D671}', 'B'),  // MATHEMATICAL MONOSPACE CAPITAL B
+	('\u{1D672}', 'C'),  // MATHEMATICAL MONOSPACE CAPITAL C
+	('\u{1D673}', 'D'),  // MATHEMATICAL MONOSPACE CAPITAL D
+	('\u{1D674}', 'E'),  // MATHEMATICAL MONOSPACE CAPITAL E
+	('\u{1D675}', 'F'),  // MATHEMATICAL MONOSPACE CAPITAL F
+	('\u{1D676}', 'G'),  // MATHEMATICAL MONOSPACE CAPITAL G
+	('\u{1D677}', 'H'),  // MATHEMATICAL MONOSPACE CAPITAL H
+	('\u{1D678}', 'l'),  // MATHEMATICAL MONOSPACE CAPITAL I
+	('\u{1D679}', 'J'),  // MATHEMATICAL MONOSPACE CAPITAL J
+	('\u{1D67A}', 'K'),  // MATHEMATICAL MONOSPACE CAPITAL K
+	('\u{1D67B}', 'L'),  // MATHEMATICAL MONOSPACE CAPITAL L
+	('\u{1D67C}', 'M'),  // MATHEMATICAL MONOSPACE CAPITAL M
+	('\u{1D67D}', 'N'),  // MATHEMATICAL MONOSPACE CAPITAL N
+	('\u{1D67E}', 'O'),  // MATHEMATICAL MONOSPACE CAPITAL O
+	('\u{1D67F}', 'P'),  // MATHEMATICAL MONOSPACE CAPITAL P
+	('\u{1D680}', 'Q'),  // MATHEMATICAL MONOSPACE CAPITAL Q
+	('\u{1D681}', 'R'),  // MATHEMATICAL MONOSPACE CAPITAL R
+	('\u{1D682}', 'S'),  // MATHEMATICAL MONOSPACE CAPITAL S
+	('\u{1D683}', 'T'),  // MATHEMATICAL MONOSPACE CAPITAL T
+	('\u{1D684}', 'U'),  // MATHEMATICAL MONOSPACE CAPITAL U
+	('\u{1D685}', 'V'),  // MATHEMATICAL MONOSPACE CAPITAL V
+	('\u{1D686}', 'W'),  // MATHEMATICAL MONOSPACE CAPITAL W
+	('\u{1D687}', 'X'),  // MATHEMATICAL MONOSPACE CAPITAL X
+	('\u{1D688}', 'Y'),  // MATHEMATICAL MONOSPACE CAPITAL Y
+	('\u{1D689}', 'Z'),  // MATHEMATICAL MONOSPACE CAPITAL Z
+	('\u{1D68A}', 'a'),  // MATHEMATICAL MONOSPACE SMALL A
+	('\u{1D68B}', 'b'),  // MATHEMATICAL MONOSPACE SMALL B
+	('\u{1D68C}', 'c'),  // MATHEMATICAL MONOSPACE SMALL C
+	('\u{1D68D}', 'd'),  // MATHEMATICAL MONOSPACE SMALL D
+	('\u{1D68E}', 'e'),  // MATHEMATICAL MONOSPACE SMALL E
+	('\u{1D68F}', 'f'),  // MATHEMATICAL MONOSPACE SMALL F
+	('\u{1D690}', 'g'),  // MATHEMATICAL MONOSPACE SMALL G
+	('\u{1D691}', 'h'),  // MATHEMATICAL MONOSPACE SMALL H
+	('\u{1D692}', 'i'),  // MATHEMATICAL MONOSPACE SMALL I
+	('\u{1D693}', 'j'),  // MATHEMATICAL MONOSPACE SMALL J
+	('\u{1D694}', 'k'),  // MATHEMATICAL MONOSPACE SMALL K
+	('\u{1D695}', 'l'),  // MATHEMATICAL MONOSPACE SMALL L
+	('\u{1D697}', 'n'),  // MATHEMATICAL MONOSPACE SMALL N
+	('\u{1D698}', 'o'),  // MATHEMATICAL MONOSPACE SMALL O
+	('\u{1D699}', 'p'),  // MATHEMATICAL MONOSPACE SMALL P
+	('\u{1D69A}', 'q'),  // MATHEMATICAL MONOSPACE SMALL Q
+	('\u{1D69B}', 'r'),  // MATHEMATICAL MONOSPACE SMALL R
+	('\u{1D69C}', 's'),  // MATHEMATICAL MONOSPACE SMALL S
+	('\u{1D69D}', 't'),  // MATHEMATICAL MONOSPACE SMALL T
+	('\u{1D69E}', 'u'),  // MATHEMATICAL MONOSPACE SMALL U
+	('\u{1D69F}', 'v'),  // MATHEMATICAL MONOSPACE SMALL V
+	('\u{1D6A0}', 'w'),  // MATHEMATICAL MONOSPACE SMALL W
+	('\u{1D6A1}', 'x'),  // MATHEMATICAL MONOSPACE SMALL X
+	('\u{1D6A2}', 'y'),  // MATHEMATICAL MONOSPACE SMALL Y
+	('\u{1D6A3}', 'z'),  // MATHEMATICAL MONOSPACE SMALL Z
+	('\u{1D6A4}', 'i'),  // MATHEMATICAL ITALIC SMALL DOTLESS I
+	('\u{1D6A8}', 'A'),  // MATHEMATICAL BOLD CAPITAL ALPHA
+	('\u{1D6A9}', 'B'),  // MATHEMATICAL BOLD CAPITAL BETA
+	('\u{1D6AC}', 'E'),  // MATHEMATICAL BOLD CAPITAL EPSILON
+	('\u{1D6AD}', 'Z'),  // MATHEMATICAL BOLD CAPITAL ZETA
+	('\u{1D6AE}', 'H'),  // MATHEMATICAL BOLD CAPITAL ETA
+	('\u{1D6B0}', 'l'),  // MATHEMATICAL BOLD CAPITAL IOTA
+	('\u{1D6B1}', 'K'),  // MATHEMATICAL BOLD CAPITAL KAPPA
+	('\u{1D6B3}', 'M'),  // MATHEMATICAL BOLD CAPITAL MU
+	('\u{1D6B4}', 'N'),  // MATHEMATICAL BOLD CAPITAL NU
+	('\u{1D6B6}', 'O'),  // MATHEMATICAL BOLD CAPITAL OMICRON
+	('\u{1D6B8}', 'P'),  // MATHEMATICAL BOLD CAPITAL RHO
+	('\u{1D6BB}', 'T'),  // MATHEMATICAL BOLD CAPITAL TAU
+	('\u{1D6BC}', 'Y'),  // MATHEMATICAL BOLD CAPITAL UPSILON
+	('\u{1D6BE}', 'X'),  // MATHEMATICAL BOLD CAPITAL CHI
+	('\u{1D6C2}', 'a'),  // MATHEMATICAL BOLD SMALL ALPHA
+	('\u{1D6C4}', 'y'),  // MATHEMATICAL BOLD SMALL GAMMA
+	('\u{1D6CA}', 'i'),  // MATHEMATICAL BOLD SMALL IOTA
+	('\u{1D6CE}', 'v'),  // MATHEMATICAL BOLD SMALL NU
+	('\u{1D6D0}', 'o'),  // MATHEMATICAL BOLD SMALL OMICRON
+	('\u{1D6D2}', 'p'),  // MATHEMATICAL BOLD SMALL RHO
+	('\u{1D6D4}', 'o'),  // MATHEMATICAL BOLD SMALL SIGMA
+	('\u{1D6D6}', 'u'),  // MATHEMATICAL BOLD SMALL UPSILON
+	('\u{1D6E0}', 'p'),  // MATHEMATICAL BOLD RHO SYMBOL
+	('\u{1D6E2}', 'A'),  // MATHEMATICAL ITALIC CAPITAL ALPHA
+	('\u{1D6E3}', 'B'),  // MATHEMATICAL ITALIC CAPITAL BETA
+	('\u{1D6E6}', 'E'),  // MATHEMATICAL ITALIC CAPITAL EPSILON
+	('\u{1D6E7}', 'Z'),  // MATHEMATICAL ITALIC CAPITAL ZETA
+	('\u{1D6E8}', 'H'),  // MATHEMATICAL ITALIC CAPITAL ETA
+	('\u{1D6EA}', 'l'),  // MATHEMATICAL ITALIC CAPITAL IOTA
+	('\u{1D6EB}', 'K'),  // MATHEMATICAL ITALIC CAPITAL KAPPA
+	('\u{1D6ED}', 'M'),  // MATHEMATICAL ITALIC CAPITAL MU
+	('\u{1D6EE}', 'N'),  // MATHEMATICAL ITALIC CAPITAL NU
+	('\u{1D6F0}', 'O'),  // MATHEMATICAL ITALIC CAPITAL OMICRON
+	('\u{1D6F2}', 'P'),  // MATHEMATICAL ITALIC CAPITAL RHO
+	('\u{1D6F5}', 'T'),  // MATHEMATICAL ITALIC CAPITAL TAU
+	('\u{1D6F6}', 'Y'),  // MATHEMATICAL ITALIC CAPITAL UPSILON
+	('\u{1D6F8}', 'X'),  // MATHEMATICAL ITALIC CAPITAL CHI
+	('\u{1D6FC}', 'a'),  // MATHEMATICAL ITALIC SMALL ALPHA
+	('\u{1D6FE}', 'y'),  // MATHEMATICAL ITALIC SMALL GAMMA
+	('\u{1D704}', 'i'),  // MATHEMATICAL ITALIC SMALL IOTA
+	('\u{1D708}', 'v'),  // MATHEMATICAL ITALIC SMALL NU
+	('\u{1D70A}', 'o'),  // MATHEMATICAL ITALIC SMALL OMICRON
+	('\u{1D70C}', 'p'),  // MATHEMATICAL ITALIC SMALL RHO
+	('\u{1D70E}', 'o'),  // MATHEMATICAL ITALIC SMALL SIGMA
+	('\u{1D710}', 'u'),  // MATHEMATICAL ITALIC SMALL UPSILON
+	('\u{1D71A}', 'p'),  // MATHEMATICAL ITALIC RHO SYMBOL
+	('\u{1D71C}', 'A'),  // MATHEMATICAL BOLD ITALIC CAPITAL ALPHA
+	('\u{1D71D}', 'B'),  // MATHEMATICAL BOLD ITALIC CAPITAL BETA
+	('\u{1D720}', 'E'),  // MATHEMATICAL BOLD ITALIC CAPITAL EPSILON
+	('\u{1D721}', 'Z'),  // MATHEMATICAL BOLD ITALIC CAPITAL ZETA
+	('\u{1D722}', 'H'),  // MATHEMATICAL BOLD ITALIC CAPITAL ETA
+	('\u{1D724}', 'l'),  // MATHEMATICAL BOLD ITALIC CAPITAL IOTA
+	('\u{1D725}', 'K'),  // MATHEMATICAL BOLD ITALIC CAPITAL KAPPA
+	('\u{1D727}', 'M'),  // MATHEMATICAL BOLD ITALIC CAPITAL MU
+	('\u{1D728}', 'N'),  // MATHEMATICAL BOLD ITALIC CAPITAL NU
+	('\u{1D72A}', 'O'),  // MATHEMATICAL BOLD ITALIC CAPITAL OMICRON
+	('\u{1D72C}', 'P'),  // MATHEMATICAL BOLD ITALIC CAPITAL RHO
+	('\u{1D72F}', 'T'),  // MATHEMATICAL BOLD ITALIC CAPITAL TAU
+	('\u{1D730}', 'Y'),  // MATHEMATICAL BOLD ITALIC CAPITAL UPSILON
+	('\u{1D732}', 'X'),  // MATHEMATICAL BOLD ITALIC CAPITAL CHI
+	('\u{1D736}', 'a'),  // MATHEMATICAL BOLD ITALIC SMALL ALPHA
+	('\u{1D738}', 'y'),  // MATHEMATICAL BOLD ITALIC SMALL GAMMA
+	('\u{1D73E}', 'i'),  // MATHEMATICAL BOLD ITALIC SMALL IOTA
+	('\u{1D742}', 'v'),  // MATHEMATICAL BOLD ITALIC SMALL NU
+	('\u{1D744}', 'o'),  // MATHEMATICAL BOLD ITALIC SMALL OMICRON
+	('\u{1D746}', 'p'),  // MATHEMATICAL BOLD ITALIC SMALL RHO
+	('\u{1D748}', 'o'),  // MATHEMATICAL BOLD ITALIC SMALL SIGMA
+	('\u{1D74A}', 'u'),  // MATHEMATICAL BOLD ITALIC SMALL UPSILON
+	('\u{1D754}', 'p'),  // MATHEMATICAL BOLD ITALIC RHO SYMBOL
+	('\u{1D756}', 'A'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL ALPHA
+	('\u{1D757}', 'B'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL BETA
+	('\u{1D75A}', 'E'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL EPSILON
+	('\u{1D75B}', 'Z'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL ZETA
+	('\u{1D75C}', 'H'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL ETA
+	('\u{1D75E}', 'l'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL IOTA
+	('\u{1D75F}', 'K'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL KAPPA
+	('\u{1D761}', 'M'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL MU
+	('\u{1D762}', 'N'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL NU
+	('\u{1D764}', 'O'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL OMICRON
+	('\u{1D766}', 'P'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL RHO
+	('\u{1D769}', 'T'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL TAU
+	('\u{1D76A}', 'Y'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL UPSILON
+	('\u{1D76C}', 'X'),  // MATHEMATICAL SANS-SERIF BOLD CAPITAL CHI
+	('\u{1D770}', 'a'),  // MATHEMATICAL SANS-SERIF BOLD SMALL ALPHA
+	('\u{1D772}', 'y'),  // MATHEMATICAL SANS-SERIF BOLD SMALL GAMMA
+	('\u{1D778}', 'i'),  // MATHEMATICAL SANS-SERIF BOLD SMALL IOTA
+	('\u{1D77C}', 'v'),  // MATHEMATICAL SANS-SERIF BOLD SMALL NU
+	('\u{1D77E}', 'o'),  // MATHEMATICAL SANS-SERIF BOLD SMALL OMICRON
+	('\u{1D780}', 'p'),  // MATHEMATICAL SANS-SERIF BOLD SMALL RHO
+	('\u{1D782}', 'o'),  // MATHEMATICAL SANS-SERIF BOLD SMALL SIGMA
+	('\u{1D784}', 'u'),  // MATHEMATICAL SANS-SERIF BOLD SMALL UPSILON
+	('\u{1D78E}', 'p'),  // MATHEMATICAL SANS-SERIF BOLD RHO SYMBOL
+	('\u{1D790}', 'A'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL ALPHA
+	('\u{1D791}', 'B'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL BETA
+	('\u{1D794}', 'E'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL EPSILON
+	('\u{1D795}', 'Z'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL ZETA
+	('\u{1D796}', 'H'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL ETA
+	('\u{1D798}', 'l'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL IOTA
+	('\u{1D799}', 'K'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL KAPPA
+	('\u{1D79B}', 'M'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL MU
+	('\u{1D79C}', 'N'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL NU
+	('\u{1D79E}', 'O'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL OMICRON
+	('\u{1D7A0}', 'P'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL RHO
+	('\u{1D7A3}', 'T'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL TAU
+	('\u{1D7A4}', 'Y'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL UPSILON
+	('\u{1D7A6}', 'X'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL CHI
+	('\u{1D7AA}', 'a'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL ALPHA
+	('\u{1D7AC}', 'y'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL GAMMA
+	('\u{1D7B2}', 'i'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL IOTA
+	('\u{1D7B6}', 'v'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL NU
+	('\u{1D7B8}', 'o'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL OMICRON
+	('\u{1D7BA}', 'p'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL RHO
+	('\u{1D7BC}', 'o'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL SIGMA
+	('\u{1D7BE}', 'u'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL UPSILON
+	('\u{1D7C8}', 'p'),  // MATHEMATICAL SANS-SERIF BOLD ITALIC RHO SYMBOL
+	('\u{1D7CA}', 'F'),  // MATHEMATICAL BOLD CAPITAL DIGAMMA
+	('\u{1D7CE}', 'O'),  // MATHEMATICAL BOLD DIGIT ZERO
+	('\u{1D7CF}', 'l'),  // MATHEMATICAL BOLD DIGIT ONE
+	('\u{1D7D0}', '2'),  // MATHEMATICAL BOLD DIGIT TWO
+	('\u{1D7D1}', '3'),  // MATHEMATICAL BOLD DIGIT THREE
+	('\u{1D7D2}', '4'),  // MATHEMATICAL BOLD DIGIT FOUR
+	('\u{1D7D3}', '5'),  // MATHEMATICAL BOLD DIGIT FIVE
+	('\u{1D7D4}', '6'),  // MATHEMATICAL BOLD DIGIT SIX
+	('\u{1D7D5}', '7'),  // MATHEMATICAL BOLD DIGIT SEVEN
+	('\u{1D7D6}', '8'),  // MATHEMATICAL BOLD DIGIT EIGHT
+	('\u{1D7D7}', '9'),  // MATHEMATICAL BOLD DIGIT NINE
+	('\u{1D7D8}', 'O'),  // MATHEMATICAL DOUBLE-STRUCK DIGIT ZERO
+	('\u{1D7D9}', 'l'),  // MATHEMATICAL DOUBLE-STRUCK DIGIT ONE
+	('\u{1D7DA}', '2'),  // MATHEMATICAL DOUBLE-STRUCK DIGIT TWO
+	('\u{1D7DB}', '3'),  // MATHEMATICAL DOUBLE-STRUCK DIGIT THREE
+	('\u{1D7DC}', '4'),  // MATHEMATICAL DOUBLE-STRUCK DIGIT FOUR
+	('\u{1D7DD}', '5'),  // MATHEMATICAL DOUBLE-STRUCK DIGIT FIVE
+	('\u{1D7DE}', '6'),  // MATHEMATICAL DOUBLE-STRUCK DIGIT SIX
+	('\u{1D7DF}', '7'),  // MATHEMATICAL DOUBLE-STRUCK DIGIT SEVEN
+	('\u{1D7E0}', '8'),  // MATHEMATICAL DOUBLE-STRUCK DIGIT EIGHT
+	('\u{1D7E1}', '9'),  // MATHEMATICAL DOUBLE-STRUCK DIGIT NINE
+	('\u{1D7E2}', 'O'),  // MATHEMATICAL SANS-SERIF DIGIT ZERO
+	('\u{1D7E3}', 'l'),  // MATHEMATICAL SANS-SERIF DIGIT ONE
+	('\u{1D7E4}', '2'),  // MATHEMATICAL SANS-SERIF DIGIT TWO
+	('\u{1D7E5}', '3'),  // MATHEMATICAL SANS-SERIF DIGIT THREE
+	('\u{1D7E6}', '4'),  // MATHEMATICAL SANS-SERIF DIGIT FOUR
+	('\u{1D7E7}', '5'),  // MATHEMATICAL SANS-SERIF DIGIT FIVE
+	('\u{1D7E8}', '6'),  // MATHEMATICAL SANS-SERIF DIGIT SIX
+	('\u{1D7E9}', '7'),  // MATHEMATICAL SANS-SERIF DIGIT SEVEN
+	('\u{1D7EA}', '8'),  // MATHEMATICAL SANS-SERIF DIGIT EIGHT
+	('\u{1D7EB}', '9'),  // MATHEMATICAL SANS-SERIF DIGIT NINE
+	('\u{1D7EC}', 'O'),  // MATHEMATICAL SANS-SERIF BOLD DIGIT ZERO
+	('\u{1D7ED}', 'l'),  // MATHEMATICAL SANS-SERIF BOLD DIGIT ONE
+	('\u{1D7EE}', '2'),  // MATHEMATICAL SANS-SERIF BOLD DIGIT TWO
+	('\u{1D7EF}', '3'),  // MATHEMATICAL SANS-SERIF BOLD DIGIT THREE
+	('\u{1D7F0}', '4'),  // MATHEMATICAL SANS-SERIF BOLD DIGIT FOUR
+	('\u{1D7F1}', '5'),  // MATHEMATICAL SANS-SERIF BOLD DIGIT FIVE
+	('\u{1D7F2}', '6'),  // MATHEMATICAL SANS-SERIF BOLD DIGIT SIX
+	('\u{1D7F3}', '7'),  // MATHEMATICAL SANS-SERIF BOLD DIGIT SEVEN
+	('\u{1D7F4}', '8'),  // MATHEMATICAL SANS-SERIF BOLD DIGIT EIGHT
+	('\u{1D7F5}', '9'),  // MATHEMATICAL SANS-SERIF BOLD DIGIT NINE
+	('\u{1D7F6}', 'O'),  // MATHEMATICAL MONOSPACE DIGIT ZERO
+	('\u{1D7F7}', 'l'),  // MATHEMATICAL MONOSPACE DIGIT ONE
+	('\u{1D7F8}', '2'),  // MATHEMATICAL MONOSPACE DIGIT TWO
+	('\u{1D7F9}', '3'),  // MATHEMATICAL MONOSPACE DIGIT THREE
+	('\u{1D7FA}', '4'),  // MATHEMATICAL MONOSPACE DIGIT FOUR
+	('\u{1D7FB}', '5'),  // MATHEMATICAL MONOSPACE DIGIT FIVE
+	('\u{1D7FC}', '6'),  // MATHEMATICAL MONOSPACE DIGIT SIX
+	('\u{1D7FD}', '7'),  // MATHEMATICAL MONOSPACE DIGIT SEVEN
+	('\u{1D7FE}', '8'),  // MATHEMATICAL MONOSPACE DIGIT EIGHT
+	('\u{1D7FF}', '9'),  // MATHEMATICAL MONOSPACE DIGIT NINE
+	('\u{1E8C7}', 'l'),  // MENDE KIKAKUI DIGIT ONE
+	('\u{1E8CB}', '8'),  // MENDE KIKAKUI DIGIT FIVE
+	('\u{1EE00}', 'l'),  // ARABIC MATHEMATICAL ALEF
+	('\u{1EE24}', 'o'),  // ARABIC MATHEMATICAL INITIAL HEH
+	('\u{1EE64}', 'o'),  // ARABIC MATHEMATICAL STRETCHED HEH
+	('\u{1EE80}', 'l'),  // ARABIC MATHEMATICAL LOOPED ALEF
+	('\u{1EE84}', 'o'),  // ARABIC MATHEMATICAL LOOPED HEH
+	('\u{1F74C}', 'C'),  // ALCHEMICAL SYMBOL FOR CALX
+	('\u{1F768}', 'T'),  // ALCHEMICAL SYMBOL FOR CRUCIBLE-4
+	('\u{1FBF0}', 'O'),  // SEGMENTED DIGIT ZERO
+	('\u{1FBF1}', 'l'),  // SEGMENTED DIGIT ONE
+	('\u{1FBF2}', '2'),  // SEGMENTED DIGIT TWO
+	('\u{1FBF3}', '3'),  // SEGMENTED DIGIT THREE
+	('\u{1FBF4}', '4'),  // SEGMENTED DIGIT FOUR
+	('\u{1FBF5}', '5'),  // SEGMENTED DIGIT FIVE
+	('\u{1FBF6}', '6'),  // SEGMENTED DIGIT SIX
+	('\u{1FBF7}', '7'),  // SEGMENTED DIGIT SEVEN
+	('\u{1FBF8}', '8'),  // SEGMENTED DIGIT EIGHT
+	('\u{1FBF9}', '9'),  // SEGMENTED DIGIT NINE
+];
+
+/// Returns the ASCII character the given non-ASCII character is confusable
+/// with, if any.
+pub(super) fn ascii_prototype(c: char) -> Option<char> {
+	match c {
+		// Fullwidth ASCII variants.
+		'\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0),
+		_ => CONFUSABLES
+			.binary_search_by_key(&c, |(c, _)| *c)
+			.ok()
+			.map(|i| CONFUSABLES[i].1),
+	}
+}
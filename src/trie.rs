@@ -0,0 +1,452 @@
+//! Prefix trie keyed by IRIs.
+use core::cmp::Ordering;
+
+use crate::{
+	Iri,
+	iri::{Authority, AuthorityBuf, Scheme, SchemeBuf, Segment, SegmentBuf},
+};
+
+/// Prefix trie mapping IRIs to values, with longest prefix matching.
+///
+/// IRIs are decomposed into a sequence of components: the scheme, then the
+/// authority, then the path segments. Scheme comparison is
+/// case-insensitive, and authority and segment comparison is
+/// percent-encoding-aware, following the [`PartialEq`] implementation of
+/// [`Iri`]. Path segments are normalized (dot segments are removed).
+///
+/// The query and fragment are ignored. Otherwise, entries follow the
+/// equivalence of [`Iri`]: `foo:/a` and `foo:a` are distinct entries, and so
+/// are `http://example.org/foo` and `http://example.org/foo/`.
+///
+/// An IRI is a prefix of another if its path segments are a prefix of the
+/// other's path segments. An IRI with an empty path, or a path ending with
+/// `/`, is also a prefix of the IRIs extending its path:
+/// `http://example.org/foo/` is a prefix of `http://example.org/foo/bar`, but
+/// not of `http://example.org/foo`.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Iri, trie::IriTrie};
+///
+/// let mut trie = IriTrie::new();
+/// trie.insert(Iri::new("https://example.org/").unwrap(), "site");
+/// trie.insert(Iri::new("https://example.org/api/").unwrap(), "api");
+///
+/// let iri = Iri::new("HTTPS://example.org/api/v1/users?page=2").unwrap();
+/// assert_eq!(trie.longest_prefix(iri), Some(&"api"));
+///
+/// let iri = Iri::new("https://example.org/%61pi/").unwrap();
+/// assert_eq!(trie.get(iri), Some(&"api"));
+///
+/// let iri = Iri::new("https://example.com/").unwrap();
+/// assert_eq!(trie.longest_prefix(iri), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IriTrie<V> {
+	root: Node<V>,
+	len: usize,
+}
+
+impl<V> Default for IriTrie<V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<V> IriTrie<V> {
+	/// Creates a new empty trie.
+	pub fn new() -> Self {
+		Self {
+			root: Node::default(),
+			len: 0,
+		}
+	}
+
+	/// Returns the number of entries in the trie.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Checks if the trie is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Removes all the entries of the trie.
+	pub fn clear(&mut self) {
+		*self = Self::new()
+	}
+
+	/// Inserts a value for the given IRI, returning the previous value, if
+	/// any.
+	pub fn insert(&mut self, iri: &Iri, value: V) -> Option<V> {
+		let mut node = &mut self.root;
+		for key in keys(iri) {
+			node = node.child_or_insert(key);
+		}
+
+		let old = node.value.replace(value);
+		if old.is_none() {
+			self.len += 1
+		}
+
+		old
+	}
+
+	/// Returns the value associated to the given IRI, if any.
+	pub fn get(&self, iri: &Iri) -> Option<&V> {
+		let mut node = &self.root;
+		for key in keys(iri) {
+			node = node.child(key)?;
+		}
+
+		node.value.as_ref()
+	}
+
+	/// Returns a mutable reference to the value associated to the given
+	/// IRI, if any.
+	pub fn get_mut(&mut self, iri: &Iri) -> Option<&mut V> {
+		let mut node = &mut self.root;
+		for key in keys(iri) {
+			node = node.child_mut(key)?;
+		}
+
+		node.value.as_mut()
+	}
+
+	/// Checks if the trie has a value for the given IRI.
+	pub fn contains(&self, iri: &Iri) -> bool {
+		self.get(iri).is_some()
+	}
+
+	/// Removes the value associated to the given IRI, returning it.
+	pub fn remove(&mut self, iri: &Iri) -> Option<V> {
+		let keys: Vec<_> = keys(iri).collect();
+		let value = self.root.remove(&keys);
+		if value.is_some() {
+			self.len -= 1
+		}
+
+		value
+	}
+
+	/// Returns the value associated to the longest prefix of the given IRI,
+	/// if any.
+	pub fn longest_prefix(&self, iri: &Iri) -> Option<&V> {
+		self.prefixes(iri).last()
+	}
+
+	/// Returns an iterator over the values associated to the prefixes of
+	/// the given IRI, from the shortest prefix to the longest.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::{Iri, trie::IriTrie};
+	///
+	/// let mut trie = IriTrie::new();
+	/// trie.insert(Iri::new("https://example.org/").unwrap(), 1);
+	/// trie.insert(Iri::new("https://example.org/a/b").unwrap(), 2);
+	/// trie.insert(Iri::new("https://example.org/a/c").unwrap(), 3);
+	///
+	/// let iri = Iri::new("https://example.org/a/./b/c").unwrap();
+	/// assert_eq!(trie.prefixes(iri).collect::<Vec<_>>(), [&1, &2]);
+	/// ```
+	pub fn prefixes<'t>(&'t self, iri: &Iri) -> impl Iterator<Item = &'t V> {
+		let mut keys = keys(iri);
+		let mut node = Some(&self.root);
+		let mut pending = None;
+		core::iter::from_fn(move || {
+			loop {
+				if let Some(value) = pending.take() {
+					break Some(value);
+				}
+
+				let current = node?;
+				let key = keys.next();
+				pending = key.and_then(|key| current.extended_prefix(key));
+				node = key.and_then(|key| current.child(key));
+				if let Some(value) = &current.value {
+					break Some(value);
+				}
+			}
+		})
+	}
+}
+
+impl<'a, V> FromIterator<(&'a Iri, V)> for IriTrie<V> {
+	fn from_iter<T: IntoIterator<Item = (&'a Iri, V)>>(iter: T) -> Self {
+		let mut result = Self::new();
+		result.extend(iter);
+		result
+	}
+}
+
+impl<'a, V> Extend<(&'a Iri, V)> for IriTrie<V> {
+	fn extend<T: IntoIterator<Item = (&'a Iri, V)>>(&mut self, iter: T) {
+		for (iri, value) in iter {
+			self.insert(iri, value);
+		}
+	}
+}
+
+/// Returns the trie keys of the given IRI.
+fn keys(iri: &Iri) -> impl '_ + Iterator<Item = KeyRef<'_>> {
+	let parts = iri.parts();
+
+	[
+		KeyRef::Scheme(parts.scheme),
+		KeyRef::Authority(parts.authority),
+		KeyRef::Absolute(parts.path.is_absolute()),
+	]
+	.into_iter()
+	.chain(parts.path.normalized_segments().map(KeyRef::Segment))
+}
+
+/// Trie key.
+#[derive(Debug, Clone)]
+enum Key {
+	Scheme(SchemeBuf),
+	Authority(Option<AuthorityBuf>),
+	Absolute(bool),
+	Segment(SegmentBuf),
+}
+
+impl Key {
+	fn as_key_ref(&self) -> KeyRef<'_> {
+		match self {
+			Self::Scheme(s) => KeyRef::Scheme(s),
+			Self::Authority(a) => KeyRef::Authority(a.as_deref()),
+			Self::Absolute(a) => KeyRef::Absolute(*a),
+			Self::Segment(s) => KeyRef::Segment(s),
+		}
+	}
+}
+
+/// Borrowed trie key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum KeyRef<'a> {
+	Scheme(&'a Scheme),
+	Authority(Option<&'a Authority>),
+	Absolute(bool),
+	Segment(&'a Segment),
+}
+
+impl KeyRef<'_> {
+	fn to_owned(self) -> Key {
+		match self {
+			Self::Scheme(s) => Key::Scheme(s.to_owned()),
+			Self::Authority(a) => Key::Authority(a.map(ToOwned::to_owned)),
+			Self::Absolute(a) => Key::Absolute(a),
+			Self::Segment(s) => Key::Segment(s.to_owned()),
+		}
+	}
+}
+
+/// Trie node.
+///
+/// Children are sorted by key, using the equivalence-aware ordering of
+/// the key components.
+#[derive(Debug, Clone)]
+struct Node<V> {
+	value: Option<V>,
+	children: Vec<(Key, Node<V>)>,
+}
+
+impl<V> Default for Node<V> {
+	fn default() -> Self {
+		Self {
+			value: None,
+			children: Vec::new(),
+		}
+	}
+}
+
+impl<V> Node<V> {
+	fn find(&self, key: KeyRef) -> Result<usize, usize> {
+		self.children
+			.binary_search_by(|(k, _)| k.as_key_ref().cmp(&key))
+	}
+
+	fn child(&self, key: KeyRef) -> Option<&Self> {
+		let i = self.find(key).ok()?;
+		Some(&self.children[i].1)
+	}
+
+	/// Returns the value of the entry extended by the given key, if any.
+	///
+	/// This is the entry with an empty path when the key starts an
+	/// absolute path, or the entry ending with `/` when the key is a
+	/// non-empty segment.
+	fn extended_prefix(&self, key: KeyRef) -> Option<&V> {
+		let key = match key {
+			KeyRef::Absolute(true) => KeyRef::Absolute(false),
+			KeyRef::Segment(s) if !s.is_empty() => KeyRef::Segment(Segment::EMPTY),
+			_ => return None,
+		};
+
+		self.child(key)?.value.as_ref()
+	}
+
+	fn child_mut(&mut self, key: KeyRef) -> Option<&mut Self> {
+		let i = self.find(key).ok()?;
+		Some(&mut self.children[i].1)
+	}
+
+	fn child_or_insert(&mut self, key: KeyRef) -> &mut Self {
+		let i = match self.find(key) {
+			Ok(i) => i,
+			Err(i) => {
+				self.children.insert(i, (key.to_owned(), Self::default()));
+				i
+			}
+		};
+
+		&mut self.children[i].1
+	}
+
+	fn is_empty(&self) -> bool {
+		self.value.is_none() && self.children.is_empty()
+	}
+
+	/// Removes the value at the given key path, pruning empty nodes.
+	fn remove(&mut self, keys: &[KeyRef]) -> Option<V> {
+		match keys.split_first() {
+			None => self.value.take(),
+			Some((key, rest)) => {
+				let i = self.find(*key).ok()?;
+				let value = self.children[i].1.remove(rest);
+				if self.children[i].1.is_empty() {
+					self.children.remove(i);
+				}
+
+				value
+			}
+		}
+	}
+}
+
+impl PartialEq for Key {
+	fn eq(&self, other: &Self) -> bool {
+		self.as_key_ref() == other.as_key_ref()
+	}
+}
+
+impl Eq for Key {}
+
+impl PartialOrd for Key {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Key {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_key_ref().cmp(&other.as_key_ref())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn iri(s: &str) -> &Iri {
+		Iri::new(s).unwrap()
+	}
+
+	#[test]
+	fn insert_get_remove() {
+		let mut trie = IriTrie::new();
+		assert_eq!(trie.insert(iri("http://example.org/a/b"), 1), None);
+		assert_eq!(trie.insert(iri("http://example.org/a"), 2), None);
+		assert_eq!(trie.insert(iri("HTTP://example.org/a/"), 3), None);
+		assert_eq!(trie.insert(iri("http://example.org/a/."), 4), Some(3));
+		assert_eq!(trie.len(), 3);
+
+		assert_eq!(trie.get(iri("http://example.org/a/b")), Some(&1));
+		assert_eq!(trie.get(iri("http://example.org/a/%62")), Some(&1));
+		assert_eq!(trie.get(iri("http://example.org/a/c/../b#f")), Some(&1));
+		assert_eq!(trie.get(iri("http://example.org/a")), Some(&2));
+		assert_eq!(trie.get(iri("http://example.org/a/")), Some(&4));
+		assert_eq!(trie.get(iri("http://example.org/")), None);
+		assert_eq!(trie.get(iri("http://example.org/a/b/c")), None);
+
+		*trie.get_mut(iri("http://example.org/a/b")).unwrap() += 10;
+		assert_eq!(trie.remove(iri("http://example.org/a/b")), Some(11));
+		assert_eq!(trie.remove(iri("http://example.org/a/b")), None);
+		assert_eq!(trie.len(), 2);
+		assert_eq!(
+			trie.root.children[0].1.children[0].1.children[0].1.children[0]
+				.1
+				.children
+				.len(),
+			1
+		);
+
+		assert_eq!(trie.remove(iri("http://example.org/a")), Some(2));
+		assert_eq!(trie.remove(iri("http://example.org/a/")), Some(4));
+		assert!(trie.is_empty());
+		assert!(trie.root.is_empty())
+	}
+
+	#[test]
+	fn longest_prefix() {
+		let trie: IriTrie<_> = [
+			(iri("http://example.org"), 0),
+			(iri("http://example.org/ns/"), 1),
+			(iri("http://example.org/ns/sub/"), 2),
+			(iri("http://user@example.org/"), 3),
+			(iri("urn:isbn:0451450523"), 4),
+		]
+		.into_iter()
+		.collect();
+
+		let vectors = [
+			("http://example.org/other", Some(&0)),
+			("http://example.org", Some(&0)),
+			("http://example.org/", Some(&0)),
+			("http://example.org/ns", Some(&0)),
+			("http://example.org/ns/", Some(&1)),
+			("http://example.org/ns/a?q", Some(&1)),
+			("http://example.org/ns/sub/a/b", Some(&2)),
+			("http://example.org/ns/subway", Some(&1)),
+			("http://example.org/ns/sub", Some(&1)),
+			("http://user@example.org/ns/", Some(&3)),
+			("http://example.org:8080/ns/", None),
+			("https://example.org/ns/", None),
+			("urn:isbn:0451450523", Some(&4)),
+			("urn:isbn:0451450524", None),
+		];
+
+		for (input, expected) in vectors {
+			assert_eq!(trie.longest_prefix(iri(input)), expected, "{input}")
+		}
+	}
+
+	#[test]
+	fn path_kinds() {
+		let mut trie = IriTrie::new();
+		trie.insert(iri("foo:/a"), 1);
+		trie.insert(iri("http://x/a"), 2);
+		assert_eq!(trie.get(iri("foo:/a")), Some(&1));
+		assert_eq!(trie.get(iri("foo:a")), None);
+		assert_eq!(trie.get(iri("http://x/a")), Some(&2));
+		assert_eq!(trie.get(iri("http://x/a/")), None);
+		assert_eq!(trie.longest_prefix(iri("foo:a/b")), None);
+		assert_eq!(trie.longest_prefix(iri("http://x/a/")), Some(&2));
+
+		trie.insert(iri("foo:a"), 3);
+		trie.insert(iri("http://x/a/"), 4);
+		assert_eq!(trie.get(iri("foo:/a")), Some(&1));
+		assert_eq!(trie.get(iri("foo:a")), Some(&3));
+		assert_eq!(trie.get(iri("http://x/a")), Some(&2));
+		assert_eq!(trie.get(iri("http://x/a/")), Some(&4));
+		assert_eq!(trie.longest_prefix(iri("foo:a/b")), Some(&3));
+		assert_eq!(
+			trie.prefixes(iri("http://x/a/b")).collect::<Vec<_>>(),
+			[&2, &4]
+		);
+		assert_eq!(trie.len(), 4)
+	}
+}
@@ -194,6 +194,67 @@ impl Host {
 
 		Some(result)
 	}
+
+	/// Parses this host as an IPv4 address, the way the [WHATWG URL
+	/// Standard] does, and returns it as a `u32`.
+	///
+	/// Unlike [`Self::to_ipv4`], this accepts the forms that web browsers
+	/// interpret as IPv4 addresses although they are registered names in
+	/// RFC 3986: addresses with less than four parts (`127.1`), a trailing
+	/// dot, parts in octal (`0177`) or hexadecimal (`0x7f`), and
+	/// percent-encoded characters. Like in the WHATWG parser, non-ASCII
+	/// characters are first mapped as specified by UTS #46, so fullwidth
+	/// digits (`１２７`) and ideographic full stops (`。`) are accepted.
+	///
+	/// Returns `None` if the host is not an IPv4 address in any of those
+	/// forms.
+	///
+	/// [WHATWG URL Standard]: <https://url.spec.whatwg.org/#concept-ipv4-parser>
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::uri::Host;
+	///
+	/// assert_eq!(Host::new("127.0.0.1").unwrap().to_lenient_ipv4(), Some(0x7f000001));
+	/// assert_eq!(Host::new("2130706433").unwrap().to_lenient_ipv4(), Some(0x7f000001));
+	/// assert_eq!(Host::new("0x7f.1").unwrap().to_lenient_ipv4(), Some(0x7f000001));
+	/// assert_eq!(Host::new("example.org").unwrap().to_lenient_ipv4(), None);
+	/// ```
+	pub fn to_lenient_ipv4(&self) -> Option<u32> {
+		if self.is_ip_literal() {
+			return None;
+		}
+
+		crate::common::parse_lenient_ipv4(crate::common::fold_host(self.as_pct_str().bytes()))
+	}
+
+	/// Returns the scope of the IP address designated by this host.
+	///
+	/// IPv4 addresses are recognized in all the forms accepted by
+	/// [`Self::to_lenient_ipv4`], and IPv6 addresses embedding an IPv4
+	/// address (such as `[::ffff:127.0.0.1]`) have the scope of the
+	/// embedded address. Returns `None` if the host is not an IP address.
+	///
+	/// Registered names are not resolved: `localhost` has no IP scope. See
+	/// [`TargetPolicy`](crate::TargetPolicy) to check whole URIs.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::{IpScope, uri::Host};
+	///
+	/// assert_eq!(Host::new("127.1").unwrap().ip_scope(), Some(IpScope::Loopback));
+	/// assert_eq!(Host::new("[::ffff:10.0.0.1]").unwrap().ip_scope(), Some(IpScope::Private));
+	/// assert_eq!(Host::new("8.8.8.8").unwrap().ip_scope(), Some(IpScope::Global));
+	/// assert_eq!(Host::new("example.org").unwrap().ip_scope(), None);
+	/// ```
+	pub fn ip_scope(&self) -> Option<crate::IpScope> {
+		match self.to_ipv6() {
+			Some(addr) => Some(crate::IpScope::of_ipv6(addr)),
+			None => self.to_lenient_ipv4().map(crate::IpScope::of_ipv4),
+		}
+	}
 }
 
 /// Parses a `dec-octet` (RFC 3986) starting at position `i` in `bytes`.
@@ -28,7 +28,7 @@ pub use userinfo::*;
 /// assert_eq!(authority.host(), "example.org");
 /// assert_eq!(authority.port().unwrap(), "8080");
 /// ```
-#[derive(static_automata::Validate, str_newtype::StrNewType)]
+#[derive(static_automata::Validate, iref_newtype_derive::StrNewType)]
 #[automaton(crate::uri::grammar::Authority)]
 #[newtype(ord([u8], &[u8], str, &str))]
 #[cfg_attr(
@@ -36,6 +36,7 @@ pub use userinfo::*;
 	newtype(ord(Vec<u8>, String), owned(AuthorityBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))
 )]
 #[cfg_attr(feature = "serde", newtype(serde))]
+#[cfg_attr(feature = "redact-debug", newtype(no_debug))]
 pub struct Authority(str);
 
 impl Authority {
@@ -269,6 +270,51 @@ impl AuthorityBuf {
 		Ok(self)
 	}
 
+	/// Sets the user name of the user info component, percent-encoding it.
+	///
+	/// The password, if any, is kept. The user info is created if
+	/// necessary.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::uri::AuthorityBuf;
+	///
+	/// let mut authority = AuthorityBuf::new("old:secret@example.org".to_string()).unwrap();
+	/// authority.set_user("a:b c");
+	///
+	/// assert_eq!(authority, "a%3Ab%20c:secret@example.org");
+	/// ```
+	#[inline]
+	pub fn set_user(&mut self, user: &str) -> &mut Self {
+		self.as_authority_mut().set_user(user);
+		self
+	}
+
+	/// Sets the password of the user info component, percent-encoding it.
+	///
+	/// The user name, if any, is kept. The user info is created (with an
+	/// empty user name) if necessary. Setting `None` removes the password
+	/// and its `:` separator.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::uri::AuthorityBuf;
+	///
+	/// let mut authority = AuthorityBuf::new("alice@example.org".to_string()).unwrap();
+	/// authority.set_password(Some("p@ss:word"));
+	/// assert_eq!(authority, "alice:p%40ss%3Aword@example.org");
+	///
+	/// authority.set_password(None);
+	/// assert_eq!(authority, "alice@example.org");
+	/// ```
+	#[inline]
+	pub fn set_password(&mut self, password: Option<&str>) -> &mut Self {
+		self.as_authority_mut().set_password(password);
+		self
+	}
+
 	/// Sets the host component.
 	///
 	/// # Example
@@ -4,7 +4,7 @@ use core::{
 };
 
 /// URI authority user info.
-#[derive(static_automata::Validate, str_newtype::StrNewType)]
+#[derive(static_automata::Validate, iref_newtype_derive::StrNewType)]
 #[automaton(super::super::grammar::UserInfo)]
 #[newtype(
 	no_deref,
@@ -15,6 +15,7 @@ use core::{
 	newtype(ord(Vec<u8>, String, pct_str::PctString), owned(UserInfoBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))
 )]
 #[cfg_attr(feature = "serde", newtype(serde))]
+#[cfg_attr(feature = "redact-debug", newtype(no_debug))]
 pub struct UserInfo(str);
 
 impl UserInfo {
@@ -23,6 +24,46 @@ impl UserInfo {
 	pub fn as_pct_str(&self) -> &pct_str::PctStr {
 		unsafe { pct_str::PctStr::new_unchecked(self.as_str()) }
 	}
+
+	/// Returns the user name, before the first `:`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::uri::UserInfo;
+	///
+	/// let user_info = UserInfo::new("alice:p%40ss:word").unwrap();
+	/// assert_eq!(user_info.user(), "alice");
+	/// assert_eq!(UserInfo::new("alice").unwrap().user(), "alice");
+	/// ```
+	#[inline]
+	pub fn user(&self) -> &pct_str::PctStr {
+		let user = match self.as_str().split_once(':') {
+			Some((user, _)) => user,
+			None => self.as_str(),
+		};
+
+		unsafe { pct_str::PctStr::new_unchecked(user) }
+	}
+
+	/// Returns the password, after the first `:`, if any.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::uri::UserInfo;
+	///
+	/// let user_info = UserInfo::new("alice:p%40ss:word").unwrap();
+	/// assert_eq!(user_info.password().unwrap().as_str(), "p%40ss:word");
+	/// assert_eq!(user_info.password().unwrap().decode(), "p@ss:word");
+	/// assert_eq!(UserInfo::new("alice").unwrap().password(), None);
+	/// ```
+	#[inline]
+	pub fn password(&self) -> Option<&pct_str::PctStr> {
+		self.as_str()
+			.split_once(':')
+			.map(|(_, password)| unsafe { pct_str::PctStr::new_unchecked(password) })
+	}
 }
 
 impl Deref for UserInfo {
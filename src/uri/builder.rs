@@ -0,0 +1,505 @@
+use core::marker::PhantomData;
+
+use smallvec::SmallVec;
+
+use crate::builder as state;
+
+use super::format::{encode_into, encoded_len};
+
+use super::{
+	Authority, FormatComponent, Fragment, Host, Path, Port, Query, Scheme, Segment, UriBuf,
+	UriRefBuf, UserInfo,
+};
+
+/// Components given to a builder.
+#[derive(Debug, Clone, Default)]
+struct Components<'a> {
+	scheme: Option<&'a Scheme>,
+	authority: Option<&'a Authority>,
+	user_info: Option<&'a UserInfo>,
+	host: Option<&'a Host>,
+	port: Option<&'a Port>,
+	path: Option<&'a Path>,
+	segments: SmallVec<[&'a Segment; 8]>,
+	query: Option<&'a Query>,
+	query_pairs: SmallVec<[(&'a str, &'a str); 4]>,
+	fragment: Option<&'a Fragment>,
+}
+
+impl Components<'_> {
+	fn has_authority(&self) -> bool {
+		self.authority.is_some() || self.host.is_some()
+	}
+
+	/// Returns the prefix to insert before the path to keep the result
+	/// valid and unambiguous.
+	fn path_prefix(&self) -> &'static str {
+		match self.path {
+			Some(path) => {
+				if self.has_authority() {
+					if !path.is_empty() && path.is_relative() {
+						// VALIDITY: path must be absolute when an authority
+						//           is present.
+						"/"
+					} else {
+						""
+					}
+				} else if path.as_bytes().starts_with(b"//") {
+					// AMBIGUITY: `//path` would look like an authority.
+					"/."
+				} else if self.scheme.is_none() && path.looks_like_scheme() {
+					// AMBIGUITY: `path:` would look like a scheme.
+					"./"
+				} else {
+					""
+				}
+			}
+			None => {
+				if !self.has_authority() && self.segments.len() > 1 && self.segments[0].is_empty() {
+					// AMBIGUITY: `//path` would look like an authority.
+					"/."
+				} else {
+					""
+				}
+			}
+		}
+	}
+
+	/// Computes the exact length of the output.
+	fn len(&self) -> usize {
+		let mut len = self.scheme.map(|s| s.len() + 1).unwrap_or(0);
+
+		if let Some(authority) = self.authority {
+			len += 2 + authority.len()
+		} else if let Some(host) = self.host {
+			len += 2 + host.len();
+			len += self.user_info.map(|u| u.len() + 1).unwrap_or(0);
+			len += self.port.map(|p| p.len() + 1).unwrap_or(0);
+		}
+
+		len += self.path_prefix().len();
+		match self.path {
+			Some(path) => len += path.len(),
+			None => len += self.segments.iter().map(|s| s.len() + 1).sum::<usize>(),
+		}
+
+		if let Some(query) = self.query {
+			len += 1 + query.len()
+		} else if !self.query_pairs.is_empty() {
+			len += self
+				.query_pairs
+				.iter()
+				.map(|(k, v)| {
+					2 + encoded_len(k, &FormatComponent::Query)
+						+ encoded_len(v, &FormatComponent::Query)
+				})
+				.sum::<usize>()
+		}
+
+		len += self.fragment.map(|f| f.len() + 1).unwrap_or(0);
+		len
+	}
+
+	/// Writes the components, with a single allocation.
+	fn build(&self) -> String {
+		let len = self.len();
+		let mut result = String::with_capacity(len);
+
+		if let Some(scheme) = self.scheme {
+			result.push_str(scheme.as_str());
+			result.push(':');
+		}
+
+		if let Some(authority) = self.authority {
+			result.push_str("//");
+			result.push_str(authority.as_str());
+		} else if let Some(host) = self.host {
+			result.push_str("//");
+			if let Some(user_info) = self.user_info {
+				result.push_str(user_info.as_str());
+				result.push('@');
+			}
+
+			result.push_str(host.as_str());
+
+			if let Some(port) = self.port {
+				result.push(':');
+				result.push_str(port.as_str());
+			}
+		}
+
+		result.push_str(self.path_prefix());
+		match self.path {
+			Some(path) => result.push_str(path.as_str()),
+			None => {
+				for segment in &self.segments {
+					result.push('/');
+					result.push_str(segment.as_str());
+				}
+			}
+		}
+
+		if let Some(query) = self.query {
+			result.push('?');
+			result.push_str(query.as_str());
+		} else {
+			for (i, (key, value)) in self.query_pairs.iter().enumerate() {
+				result.push(if i == 0 { '?' } else { '&' });
+				encode_into(key, &FormatComponent::Query, &mut result);
+				result.push('=');
+				encode_into(value, &FormatComponent::Query, &mut result);
+			}
+		}
+
+		if let Some(fragment) = self.fragment {
+			result.push('#');
+			result.push_str(fragment.as_str());
+		}
+
+		debug_assert_eq!(result.len(), len);
+		result
+	}
+}
+
+/// URI builder.
+///
+/// Assembles a URI from its components, given in order. The builder state
+/// `S` (see the [`builder`](crate::builder) module) names the next
+/// expected component, so that components cannot be given out of order, or
+/// twice, and the host cannot be omitted after the user info.
+///
+/// The output is always a valid URI. If needed, the path is prefixed to
+/// stay unambiguous (e.g. an authority-less path starting with `//` is
+/// prefixed with `/.`). The final length is computed before writing the
+/// URI, so building it only allocates once.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::uri::{Host, Port, Scheme, Segment, UriBuilder};
+///
+/// let uri = UriBuilder::new(Scheme::new(b"https").unwrap())
+///     .host(Host::new("example.org").unwrap())
+///     .port(Port::new(b"8080").unwrap())
+///     .segment(Segment::new("users").unwrap())
+///     .segment(Segment::new("42").unwrap())
+///     .query_pair("tag", "a&b")
+///     .build();
+///
+/// assert_eq!(uri, "https://example.org:8080/users/42?tag=a%26b");
+/// ```
+///
+/// The host is required after the user info:
+///
+/// ```compile_fail
+/// use iref::uri::{Scheme, UriBuilder, UserInfo};
+///
+/// UriBuilder::new(Scheme::new(b"https").unwrap())
+///     .user_info(UserInfo::new("user").unwrap())
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct UriBuilder<'a, S = state::Authority> {
+	components: Components<'a>,
+	state: PhantomData<S>,
+}
+
+impl<'a> UriBuilder<'a> {
+	/// Creates a new builder with the given scheme.
+	pub fn new(scheme: &'a Scheme) -> Self {
+		Self {
+			components: Components {
+				scheme: Some(scheme),
+				..Default::default()
+			},
+			state: PhantomData,
+		}
+	}
+}
+
+impl<S: state::Complete> UriBuilder<'_, S> {
+	/// Builds the URI.
+	pub fn build(&self) -> UriBuf {
+		unsafe { UriBuf::new_unchecked(self.components.build()) }
+	}
+}
+
+/// URI reference builder.
+///
+/// Same as [`UriBuilder`], but the scheme is optional.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::uri::{Path, UriRefBuilder};
+///
+/// let uri_ref = UriRefBuilder::new()
+///     .path(Path::new("a:b").unwrap())
+///     .build();
+///
+/// assert_eq!(uri_ref, "./a:b");
+/// ```
+#[derive(Debug, Clone)]
+pub struct UriRefBuilder<'a, S = state::Scheme> {
+	components: Components<'a>,
+	state: PhantomData<S>,
+}
+
+impl UriRefBuilder<'_> {
+	/// Creates a new builder.
+	pub fn new() -> Self {
+		Self {
+			components: Components::default(),
+			state: PhantomData,
+		}
+	}
+}
+
+impl Default for UriRefBuilder<'_> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<'a> UriRefBuilder<'a, state::Scheme> {
+	/// Sets the scheme.
+	pub fn scheme(mut self, scheme: &'a Scheme) -> UriRefBuilder<'a, state::Authority> {
+		self.components.scheme = Some(scheme);
+		self.step()
+	}
+}
+
+impl<S: state::Complete> UriRefBuilder<'_, S> {
+	/// Builds the URI reference.
+	pub fn build(&self) -> UriRefBuf {
+		unsafe { UriRefBuf::new_unchecked(self.components.build()) }
+	}
+}
+
+macro_rules! uri_builder_steps {
+	($($ty:ident),*) => {
+		$(
+			impl<'a, S> $ty<'a, S> {
+				fn step<T>(self) -> $ty<'a, T> {
+					$ty {
+						components: self.components,
+						state: PhantomData,
+					}
+				}
+			}
+
+			impl<'a, S: state::AuthorityStep> $ty<'a, S> {
+				/// Sets the authority.
+				pub fn authority(mut self, authority: &'a Authority) -> $ty<'a, state::Path> {
+					self.components.authority = Some(authority);
+					self.step()
+				}
+
+				/// Sets the user info. The host must follow.
+				pub fn user_info(mut self, user_info: &'a UserInfo) -> $ty<'a, state::Host> {
+					self.components.user_info = Some(user_info);
+					self.step()
+				}
+
+				/// Sets the host.
+				pub fn host(mut self, host: &'a Host) -> $ty<'a, state::Port> {
+					self.components.host = Some(host);
+					self.step()
+				}
+			}
+
+			impl<'a> $ty<'a, state::Host> {
+				/// Sets the host.
+				pub fn host(mut self, host: &'a Host) -> $ty<'a, state::Port> {
+					self.components.host = Some(host);
+					self.step()
+				}
+			}
+
+			impl<'a> $ty<'a, state::Port> {
+				/// Sets the port.
+				pub fn port(mut self, port: &'a Port) -> $ty<'a, state::Path> {
+					self.components.port = Some(port);
+					self.step()
+				}
+			}
+
+			impl<'a, S: state::PathStep> $ty<'a, S> {
+				/// Sets the whole path.
+				pub fn path(mut self, path: &'a Path) -> $ty<'a, state::Query> {
+					self.components.path = Some(path);
+					self.step()
+				}
+			}
+
+			impl<'a, S: state::SegmentStep> $ty<'a, S> {
+				/// Pushes a path segment, preceded by `/`.
+				pub fn segment(mut self, segment: &'a Segment) -> $ty<'a, state::Path> {
+					self.components.segments.push(segment);
+					self.step()
+				}
+			}
+
+			impl<'a, S: state::QueryStep> $ty<'a, S> {
+				/// Sets the whole query.
+				pub fn query(mut self, query: &'a Query) -> $ty<'a, state::Fragment> {
+					self.components.query = Some(query);
+					self.step()
+				}
+			}
+
+			impl<'a, S: state::QueryPairStep> $ty<'a, S> {
+				/// Pushes a `key=value` query pair.
+				///
+				/// The key and value are percent-encoded, including the `&`,
+				/// `=` and `+` characters.
+				pub fn query_pair(mut self, key: &'a str, value: &'a str) -> $ty<'a, state::QueryPairs> {
+					self.components.query_pairs.push((key, value));
+					self.step()
+				}
+			}
+
+			impl<'a, S: state::FragmentStep> $ty<'a, S> {
+				/// Sets the fragment.
+				pub fn fragment(mut self, fragment: &'a Fragment) -> $ty<'a, state::Done> {
+					self.components.fragment = Some(fragment);
+					self.step()
+				}
+			}
+		)*
+	};
+}
+
+uri_builder_steps!(UriBuilder, UriRefBuilder);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn scheme(s: &str) -> &Scheme {
+		Scheme::new(s.as_bytes()).unwrap()
+	}
+
+	fn segment(s: &str) -> &Segment {
+		Segment::new(s).unwrap()
+	}
+
+	#[test]
+	fn build() {
+		let vectors = [
+			(
+				UriBuilder::new(scheme("http"))
+					.authority(Authority::new("example.org").unwrap())
+					.build(),
+				"http://example.org",
+			),
+			(
+				UriBuilder::new(scheme("http"))
+					.user_info(UserInfo::new("user:pw").unwrap())
+					.host(Host::new("[::1]").unwrap())
+					.segment(segment(""))
+					.build(),
+				"http://user:pw@[::1]/",
+			),
+			(
+				UriBuilder::new(scheme("http"))
+					.host(Host::new("example.org").unwrap())
+					.path(Path::new("a/b").unwrap())
+					.query(Query::new("q").unwrap())
+					.fragment(Fragment::new("f").unwrap())
+					.build(),
+				"http://example.org/a/b?q#f",
+			),
+			(
+				UriBuilder::new(scheme("urn"))
+					.path(Path::new("isbn:0451450523").unwrap())
+					.build(),
+				"urn:isbn:0451450523",
+			),
+			(
+				UriBuilder::new(scheme("a"))
+					.path(Path::new("//b").unwrap())
+					.build(),
+				"a:/.//b",
+			),
+			(
+				UriBuilder::new(scheme("a"))
+					.segment(segment(""))
+					.segment(segment("b"))
+					.build(),
+				"a:/.//b",
+			),
+			(
+				UriBuilder::new(scheme("a"))
+					.query_pair("k", "v 1")
+					.query_pair("#", "x=y+z")
+					.build(),
+				"a:?k=v%201&%23=x%3Dy%2Bz",
+			),
+			(
+				UriBuilder::new(scheme("a"))
+					.fragment(Fragment::new("").unwrap())
+					.build(),
+				"a:#",
+			),
+		];
+
+		for (uri, expected) in vectors {
+			assert_eq!(uri.as_str(), expected);
+			assert!(super::super::Uri::validate_str(uri.as_str()), "{uri}")
+		}
+	}
+
+	#[test]
+	fn build_ref() {
+		let vectors = [
+			(UriRefBuilder::new().build(), ""),
+			(
+				UriRefBuilder::new()
+					.host(Host::new("example.org").unwrap())
+					.build(),
+				"//example.org",
+			),
+			(
+				UriRefBuilder::new()
+					.path(Path::new("a:b/c").unwrap())
+					.build(),
+				"./a:b/c",
+			),
+			(
+				UriRefBuilder::new()
+					.scheme(scheme("s"))
+					.path(Path::new("a:b").unwrap())
+					.build(),
+				"s:a:b",
+			),
+			(
+				UriRefBuilder::new()
+					.segment(segment(""))
+					.segment(segment(""))
+					.build(),
+				"/.//",
+			),
+		];
+
+		for (uri_ref, expected) in vectors {
+			assert_eq!(uri_ref.as_str(), expected);
+			assert!(
+				super::super::UriRef::validate_str(uri_ref.as_str()),
+				"{uri_ref}"
+			)
+		}
+	}
+
+	#[test]
+	fn single_allocation() {
+		let uri = UriBuilder::new(scheme("https"))
+			.host(Host::new("example.org").unwrap())
+			.segment(segment("a"))
+			.query_pair("q", "€ &")
+			.build()
+			.into_string();
+
+		assert_eq!(uri.len(), uri.capacity());
+	}
+}
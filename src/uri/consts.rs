@@ -0,0 +1,538 @@
+//! Compile-time URI operations.
+//!
+//! The functions of this module back the `uri_concat!` and `uri_resolve!`
+//! macros. Since const functions cannot allocate, they write into arrays
+//! whose length is computed beforehand by the macros.
+use super::{Uri, UriRef};
+
+/// Byte range.
+type Range = (usize, usize);
+
+/// Fixed-capacity byte buffer.
+struct Buffer<const L: usize> {
+	bytes: [u8; L],
+	len: usize,
+}
+
+impl<const L: usize> Buffer<L> {
+	const fn new() -> Self {
+		Self {
+			bytes: [0; L],
+			len: 0,
+		}
+	}
+
+	const fn push(&mut self, bytes: &[u8]) {
+		let mut i = 0;
+		while i < bytes.len() {
+			self.bytes[self.len] = bytes[i];
+			self.len += 1;
+			i += 1
+		}
+	}
+
+	const fn as_bytes(&self) -> &[u8] {
+		self.bytes.split_at(self.len).0
+	}
+}
+
+/// Returns the given range of `bytes`.
+const fn slice(bytes: &[u8], (start, end): Range) -> &[u8] {
+	bytes.split_at(end).0.split_at(start).1
+}
+
+const fn eq(a: &[u8], b: &[u8]) -> bool {
+	if a.len() != b.len() {
+		return false;
+	}
+
+	let mut i = 0;
+	while i < a.len() {
+		if a[i] != b[i] {
+			return false;
+		}
+
+		i += 1
+	}
+
+	true
+}
+
+/// Component ranges of a valid URI reference.
+struct Parts {
+	scheme: Option<Range>,
+	authority: Option<Range>,
+	path: Range,
+	query: Option<Range>,
+	fragment: Option<Range>,
+}
+
+impl Parts {
+	const fn new(bytes: &[u8]) -> Self {
+		let mut i = 0;
+		while i < bytes.len() && !matches!(bytes[i], b':' | b'/' | b'?' | b'#') {
+			i += 1
+		}
+
+		let scheme = if i < bytes.len() && bytes[i] == b':' {
+			i += 1;
+			Some((0, i - 1))
+		} else {
+			i = 0;
+			None
+		};
+
+		let authority = if i + 1 < bytes.len() && bytes[i] == b'/' && bytes[i + 1] == b'/' {
+			i += 2;
+			let start = i;
+			while i < bytes.len() && !matches!(bytes[i], b'/' | b'?' | b'#') {
+				i += 1
+			}
+
+			Some((start, i))
+		} else {
+			None
+		};
+
+		let start = i;
+		while i < bytes.len() && !matches!(bytes[i], b'?' | b'#') {
+			i += 1
+		}
+
+		let path = (start, i);
+
+		let query = if i < bytes.len() && bytes[i] == b'?' {
+			i += 1;
+			let start = i;
+			while i < bytes.len() && bytes[i] != b'#' {
+				i += 1
+			}
+
+			Some((start, i))
+		} else {
+			None
+		};
+
+		let fragment = if i < bytes.len() {
+			Some((i + 1, bytes.len()))
+		} else {
+			None
+		};
+
+		Self {
+			scheme,
+			authority,
+			path,
+			query,
+			fragment,
+		}
+	}
+}
+
+/// Writes the normalized `path` into `output`, following
+/// [`PathMut::normalize`](super::PathMut::normalize).
+const fn push_normalized<const L: usize>(
+	output: &mut Buffer<L>,
+	path: &[u8],
+	has_scheme: bool,
+	has_authority: bool,
+) {
+	let absolute = !path.is_empty() && path[0] == b'/';
+	let offset = if absolute { 1 } else { 0 };
+
+	let mut stack: [Range; L] = [(0, 0); L];
+	let mut len = 0;
+	let mut open = false;
+
+	if offset < path.len() {
+		let mut start = offset;
+		loop {
+			let mut end = start;
+			while end < path.len() && path[end] != b'/' {
+				end += 1
+			}
+
+			let segment = slice(path, (start, end));
+			open = if eq(segment, b".") {
+				true
+			} else if eq(segment, b"..") {
+				let push = if len > 0 {
+					eq(slice(path, stack[len - 1]), b"..")
+				} else {
+					!absolute
+				};
+
+				if push {
+					stack[len] = (start, end);
+					len += 1
+				} else {
+					len = len.saturating_sub(1)
+				}
+
+				true
+			} else {
+				stack[len] = (start, end);
+				len += 1;
+				false
+			};
+
+			if end == path.len() {
+				break;
+			}
+
+			start = end + 1
+		}
+	}
+
+	if open && len > 0 {
+		stack[len] = (0, 0);
+		len += 1
+	}
+
+	if absolute {
+		if !has_authority && len > 1 && stack[0].0 == stack[0].1 {
+			// AMBIGUITY: `//path` would look like an authority.
+			output.push(b"/.")
+		}
+
+		output.push(b"/")
+	} else if has_authority && len > 0 && !(len == 1 && stack[0].0 == stack[0].1) {
+		// VALIDITY: path must be absolute when an authority is present.
+		output.push(b"/")
+	} else if !has_scheme
+		&& !has_authority
+		&& len > 0
+		&& crate::common::parse::looks_like_scheme(slice(path, stack[0]))
+	{
+		// AMBIGUITY: the first segment would look like a scheme.
+		output.push(b"./")
+	}
+
+	let mut i = 0;
+	while i < len {
+		if i > 0 {
+			output.push(b"/")
+		}
+
+		output.push(slice(path, stack[i]));
+		i += 1
+	}
+}
+
+/// Concatenates the given strings, where `L` is the total length.
+#[doc(hidden)]
+pub const fn const_concat<const L: usize>(a: &str, b: &str) -> [u8; L] {
+	let mut buffer = Buffer::<L>::new();
+	buffer.push(a.as_bytes());
+	buffer.push(b.as_bytes());
+	buffer.bytes
+}
+
+/// Resolves `reference` against `base`, following
+/// [`UriRef::resolved`](super::UriRef::resolved).
+///
+/// Returns the resolved URI in a buffer of `L` bytes, and its length.
+/// `L` must be at least the sum of the input lengths plus 4.
+#[doc(hidden)]
+pub const fn const_resolve<const L: usize>(base: &Uri, reference: &UriRef) -> ([u8; L], usize) {
+	let b = base.as_str().as_bytes();
+	let r = reference.as_str().as_bytes();
+	let bp = Parts::new(b);
+	let rp = Parts::new(r);
+
+	let mut output = Buffer::<L>::new();
+
+	match rp.scheme {
+		Some(scheme) => output.push(slice(r, scheme)),
+		None => match bp.scheme {
+			Some(scheme) => output.push(slice(b, scheme)),
+			None => unreachable!(),
+		},
+	}
+
+	output.push(b":");
+
+	let r_path = slice(r, rp.path);
+	let mut query = rp.query;
+	let mut query_bytes = r;
+
+	if rp.scheme.is_some() || rp.authority.is_some() {
+		if let Some(authority) = rp.authority {
+			output.push(b"//");
+			output.push(slice(r, authority));
+		}
+
+		push_normalized(&mut output, r_path, true, rp.authority.is_some());
+	} else {
+		if let Some(authority) = bp.authority {
+			output.push(b"//");
+			output.push(slice(b, authority));
+		}
+
+		let has_authority = bp.authority.is_some();
+		if r_path.is_empty() {
+			output.push(slice(b, bp.path));
+			if query.is_none() {
+				query = bp.query;
+				query_bytes = b
+			}
+		} else if r_path[0] == b'/' {
+			push_normalized(&mut output, r_path, true, has_authority);
+		} else {
+			// Merge the paths.
+			let mut merged = Buffer::<L>::new();
+			let b_path = slice(b, bp.path);
+			if b_path.is_empty() && has_authority {
+				merged.push(b"/")
+			} else {
+				let mut end = b_path.len();
+				while end > 0 && b_path[end - 1] != b'/' {
+					end -= 1
+				}
+
+				merged.push(b_path.split_at(end).0)
+			}
+
+			merged.push(r_path);
+
+			// The merged path is normalized without context, as in
+			// `UriRefBuf::resolve`.
+			push_normalized(&mut output, merged.as_bytes(), false, false);
+		}
+	}
+
+	if let Some(query) = query {
+		output.push(b"?");
+		output.push(slice(query_bytes, query))
+	}
+
+	if let Some(fragment) = rp.fragment {
+		output.push(b"#");
+		output.push(slice(r, fragment))
+	}
+
+	(output.bytes, output.len)
+}
+
+/// Truncates the given array.
+#[doc(hidden)]
+pub const fn const_truncate<const L: usize, const N: usize>(bytes: &[u8; L]) -> [u8; N] {
+	let mut result = [0; N];
+	let mut i = 0;
+	while i < N {
+		result[i] = bytes[i];
+		i += 1
+	}
+
+	result
+}
+
+/// Concatenates a URI with string literals, at compile time.
+///
+/// The first argument must be a constant `&Uri` expression (such as a
+/// `const` item or a [`uri!`](macro@crate::uri) invocation), followed by one or
+/// more string literals. The result is a `&'static Uri`, checked at compile
+/// time.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Uri, uri, uri_concat};
+///
+/// const FOAF: &Uri = uri!("http://xmlns.com/foaf/0.1/");
+/// const NAME: &Uri = uri_concat!(FOAF, "name");
+/// assert_eq!(NAME, "http://xmlns.com/foaf/0.1/name");
+/// ```
+///
+/// Invalid results are rejected at compile time:
+///
+/// ```compile_fail
+/// # use iref::{Uri, uri, uri_concat};
+/// const FOAF: &Uri = uri!("http://xmlns.com/foaf/0.1/");
+/// const NAME: &Uri = uri_concat!(FOAF, "first name");
+/// ```
+#[macro_export]
+macro_rules! uri_concat {
+	($base:expr, $($suffix:literal),+ $(,)?) => {{
+		const __BASE: &$crate::uri::Uri = $base;
+		const __SUFFIX: &str = concat!($($suffix),+);
+		const __LEN: usize = __BASE.as_str().len() + __SUFFIX.len();
+		const __BYTES: [u8; __LEN] = $crate::uri::const_concat::<__LEN>(__BASE.as_str(), __SUFFIX);
+		const __RESULT: &$crate::uri::Uri = match $crate::uri::Uri::from_bytes(&__BYTES) {
+			Ok(value) => value,
+			Err(_) => panic!("invalid URI concatenation"),
+		};
+		__RESULT
+	}};
+}
+
+/// Resolves a URI reference against a base URI, at compile time.
+///
+/// The first argument must be a constant `&Uri` expression (such as a
+/// `const` item or a [`uri!`](macro@crate::uri) invocation), and the second a
+/// string literal or a constant `&UriRef` expression. The result is a
+/// `&'static Uri`, equal to what [`UriRef::resolved`] would return, checked
+/// at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Uri, uri, uri_resolve};
+///
+/// const BASE: &Uri = uri!("http://a/b/c/d;p?q");
+/// const RESOLVED: &Uri = uri_resolve!(BASE, "../g?y#s");
+/// assert_eq!(RESOLVED, "http://a/b/g?y#s");
+/// ```
+#[macro_export]
+macro_rules! uri_resolve {
+	($base:expr, $reference:literal $(,)?) => {{
+		const __REFERENCE: &$crate::uri::UriRef = match $crate::uri::UriRef::from_str($reference) {
+			Ok(value) => value,
+			Err(_) => panic!("invalid URI reference"),
+		};
+		const __BASE: &$crate::uri::Uri = $base;
+		const __MAX: usize = __BASE.as_str().len() + __REFERENCE.as_str().len() + 4;
+		const __RESOLVED: ([u8; __MAX], usize) = $crate::uri::const_resolve(__BASE, __REFERENCE);
+		const __BYTES: [u8; __RESOLVED.1] = $crate::uri::const_truncate(&__RESOLVED.0);
+		const __RESULT: &$crate::uri::Uri = match $crate::uri::Uri::from_bytes(&__BYTES) {
+			Ok(value) => value,
+			Err(_) => panic!("invalid URI resolution"),
+		};
+		__RESULT
+	}};
+	($base:expr, $reference:expr $(,)?) => {{
+		const __REFERENCE: &$crate::uri::UriRef = $reference;
+		const __BASE: &$crate::uri::Uri = $base;
+		const __MAX: usize = __BASE.as_str().len() + __REFERENCE.as_str().len() + 4;
+		const __RESOLVED: ([u8; __MAX], usize) = $crate::uri::const_resolve(__BASE, __REFERENCE);
+		const __BYTES: [u8; __RESOLVED.1] = $crate::uri::const_truncate(&__RESOLVED.0);
+		const __RESULT: &$crate::uri::Uri = match $crate::uri::Uri::from_bytes(&__BYTES) {
+			Ok(value) => value,
+			Err(_) => panic!("invalid URI resolution"),
+		};
+		__RESULT
+	}};
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const BASE: &Uri = match Uri::from_str("http://a/b/c/d;p?q") {
+		Ok(value) => value,
+		Err(_) => panic!(),
+	};
+
+	/// RFC 3986 section 5.4 examples.
+	#[test]
+	fn resolve() {
+		let vectors: [(&Uri, &str); _] = [
+			(uri_resolve!(BASE, "g:h"), "g:h"),
+			(uri_resolve!(BASE, "g"), "http://a/b/c/g"),
+			(uri_resolve!(BASE, "./g"), "http://a/b/c/g"),
+			(uri_resolve!(BASE, "g/"), "http://a/b/c/g/"),
+			(uri_resolve!(BASE, "/g"), "http://a/g"),
+			(uri_resolve!(BASE, "//g"), "http://g"),
+			(uri_resolve!(BASE, "?y"), "http://a/b/c/d;p?y"),
+			(uri_resolve!(BASE, "g?y"), "http://a/b/c/g?y"),
+			(uri_resolve!(BASE, "#s"), "http://a/b/c/d;p?q#s"),
+			(uri_resolve!(BASE, "g#s"), "http://a/b/c/g#s"),
+			(uri_resolve!(BASE, "g?y#s"), "http://a/b/c/g?y#s"),
+			(uri_resolve!(BASE, ";x"), "http://a/b/c/;x"),
+			(uri_resolve!(BASE, "g;x"), "http://a/b/c/g;x"),
+			(uri_resolve!(BASE, ""), "http://a/b/c/d;p?q"),
+			(uri_resolve!(BASE, "."), "http://a/b/c/"),
+			(uri_resolve!(BASE, "./"), "http://a/b/c/"),
+			(uri_resolve!(BASE, ".."), "http://a/b/"),
+			(uri_resolve!(BASE, "../g"), "http://a/b/g"),
+			(uri_resolve!(BASE, "../.."), "http://a/"),
+			(uri_resolve!(BASE, "../../g"), "http://a/g"),
+			(uri_resolve!(BASE, "../../../g"), "http://a/g"),
+			(uri_resolve!(BASE, "/./g"), "http://a/g"),
+			(uri_resolve!(BASE, "g."), "http://a/b/c/g."),
+			(uri_resolve!(BASE, "g/../h"), "http://a/b/c/h"),
+			(uri_resolve!(BASE, "g;x=1/../y"), "http://a/b/c/y"),
+			(uri_resolve!(BASE, "http:g"), "http:g"),
+		];
+
+		for (resolved, expected) in vectors {
+			assert_eq!(resolved.as_str(), expected)
+		}
+	}
+
+	/// Checks that the const resolution matches the runtime one.
+	#[cfg(feature = "std")]
+	#[test]
+	fn differential() {
+		const L: usize = 64;
+
+		let bases = [
+			"http://a/b/c/d;p?q",
+			"http://a",
+			"http://a/",
+			"http://a//b",
+			"http://a/b/",
+			"s:",
+			"s:a",
+			"s:a/b/c",
+			"s:/a/b",
+			"s:/.//a",
+			"s:?q",
+		];
+
+		let references = [
+			"",
+			".",
+			"..",
+			"./",
+			"../",
+			"../..",
+			"g",
+			"g/",
+			"/g",
+			"//g",
+			"?y",
+			"#s",
+			"../../../g",
+			".//g",
+			"./..//g",
+			"/.//g",
+			"a/./b/../../..",
+			"s2:a/../b",
+			"s2://h/../a",
+			"//h",
+			"//h?q",
+			"g/../..",
+			"..//g",
+			"./a:b",
+			"/..",
+			"/../g",
+		];
+
+		for base in bases {
+			let base = Uri::new(base).unwrap();
+			for reference in references {
+				let reference = UriRef::new(reference).unwrap();
+				let expected = reference.resolved(base);
+				let (bytes, len) = const_resolve::<L>(base, reference);
+				assert_eq!(
+					core::str::from_utf8(&bytes[..len]).unwrap(),
+					expected.as_str(),
+					"{reference} against {base}"
+				)
+			}
+		}
+	}
+
+	#[test]
+	fn concat() {
+		const FOAF: &Uri = match Uri::from_str("http://xmlns.com/foaf/0.1/") {
+			Ok(value) => value,
+			Err(_) => panic!(),
+		};
+		const NAME: &Uri = uri_concat!(FOAF, "name");
+		const MBOX: &Uri = uri_concat!(FOAF, "mbox", "_sha1sum");
+		assert_eq!(NAME.as_str(), "http://xmlns.com/foaf/0.1/name");
+		assert_eq!(MBOX.as_str(), "http://xmlns.com/foaf/0.1/mbox_sha1sum");
+	}
+}
@@ -0,0 +1,142 @@
+use crate::common::digest::Fnv1a128;
+
+use super::{Uri, UriRef};
+
+impl UriRef {
+	/// Returns the canonical digest of this URI reference.
+	///
+	/// The digest is stable: it does not depend on the platform or the
+	/// crate version, and can be persisted. It is consistent with
+	/// [`PartialEq`]: equal URI references have the same digest.
+	///
+	/// The digest is the 128-bit [FNV-1a] hash of the following encoding,
+	/// where *strings* are prefixed with their byte length as a 64-bit
+	/// little-endian integer, and *optional* values are encoded as `0x00`
+	/// when absent, or `0x01` followed by the value:
+	///
+	/// 1. the optional scheme, as a string in lowercase;
+	/// 2. the optional authority, made of:
+	///    1. the optional user info, as a percent-decoded string;
+	///    2. the host, as a percent-decoded string;
+	///    3. the optional port, as a string;
+	/// 3. the path, made of:
+	///    1. `0x01` if it is absolute, `0x00` otherwise;
+	///    2. the number of [normalized segments](super::Path::normalized_segments),
+	///       as a 64-bit little-endian integer;
+	///    3. each normalized segment, as a percent-decoded string;
+	/// 4. the optional query, as a percent-decoded string;
+	/// 5. the optional fragment, as a percent-decoded string.
+	///
+	/// FNV-1a is not a cryptographic hash function.
+	///
+	/// [FNV-1a]: <http://www.isthe.com/chongo/tech/comp/fnv/>
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::UriRef;
+	///
+	/// let a = UriRef::new("HTTP://example.org/a/./b/../%63").unwrap();
+	/// let b = UriRef::new("http://example.org/a/c").unwrap();
+	/// assert_eq!(a, b);
+	/// assert_eq!(a.canonical_digest(), b.canonical_digest());
+	/// assert_eq!(b.canonical_digest(), 0x8855d858084661bb2724ef668721c02f);
+	/// ```
+	pub fn canonical_digest(&self) -> u128 {
+		let parts = self.parts();
+		let mut hasher = Fnv1a128::new();
+
+		hasher.write_option(parts.scheme, |hasher, scheme| {
+			hasher.write_bytes(scheme.len(), scheme.bytes().map(|b| b.to_ascii_lowercase()))
+		});
+
+		hasher.write_option(parts.authority, |hasher, authority| {
+			let parts = authority.parts();
+			hasher.write_option(parts.user_info, |hasher, user_info| {
+				hasher.write_pct_str(user_info)
+			});
+			hasher.write_pct_str(parts.host);
+			hasher.write_option(parts.port, |hasher, port| {
+				hasher.write_bytes(port.len(), port.bytes())
+			})
+		});
+
+		hasher.write_u8(parts.path.is_absolute() as u8);
+		let segments = parts.path.normalized_segments();
+		hasher.write_len(segments.len());
+		for segment in segments {
+			hasher.write_pct_str(segment)
+		}
+
+		hasher.write_option(parts.query, |hasher, query| hasher.write_pct_str(query));
+		hasher.write_option(parts.fragment, |hasher, fragment| {
+			hasher.write_pct_str(fragment)
+		});
+
+		hasher.finish()
+	}
+}
+
+impl Uri {
+	/// Returns the canonical digest of this URI.
+	///
+	/// This is the same as the digest of the URI as a reference. See
+	/// [`UriRef::canonical_digest`].
+	pub fn canonical_digest(&self) -> u128 {
+		self.as_uri_ref().canonical_digest()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn consistent_with_eq() {
+		let vectors = [
+			"",
+			"a",
+			"/",
+			"//",
+			"///",
+			"./a",
+			"a/.",
+			"a/..",
+			"/a/b/../c/./d/",
+			"/a/b/%2E%2E/c",
+			"http://example.org",
+			"HTTP://example.org",
+			"http://example.org/",
+			"http://example.org:",
+			"http://example.org:80",
+			"http://user@example.org",
+			"http://us%65r@example.org",
+			"http://@example.org",
+			"http://%65xample.org",
+			"http://EXAMPLE.org",
+			"?",
+			"?a",
+			"?%61",
+			"#",
+			"#a",
+			"#%61",
+			"a?#",
+		];
+
+		for a in vectors {
+			let a = UriRef::new(a).unwrap();
+			for b in vectors {
+				let b = UriRef::new(b).unwrap();
+				assert_eq!(
+					a.canonical_digest() == b.canonical_digest(),
+					a == b,
+					"{a} {b}"
+				)
+			}
+
+			if let Some(uri) = a.as_uri() {
+				assert_eq!(uri.canonical_digest(), a.canonical_digest())
+			}
+		}
+	}
+}
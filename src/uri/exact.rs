@@ -0,0 +1,90 @@
+use crate::Exact;
+
+use super::{Authority, Fragment, Host, Path, Query, Segment, Uri, UriRef, UserInfo};
+
+#[cfg(feature = "std")]
+use super::{UriBuf, UriRefBuf};
+
+/// Owned URI comparing byte-exactly.
+#[cfg(feature = "std")]
+pub type ExactUriBuf = Exact<UriBuf>;
+
+/// Owned URI reference comparing byte-exactly.
+#[cfg(feature = "std")]
+pub type ExactUriRefBuf = Exact<UriRefBuf>;
+
+macro_rules! uri_as_exact {
+	($($ty:ident),*) => {
+		$(
+			impl $ty {
+				/// Returns a view of this value comparing, ordering and hashing
+				/// byte-exactly, without normalization.
+				pub fn as_exact(&self) -> Exact<&Self> {
+					Exact(self)
+				}
+			}
+		)*
+	};
+}
+
+uri_as_exact!(
+	Uri, UriRef, Authority, UserInfo, Host, Path, Segment, Query, Fragment
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn exact() {
+		let vectors = [
+			("http://example.org/a", "http://example.org/a", true),
+			("http://example.org/a", "HTTP://example.org/a", false),
+			("http://example.org/a", "http://example.org/b/../a", false),
+			("http://example.org/~a", "http://example.org/%7Ea", false),
+			("http://example.org?a", "http://example.org?%61", false),
+		];
+
+		for (a, b, expected) in vectors {
+			let a = Uri::new(a).unwrap();
+			let b = Uri::new(b).unwrap();
+			assert_eq!(a, b, "{a} {b}");
+			assert_eq!(a.as_exact() == b.as_exact(), expected, "{a} {b}");
+			assert_eq!(
+				a.as_uri_ref().as_exact() == b.as_uri_ref().as_exact(),
+				expected,
+				"{a} {b}"
+			)
+		}
+	}
+
+	#[test]
+	fn exact_path() {
+		let vectors = [
+			("/a/b", "/a/b", true),
+			("/a/b", "/a/./b", false),
+			("/a/b", "/a/%62", false),
+		];
+
+		for (a, b, expected) in vectors {
+			let a = Path::new(a).unwrap();
+			let b = Path::new(b).unwrap();
+			assert_eq!(a, b, "{a} {b}");
+			assert_eq!(a.as_exact() == b.as_exact(), expected, "{a} {b}")
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn exact_buf() {
+		use std::collections::BTreeSet;
+
+		let set: BTreeSet<ExactUriBuf> = ["http://a/b", "HTTP://a/b", "http://a/./b"]
+			.into_iter()
+			.map(|s| Exact(UriBuf::new(s.to_owned()).unwrap()))
+			.collect();
+		assert_eq!(set.len(), 3);
+		assert!(set.contains("http://a/./b"));
+		assert!(!set.contains("http://A/b"))
+	}
+}
@@ -0,0 +1,367 @@
+//! Fast validation path.
+//!
+//! The grammar automaton decodes and processes the input one character at a
+//! time. Most inputs are ASCII and spend most of their length in the path,
+//! query and fragment, whose validity does not depend on the position of each
+//! character. The functions of this module split the input on structural
+//! bytes and check these components eight bytes at a time using SWAR (SIMD
+//! within a register) bit tricks.
+//!
+//! They only ever return `true` for valid inputs. When they return `false`,
+//! the input may or may not be valid and must be checked by the automaton.
+//! Non-ASCII bytes are always left to the automaton.
+use super::grammar;
+
+const ONES: u64 = 0x0101_0101_0101_0101;
+const HIGHS: u64 = 0x8080_8080_8080_8080;
+
+/// Loads eight bytes starting at `i`.
+const fn load(bytes: &[u8], i: usize) -> u64 {
+	u64::from_le_bytes([
+		bytes[i],
+		bytes[i + 1],
+		bytes[i + 2],
+		bytes[i + 3],
+		bytes[i + 4],
+		bytes[i + 5],
+		bytes[i + 6],
+		bytes[i + 7],
+	])
+}
+
+/// Sets the high bit of every byte greater or equal to `n`.
+///
+/// Only exact when every byte of `x` is ASCII and `n <= 0x80`.
+const fn ge(x: u64, n: u8) -> u64 {
+	x.wrapping_add(ONES * (0x80 - n) as u64) & HIGHS
+}
+
+/// Sets the high bit of every byte in `a..=b`.
+///
+/// Only exact when every byte of `x` is ASCII.
+const fn in_range(x: u64, a: u8, b: u8) -> u64 {
+	ge(x, a) & !ge(x, b + 1)
+}
+
+/// Sets the high bit of every byte that is not a `pchar` or `/`, or that is a
+/// percent sign.
+///
+/// The result is non-zero as soon as one byte is not ASCII, in which case
+/// the other bytes may be misclassified.
+const fn unsafe_bytes(x: u64) -> u64 {
+	(x & HIGHS)
+		| (!ge(x, 0x21) & HIGHS)
+		| ge(x, 0x7f)
+		| in_range(x, b'"', b'#')
+		| in_range(x, b'%', b'%')
+		| in_range(x, b'<', b'<')
+		| in_range(x, b'>', b'?')
+		| in_range(x, b'[', b'^')
+		| in_range(x, b'`', b'`')
+		| in_range(x, b'{', b'}')
+}
+
+/// Checks if the given byte is a `pchar` (without percent-encoding) or `/`.
+const fn is_safe(b: u8) -> bool {
+	matches!(b,
+		b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9'
+		| b'-' | b'.' | b'_' | b'~'
+		| b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+		| b':' | b'@' | b'/'
+	)
+}
+
+const fn is_scheme_char(b: u8) -> bool {
+	matches!(b, b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'+' | b'-' | b'.')
+}
+
+/// Returns the end of the scheme if the input starts with a scheme
+/// followed by `:`.
+const fn scheme_end(bytes: &[u8]) -> Option<usize> {
+	if bytes.is_empty() || !bytes[0].is_ascii_alphabetic() {
+		return None;
+	}
+
+	let mut i = 1;
+	while i < bytes.len() {
+		match bytes[i] {
+			b':' => return Some(i),
+			b if is_scheme_char(b) => i += 1,
+			_ => return None,
+		}
+	}
+
+	None
+}
+
+/// Checks the hierarchical part, query and fragment starting at `i`, right
+/// after the scheme (if any).
+const fn hier_part(bytes: &[u8], mut i: usize) -> bool {
+	if i + 1 < bytes.len() && bytes[i] == b'/' && bytes[i + 1] == b'/' {
+		i += 2;
+		let start = i;
+		while i < bytes.len() && !matches!(bytes[i], b'/' | b'?' | b'#') {
+			i += 1
+		}
+
+		let authority = bytes.split_at(i).0.split_at(start).1;
+		if !grammar::Authority::validate_bytes(authority) {
+			return false;
+		}
+	}
+
+	tail(bytes, i)
+}
+
+/// Checks the path, query and fragment starting at `i`.
+///
+/// With the components split on structural bytes, the path is valid as
+/// long as its bytes are `pchar`s or `/`, and so are the query and fragment
+/// bytes (with `?`).
+const fn tail(bytes: &[u8], mut i: usize) -> bool {
+	const PATH: u8 = 0;
+	const QUERY: u8 = 1;
+	const FRAGMENT: u8 = 2;
+
+	let mut state = PATH;
+	while i < bytes.len() {
+		if i + 8 <= bytes.len() && unsafe_bytes(load(bytes, i)) == 0 {
+			i += 8;
+			continue;
+		}
+
+		match bytes[i] {
+			b'%' => {
+				if i + 2 < bytes.len()
+					&& bytes[i + 1].is_ascii_hexdigit()
+					&& bytes[i + 2].is_ascii_hexdigit()
+				{
+					i += 3;
+					continue;
+				}
+
+				return false;
+			}
+			b'?' => {
+				if state == PATH {
+					state = QUERY
+				}
+			}
+			b'#' => {
+				if state == FRAGMENT {
+					return false;
+				}
+
+				state = FRAGMENT
+			}
+			b => {
+				if !is_safe(b) {
+					return false;
+				}
+			}
+		}
+
+		i += 1
+	}
+
+	true
+}
+
+/// Checks that the input is a valid URI.
+pub(crate) const fn validate_uri(bytes: &[u8]) -> bool {
+	match scheme_end(bytes) {
+		Some(end) => hier_part(bytes, end + 1),
+		None => false,
+	}
+}
+
+/// Checks that the input is a valid URI reference.
+pub(crate) const fn validate_uri_ref(bytes: &[u8]) -> bool {
+	match scheme_end(bytes) {
+		Some(end) => hier_part(bytes, end + 1),
+		None => {
+			// The first segment of a relative path cannot contain `:`.
+			let mut i = 0;
+			while i < bytes.len() && !matches!(bytes[i], b'/' | b'?' | b'#') {
+				if bytes[i] == b':' {
+					return false;
+				}
+
+				i += 1
+			}
+
+			hier_part(bytes, 0)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Xorshift pseudo-random number generator.
+	struct Rng(u64);
+
+	impl Rng {
+		fn next_u64(&mut self) -> u64 {
+			self.0 ^= self.0 << 13;
+			self.0 ^= self.0 >> 7;
+			self.0 ^= self.0 << 17;
+			self.0
+		}
+
+		fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+			items[self.next_u64() as usize % items.len()]
+		}
+	}
+
+	const PIECES: &[&str] = &[
+		"a",
+		"Z",
+		"0",
+		"9",
+		"-",
+		".",
+		"_",
+		"~",
+		"!",
+		"$",
+		"&",
+		"'",
+		"(",
+		")",
+		"*",
+		"+",
+		",",
+		";",
+		"=",
+		":",
+		"@",
+		"/",
+		"//",
+		"?",
+		"#",
+		"%",
+		"%2",
+		"%2F",
+		"%zz",
+		"[",
+		"]",
+		"[::1]",
+		"\\",
+		"^",
+		"`",
+		"{",
+		"|",
+		"}",
+		"<",
+		">",
+		"\"",
+		" ",
+		"\t",
+		"\x7f",
+		"\0",
+		"é",
+		"\u{10ffff}",
+		"\u{e000}",
+		"http:",
+		"urn:",
+		"http://",
+		"example.org",
+		":8080",
+		"user@",
+		"path",
+		"segment/",
+		"query",
+	];
+
+	fn random_input(rng: &mut Rng) -> String {
+		let mut input = String::new();
+		if rng.next_u64().is_multiple_of(2) {
+			input.push_str(rng.pick(&["http://", "a:", "A+.-:", "1a:", "//", "/", ""]));
+		}
+
+		let len = rng.next_u64() % 48;
+		for _ in 0..len {
+			if rng.next_u64().is_multiple_of(3) {
+				input.push_str(rng.pick(PIECES))
+			} else {
+				input.push(rng.pick(b"abcxyz019-._~/") as char)
+			}
+		}
+
+		input
+	}
+
+	#[test]
+	fn differential() {
+		let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+		let mut fast = 0;
+		for _ in 0..200_000 {
+			let input = random_input(&mut rng);
+			let bytes = input.as_bytes();
+
+			let expected = grammar::Uri::validate_bytes(bytes);
+			if validate_uri(bytes) {
+				assert!(expected, "{input:?}");
+				fast += 1
+			}
+			assert_eq!(
+				super::super::Uri::validate_bytes(bytes),
+				expected,
+				"{input:?}"
+			);
+
+			let expected = grammar::UriRef::validate_bytes(bytes);
+			if validate_uri_ref(bytes) {
+				assert!(expected, "{input:?}")
+			}
+			assert_eq!(
+				super::super::UriRef::validate_bytes(bytes),
+				expected,
+				"{input:?}"
+			);
+		}
+
+		// Make sure the fast path is actually taken.
+		assert!(fast > 1000)
+	}
+
+	#[test]
+	fn unsafe_bytes_classification() {
+		for b in 0..0x80u8 {
+			let expected = !is_safe(b) || b == b'%';
+			for i in 0..8 {
+				let mut chunk = *b"aaaaaaaa";
+				chunk[i] = b;
+				let mask = unsafe_bytes(u64::from_le_bytes(chunk));
+				assert_eq!(mask != 0, expected, "{b:#x} at {i}");
+			}
+		}
+
+		for b in 0x80..=0xffu8 {
+			assert_ne!(unsafe_bytes(u64::from_le_bytes([b; 8])), 0)
+		}
+	}
+
+	#[test]
+	fn fast_path() {
+		let vectors = [
+			"https://example.org/a/long/path/with/many/segments?and=a&query=string#frag",
+			"http://[::1]:8080/%20%2F",
+			"urn:isbn:0451450523",
+			"a:",
+			"file:///etc/hosts",
+		];
+
+		for input in vectors {
+			assert!(validate_uri(input.as_bytes()), "{input}")
+		}
+
+		let vectors = ["//example.org/path", "path/to/x?y", "", "#f", "./a:b"];
+
+		for input in vectors {
+			assert!(validate_uri_ref(input.as_bytes()), "{input}")
+		}
+	}
+}
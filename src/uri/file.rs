@@ -0,0 +1,606 @@
+use pct_str::Encoder;
+
+use super::{Scheme, Uri, UriBuf};
+
+/// Error raised when converting between a `file` URI and a filesystem path.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FilePathError {
+	/// The URI scheme is not `file`.
+	#[error("not a `file` URI")]
+	NotFile,
+
+	/// The URI designates a file on a remote host, which has no local path
+	/// representation.
+	#[error("non-local host `{0}`")]
+	NonLocalHost(String),
+
+	/// The URI authority has a user info or port component.
+	#[error("unsupported authority in `file` URI")]
+	UnsupportedAuthority,
+
+	/// The path is relative.
+	#[error("relative path")]
+	RelativePath,
+
+	/// The Windows path has no drive letter nor UNC server.
+	#[error("missing drive letter")]
+	MissingDrive,
+
+	/// The path contains a NUL byte or a percent-encoded path separator.
+	#[error("invalid path character")]
+	InvalidCharacter,
+
+	/// The decoded path is not valid UTF-8, which is required on the
+	/// target platform.
+	#[error("invalid path encoding")]
+	InvalidEncoding,
+}
+
+/// Source of a `file` URI path, after the authority has been checked.
+enum Location<'a> {
+	/// Local file, with the given URI path.
+	Local(&'a [u8]),
+
+	/// File on a remote host (UNC path), with the given host and path.
+	Remote(&'a str, &'a [u8]),
+}
+
+impl Uri {
+	/// Converts this `file` URI into a path of the current platform.
+	///
+	/// On Unix platforms this is [`Self::to_posix_path`], on Windows
+	/// [`Self::to_windows_path`]. The query and fragment are ignored.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// # #[cfg(unix)] {
+	/// use iref::Uri;
+	///
+	/// let uri = Uri::new("file:///home/user/my%20file.txt").unwrap();
+	/// assert_eq!(
+	///   uri.to_file_path().unwrap(),
+	///   std::path::Path::new("/home/user/my file.txt")
+	/// );
+	/// # }
+	/// ```
+	pub fn to_file_path(&self) -> Result<std::path::PathBuf, FilePathError> {
+		#[cfg(unix)]
+		{
+			use std::os::unix::ffi::OsStringExt;
+			let bytes = self.to_posix_path()?;
+			Ok(std::ffi::OsString::from_vec(bytes).into())
+		}
+
+		#[cfg(windows)]
+		{
+			self.to_windows_path().map(Into::into)
+		}
+
+		#[cfg(not(any(unix, windows)))]
+		{
+			let bytes = self.to_posix_path()?;
+			String::from_utf8(bytes)
+				.map(Into::into)
+				.map_err(|_| FilePathError::InvalidEncoding)
+		}
+	}
+
+	/// Converts this `file` URI into a POSIX path, as a byte string.
+	///
+	/// The authority must be absent, empty or `localhost`. Percent-encoded
+	/// bytes are decoded as-is, so the result may not be valid UTF-8. The
+	/// query and fragment are ignored.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::Uri;
+	///
+	/// let uri = Uri::new("file://localhost/etc/fstab").unwrap();
+	/// assert_eq!(uri.to_posix_path().unwrap(), b"/etc/fstab");
+	///
+	/// let uri = Uri::new("file:///tmp/%FF").unwrap();
+	/// assert_eq!(uri.to_posix_path().unwrap(), b"/tmp/\xff");
+	/// ```
+	pub fn to_posix_path(&self) -> Result<Vec<u8>, FilePathError> {
+		match self.file_location()? {
+			Location::Local(path) => {
+				if !path.starts_with(b"/") {
+					return Err(FilePathError::RelativePath);
+				}
+
+				let mut result = Vec::with_capacity(path.len());
+				for segment in path[1..].split(|b| *b == b'/') {
+					result.push(b'/');
+					decode_segment(segment, &mut result, b'/')?;
+				}
+
+				Ok(result)
+			}
+			Location::Remote(host, _) => Err(FilePathError::NonLocalHost(host.to_owned())),
+		}
+	}
+
+	/// Converts this `file` URI into a Windows path.
+	///
+	/// Follows [RFC 8089 Appendix E][appendix-e]: DOS drive letters may
+	/// appear as `file:///C:/`, `file:/C:/`, `file:C:/` or with a
+	/// (percent-encoded) `|` instead of `:`, and UNC paths as
+	/// `file://host/share` or `file:////host/share`. The query and fragment
+	/// are ignored.
+	///
+	/// [appendix-e]: <https://www.rfc-editor.org/rfc/rfc8089#appendix-E>
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::Uri;
+	///
+	/// let uri = Uri::new("file:///C:/Program%20Files/app.exe").unwrap();
+	/// assert_eq!(uri.to_windows_path().unwrap(), r"C:\Program Files\app.exe");
+	///
+	/// let uri = Uri::new("file://server/share/notes.txt").unwrap();
+	/// assert_eq!(uri.to_windows_path().unwrap(), r"\\server\share\notes.txt");
+	/// ```
+	pub fn to_windows_path(&self) -> Result<String, FilePathError> {
+		let mut result = Vec::new();
+
+		let segments = match self.file_location()? {
+			Location::Local(path) => {
+				let path = path.strip_prefix(b"/").unwrap_or(path);
+
+				if path.starts_with(b"/") {
+					// `file:////host/share` form.
+					let path = trim_leading_slashes(path);
+					let (host, rest) = split_first_segment(path);
+					if host.is_empty() {
+						return Err(FilePathError::MissingDrive);
+					}
+
+					result.extend_from_slice(b"\\\\");
+					decode_segment(host, &mut result, b'\\')?;
+					rest
+				} else {
+					let (first, rest) = split_first_segment(path);
+					match first {
+						[letter, b':'] | [letter, b'%', b'7', b'C' | b'c']
+							if letter.is_ascii_alphabetic() =>
+						{
+							result.extend_from_slice(&[*letter, b':']);
+							if rest.is_empty() {
+								result.push(b'\\')
+							}
+
+							rest
+						}
+						_ => return Err(FilePathError::MissingDrive),
+					}
+				}
+			}
+			Location::Remote(host, path) => {
+				result.extend_from_slice(b"\\\\");
+				decode_segment(host.as_bytes(), &mut result, b'\\')?;
+				path
+			}
+		};
+
+		if !segments.is_empty() {
+			for segment in segments[1..].split(|b| *b == b'/') {
+				result.push(b'\\');
+				decode_segment(segment, &mut result, b'\\')?;
+			}
+		}
+
+		String::from_utf8(result).map_err(|_| FilePathError::InvalidEncoding)
+	}
+
+	/// Checks the scheme and authority of this `file` URI.
+	fn file_location(&self) -> Result<Location<'_>, FilePathError> {
+		if *self.scheme() != *Scheme::FILE {
+			return Err(FilePathError::NotFile);
+		}
+
+		let path = self.path().as_bytes();
+		match self.authority() {
+			None => Ok(Location::Local(path)),
+			Some(authority) => {
+				let parts = authority.parts();
+				if parts.user_info.is_some() || parts.port.is_some() {
+					return Err(FilePathError::UnsupportedAuthority);
+				}
+
+				let host = parts.host.as_str();
+				if host.is_empty() || host.eq_ignore_ascii_case("localhost") {
+					Ok(Location::Local(path))
+				} else {
+					Ok(Location::Remote(host, path))
+				}
+			}
+		}
+	}
+}
+
+impl UriBuf {
+	/// Creates a `file` URI from an absolute path of the current platform.
+	///
+	/// On Unix platforms this is [`Self::from_posix_path`], on Windows
+	/// [`Self::from_windows_path`].
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// # #[cfg(unix)] {
+	/// use iref::UriBuf;
+	///
+	/// let uri = UriBuf::from_file_path("/home/user/my file.txt").unwrap();
+	/// assert_eq!(uri, "file:///home/user/my%20file.txt");
+	/// # }
+	/// ```
+	pub fn from_file_path(path: impl AsRef<std::path::Path>) -> Result<Self, FilePathError> {
+		let path = path.as_ref();
+
+		#[cfg(unix)]
+		{
+			use std::os::unix::ffi::OsStrExt;
+			Self::from_posix_path(path.as_os_str().as_bytes())
+		}
+
+		#[cfg(windows)]
+		{
+			let path = path.to_str().ok_or(FilePathError::InvalidEncoding)?;
+			Self::from_windows_path(path)
+		}
+
+		#[cfg(not(any(unix, windows)))]
+		{
+			let path = path.to_str().ok_or(FilePathError::InvalidEncoding)?;
+			Self::from_posix_path(path.as_bytes())
+		}
+	}
+
+	/// Creates a `file` URI from an absolute POSIX path.
+	///
+	/// Bytes that are not allowed in a path segment, including non-UTF-8
+	/// bytes, are percent-encoded.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::UriBuf;
+	///
+	/// let uri = UriBuf::from_posix_path(b"/tmp/50%/\xff").unwrap();
+	/// assert_eq!(uri.as_str(), "file:///tmp/50%25/%FF");
+	/// ```
+	pub fn from_posix_path(path: &[u8]) -> Result<Self, FilePathError> {
+		if !path.starts_with(b"/") {
+			return Err(FilePathError::RelativePath);
+		}
+
+		let mut result = String::with_capacity(7 + path.len());
+		result.push_str("file://");
+
+		for segment in path[1..].split(|b| *b == b'/') {
+			result.push('/');
+			encode_segment(segment, &mut result)?;
+		}
+
+		Ok(unsafe { Self::new_unchecked(result) })
+	}
+
+	/// Creates a `file` URI from an absolute Windows path.
+	///
+	/// Accepts drive paths (`C:\dir\file`), UNC paths
+	/// (`\\server\share\file`) and their `\\?\` verbatim forms. Both `\` and
+	/// `/` are treated as separators.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::UriBuf;
+	///
+	/// let uri = UriBuf::from_windows_path(r"C:\Program Files\app.exe").unwrap();
+	/// assert_eq!(uri, "file:///C:/Program%20Files/app.exe");
+	///
+	/// let uri = UriBuf::from_windows_path(r"\\server\share\notes.txt").unwrap();
+	/// assert_eq!(uri, "file://server/share/notes.txt");
+	/// ```
+	pub fn from_windows_path(path: &str) -> Result<Self, FilePathError> {
+		let mut result = String::with_capacity(8 + path.len());
+		result.push_str("file://");
+
+		let (unc, rest) = match path.strip_prefix(r"\\?\") {
+			Some(verbatim) => match verbatim.strip_prefix(r"UNC\") {
+				Some(rest) => (true, rest),
+				None => (false, verbatim),
+			},
+			None => match path.strip_prefix(r"\\").or_else(|| path.strip_prefix("//")) {
+				Some(rest) => (true, rest),
+				None => (false, path),
+			},
+		};
+
+		let rest = if unc {
+			let (host, rest) = rest.split_once(['\\', '/']).unwrap_or((rest, ""));
+			if host.is_empty() || host == "." || host == "?" {
+				return Err(FilePathError::MissingDrive);
+			}
+
+			for c in host.chars() {
+				if c == '%' || c == '@' || pct_str::UriReserved::Path.encode(c) {
+					push_pct_encoded(c, &mut result)
+				} else {
+					result.push(c)
+				}
+			}
+
+			rest
+		} else {
+			match rest.as_bytes() {
+				[letter, b':', b'\\' | b'/', ..] if letter.is_ascii_alphabetic() => {
+					result.push('/');
+					result.push_str(&rest[..2]);
+					&rest[3..]
+				}
+				[letter, b':'] if letter.is_ascii_alphabetic() => {
+					result.push('/');
+					result.push_str(rest);
+					return Ok(unsafe { Self::new_unchecked(result) });
+				}
+				[letter, b':', ..] if letter.is_ascii_alphabetic() => {
+					return Err(FilePathError::RelativePath);
+				}
+				[b'\\' | b'/', ..] => return Err(FilePathError::MissingDrive),
+				_ => return Err(FilePathError::RelativePath),
+			}
+		};
+
+		for segment in rest.split(['\\', '/']) {
+			result.push('/');
+			encode_segment(segment.as_bytes(), &mut result)?;
+		}
+
+		Ok(unsafe { Self::new_unchecked(result) })
+	}
+}
+
+fn trim_leading_slashes(mut path: &[u8]) -> &[u8] {
+	while let Some(rest) = path.strip_prefix(b"/") {
+		path = rest
+	}
+
+	path
+}
+
+/// Splits a path into its first segment and the rest, starting with `/`.
+fn split_first_segment(path: &[u8]) -> (&[u8], &[u8]) {
+	match path.iter().position(|b| *b == b'/') {
+		Some(i) => (&path[..i], &path[i..]),
+		None => (path, &[]),
+	}
+}
+
+/// Percent-decodes a path segment, rejecting NUL bytes and decoded
+/// separators.
+fn decode_segment(
+	segment: &[u8],
+	output: &mut Vec<u8>,
+	separator: u8,
+) -> Result<(), FilePathError> {
+	let segment = unsafe { pct_str::PctStr::new_unchecked(segment) };
+	for b in segment.bytes() {
+		if b == 0 || b == b'/' || b == separator {
+			return Err(FilePathError::InvalidCharacter);
+		}
+
+		output.push(b)
+	}
+
+	Ok(())
+}
+
+/// Percent-encodes a path segment.
+fn encode_segment(segment: &[u8], output: &mut String) -> Result<(), FilePathError> {
+	for chunk in segment.utf8_chunks() {
+		for c in chunk.valid().chars() {
+			if c == '\0' {
+				return Err(FilePathError::InvalidCharacter);
+			}
+
+			if c == '%' || pct_str::UriReserved::Path.encode(c) {
+				push_pct_encoded(c, output)
+			} else {
+				output.push(c)
+			}
+		}
+
+		for b in chunk.invalid() {
+			push_pct_encoded_byte(*b, output)
+		}
+	}
+
+	Ok(())
+}
+
+fn push_pct_encoded(c: char, output: &mut String) {
+	let mut buffer = [0; 4];
+	for b in c.encode_utf8(&mut buffer).bytes() {
+		push_pct_encoded_byte(b, output)
+	}
+}
+
+fn push_pct_encoded_byte(b: u8, output: &mut String) {
+	const HEX: &[u8; 16] = b"0123456789ABCDEF";
+	output.push('%');
+	output.push(HEX[(b >> 4) as usize] as char);
+	output.push(HEX[(b & 0x0f) as usize] as char);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn to_posix_path() {
+		let vectors: [(&str, &[u8]); 6] = [
+			("file:///", b"/"),
+			("file:/etc/hosts", b"/etc/hosts"),
+			("file:///home/user/", b"/home/user/"),
+			("file://localhost/a%20b", b"/a b"),
+			("file://LOCALHOST/a/b?q#f", b"/a/b"),
+			("file:///a/%C3%A9/%FF", b"/a/\xc3\xa9/\xff"),
+		];
+
+		for (input, expected) in vectors {
+			let uri = Uri::new(input).unwrap();
+			assert_eq!(uri.to_posix_path().unwrap(), expected, "{input}")
+		}
+	}
+
+	#[test]
+	fn to_posix_path_errors() {
+		let vectors = [
+			("http:///a", FilePathError::NotFile),
+			(
+				"file://host/a",
+				FilePathError::NonLocalHost("host".to_owned()),
+			),
+			("file://u@localhost/a", FilePathError::UnsupportedAuthority),
+			("file:a/b", FilePathError::RelativePath),
+			("file:///a%2Fb", FilePathError::InvalidCharacter),
+			("file:///a%00", FilePathError::InvalidCharacter),
+		];
+
+		for (input, expected) in vectors {
+			let uri = Uri::new(input).unwrap();
+			assert_eq!(uri.to_posix_path(), Err(expected), "{input}")
+		}
+	}
+
+	#[test]
+	fn to_windows_path() {
+		let vectors = [
+			("file:///C:/", r"C:\"),
+			("file:///C:", r"C:\"),
+			("file:///c:/path/to/file", r"c:\path\to\file"),
+			("file:/C:/path", r"C:\path"),
+			("file:C:/path", r"C:\path"),
+			("file:///C%7C/path", r"C:\path"),
+			("file://localhost/D:/a%20b/", r"D:\a b\"),
+			(
+				"file://host.example.com/share/file",
+				r"\\host.example.com\share\file",
+			),
+			(
+				"file:////host.example.com/share/file",
+				r"\\host.example.com\share\file",
+			),
+			(
+				"file://///host.example.com/share/file",
+				r"\\host.example.com\share\file",
+			),
+			("file://server", r"\\server"),
+		];
+
+		for (input, expected) in vectors {
+			let uri = Uri::new(input).unwrap();
+			assert_eq!(uri.to_windows_path().unwrap(), expected, "{input}")
+		}
+	}
+
+	#[test]
+	fn to_windows_path_errors() {
+		let vectors = [
+			("file:///path", FilePathError::MissingDrive),
+			("file:///C:/a%5Cb", FilePathError::InvalidCharacter),
+			("file:///C:/%FF", FilePathError::InvalidEncoding),
+		];
+
+		for (input, expected) in vectors {
+			let uri = Uri::new(input).unwrap();
+			assert_eq!(uri.to_windows_path(), Err(expected), "{input}")
+		}
+	}
+
+	#[test]
+	fn from_posix_path() {
+		let vectors: [(&[u8], &str); 6] = [
+			(b"/", "file:///"),
+			(b"/etc/hosts", "file:///etc/hosts"),
+			(b"/a b/c#d?e", "file:///a%20b/c%23d%3Fe"),
+			(b"/dir/", "file:///dir/"),
+			(b"//host/share", "file:////host/share"),
+			(b"/tmp/\xfe\xff", "file:///tmp/%FE%FF"),
+		];
+
+		for (input, expected) in vectors {
+			let uri = UriBuf::from_posix_path(input).unwrap();
+			assert_eq!(uri.as_str(), expected);
+			assert_eq!(uri.to_posix_path().unwrap(), input)
+		}
+
+		assert_eq!(
+			UriBuf::from_posix_path(b"a/b"),
+			Err(FilePathError::RelativePath)
+		);
+		assert_eq!(
+			UriBuf::from_posix_path(b"/a\0"),
+			Err(FilePathError::InvalidCharacter)
+		);
+	}
+
+	#[test]
+	fn from_windows_path() {
+		let vectors = [
+			(r"C:\", "file:///C:/", r"C:\"),
+			(r"C:", "file:///C:", r"C:\"),
+			(
+				r"C:\Users\me\a b.txt",
+				"file:///C:/Users/me/a%20b.txt",
+				r"C:\Users\me\a b.txt",
+			),
+			("C:/Users/me", "file:///C:/Users/me", r"C:\Users\me"),
+			(
+				r"\\server\share\x",
+				"file://server/share/x",
+				r"\\server\share\x",
+			),
+			(r"\\?\C:\x", "file:///C:/x", r"C:\x"),
+			(
+				r"\\?\UNC\server\share",
+				"file://server/share",
+				r"\\server\share",
+			),
+		];
+
+		for (input, expected, back) in vectors {
+			let uri = UriBuf::from_windows_path(input).unwrap();
+			assert_eq!(uri.as_str(), expected);
+			assert_eq!(uri.to_windows_path().unwrap(), back)
+		}
+
+		assert_eq!(
+			UriBuf::from_windows_path(r"C:foo"),
+			Err(FilePathError::RelativePath)
+		);
+		assert_eq!(
+			UriBuf::from_windows_path(r"\foo"),
+			Err(FilePathError::MissingDrive)
+		);
+		assert_eq!(
+			UriBuf::from_windows_path(r"foo\bar"),
+			Err(FilePathError::RelativePath)
+		);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn file_path_round_trip() {
+		use std::os::unix::ffi::OsStrExt;
+
+		let path = std::path::Path::new(std::ffi::OsStr::from_bytes(b"/tmp/\xfe\xff/x y"));
+		let uri = UriBuf::from_file_path(path).unwrap();
+		assert_eq!(uri.as_str(), "file:///tmp/%FE%FF/x%20y");
+		assert_eq!(uri.to_file_path().unwrap(), path)
+	}
+}
@@ -0,0 +1,364 @@
+use core::fmt;
+
+use pct_str::Encoder;
+
+use super::{Uri, UriBuf};
+
+/// URI component in which a value is interpolated.
+///
+/// The component decides which characters of the value are
+/// percent-encoded.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatComponent {
+	/// User info. Only unreserved characters and sub-delimiters are kept.
+	UserInfo,
+
+	/// Host. Only unreserved characters and sub-delimiters are kept.
+	Host,
+
+	/// Path segment. `/` is encoded, so the value stays in one segment.
+	Segment,
+
+	/// Query. `&`, `=` and `+` are encoded, so the value stays in one
+	/// key or value.
+	Query,
+
+	/// Fragment.
+	Fragment,
+}
+
+impl Encoder for FormatComponent {
+	fn encode(&self, c: char) -> bool {
+		match self {
+			Self::UserInfo | Self::Host => c == '@' || pct_str::UriReserved::Path.encode(c),
+			Self::Segment => pct_str::UriReserved::Path.encode(c),
+			Self::Query => matches!(c, '&' | '=' | '+') || pct_str::UriReserved::Query.encode(c),
+			Self::Fragment => pct_str::UriReserved::Fragment.encode(c),
+		}
+	}
+}
+
+/// Returns the length of the given string once percent-encoded.
+pub(crate) fn encoded_len(s: &str, encoder: &impl Encoder) -> usize {
+	s.chars()
+		.map(|c| {
+			if c == '%' || encoder.encode(c) {
+				3 * c.len_utf8()
+			} else {
+				c.len_utf8()
+			}
+		})
+		.sum()
+}
+
+/// Percent-encodes the given string into `output`.
+pub(crate) fn encode_into(s: &str, encoder: &impl Encoder, output: &mut String) {
+	const HEX: &[u8; 16] = b"0123456789ABCDEF";
+	for c in s.chars() {
+		if c == '%' || encoder.encode(c) {
+			let mut buffer = [0; 4];
+			for b in c.encode_utf8(&mut buffer).bytes() {
+				output.push('%');
+				output.push(HEX[(b >> 4) as usize] as char);
+				output.push(HEX[(b & 0x0f) as usize] as char);
+			}
+		} else {
+			output.push(c)
+		}
+	}
+}
+
+/// Formatter percent-encoding everything written into it.
+struct Escaper<'a> {
+	output: &'a mut String,
+	component: FormatComponent,
+}
+
+impl fmt::Write for Escaper<'_> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		encode_into(s, &self.component, self.output);
+		Ok(())
+	}
+}
+
+/// Returns the number of `{}` placeholders in the given template.
+#[doc(hidden)]
+pub const fn format_placeholders(template: &str) -> usize {
+	let bytes = template.as_bytes();
+	let mut count = 0;
+	let mut i = 0;
+	while i + 1 < bytes.len() {
+		if bytes[i] == b'{' && bytes[i + 1] == b'}' {
+			count += 1;
+			i += 2
+		} else {
+			i += 1
+		}
+	}
+
+	count
+}
+
+/// URI template, analyzed at compile time by the
+/// [`uri_format!`](crate::uri_format) macro.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct FormatTemplate<const N: usize> {
+	template: &'static str,
+
+	/// Component of each placeholder.
+	components: [FormatComponent; N],
+
+	/// Start of the path, if the template has no authority.
+	path_start: Option<usize>,
+}
+
+impl<const N: usize> FormatTemplate<N> {
+	/// Analyzes the given template, where `L` is the template length.
+	///
+	/// # Panics
+	///
+	/// Panics if the template is not a valid URI (with placeholders) or if
+	/// it has a placeholder in the scheme, port or IP literal. Meant to be
+	/// called in a const context, where panics are compilation errors.
+	pub const fn new<const L: usize>(template: &'static str) -> Self {
+		let bytes = template.as_bytes();
+
+		// Check the template, with each placeholder replaced by `x`.
+		let mut buffer = [0u8; L];
+		let mut len = 0;
+		let mut i = 0;
+		while i < bytes.len() {
+			match bytes[i] {
+				b'{' if i + 1 < bytes.len() && bytes[i + 1] == b'}' => {
+					buffer[len] = b'x';
+					i += 2
+				}
+				b'{' | b'}' => panic!("invalid URI template: unexpected brace"),
+				b => {
+					buffer[len] = b;
+					i += 1
+				}
+			}
+
+			len += 1
+		}
+
+		if !Uri::validate_bytes(buffer.split_at(len).0) {
+			panic!("invalid URI template")
+		}
+
+		// Find the component of each placeholder.
+		let mut components = [FormatComponent::Segment; N];
+		let mut n = 0;
+
+		let mut i = 0;
+		while bytes[i] != b':' {
+			if bytes[i] == b'{' {
+				panic!("invalid URI template: placeholder in scheme")
+			}
+
+			i += 1
+		}
+
+		i += 1;
+		let mut path_start = Some(i);
+		if i + 1 < bytes.len() && bytes[i] == b'/' && bytes[i + 1] == b'/' {
+			i += 2;
+			let mut end = i;
+			let mut user_info_end = None;
+			while end < bytes.len() && !matches!(bytes[end], b'/' | b'?' | b'#') {
+				if bytes[end] == b'@' {
+					user_info_end = Some(end)
+				}
+
+				end += 1
+			}
+
+			if let Some(user_info_end) = user_info_end {
+				while i < user_info_end {
+					if bytes[i] == b'{' {
+						components[n] = FormatComponent::UserInfo;
+						n += 1
+					}
+
+					i += 1
+				}
+
+				i += 1
+			}
+
+			if i < end && bytes[i] == b'[' {
+				while bytes[i] != b']' {
+					if bytes[i] == b'{' {
+						panic!("invalid URI template: placeholder in IP literal")
+					}
+
+					i += 1
+				}
+			}
+
+			while i < end && bytes[i] != b':' {
+				if bytes[i] == b'{' {
+					components[n] = FormatComponent::Host;
+					n += 1
+				}
+
+				i += 1
+			}
+
+			while i < end {
+				if bytes[i] == b'{' {
+					panic!("invalid URI template: placeholder in port")
+				}
+
+				i += 1
+			}
+
+			path_start = None
+		}
+
+		let mut component = FormatComponent::Segment;
+		while i < bytes.len() {
+			match bytes[i] {
+				b'?' if matches!(component, FormatComponent::Segment) => {
+					component = FormatComponent::Query
+				}
+				b'#' => component = FormatComponent::Fragment,
+				b'{' => {
+					components[n] = component;
+					n += 1
+				}
+				_ => (),
+			}
+
+			i += 1
+		}
+
+		if n != N {
+			panic!("invalid URI template: placeholder count mismatch")
+		}
+
+		Self {
+			template,
+			components,
+			path_start,
+		}
+	}
+
+	/// Formats the given arguments.
+	pub fn format(&self, args: [&dyn fmt::Display; N]) -> UriBuf {
+		use fmt::Write;
+		let mut result = String::with_capacity(self.template.len());
+
+		let mut pieces = self.template.split("{}");
+		result.push_str(pieces.next().unwrap());
+		for ((piece, arg), component) in pieces.zip(args).zip(self.components) {
+			let mut escaper = Escaper {
+				output: &mut result,
+				component,
+			};
+
+			write!(escaper, "{arg}").unwrap();
+			result.push_str(piece)
+		}
+
+		if let Some(start) = self.path_start
+			&& result[start..].starts_with("//")
+		{
+			// AMBIGUITY: an empty segment would make the path look like an
+			//            authority.
+			result.insert_str(start, "/.");
+		}
+
+		debug_assert!(Uri::validate_str(&result));
+		unsafe { UriBuf::new_unchecked(result) }
+	}
+}
+
+/// Formats a URI, percent-encoding the interpolated values.
+///
+/// The template is a string literal where `{}` placeholders stand for
+/// values implementing [`Display`](core::fmt::Display). It is checked at
+/// compile time: it must be a valid URI once the placeholders are
+/// substituted, without placeholder in the scheme, port or IP literal.
+///
+/// Each value is percent-encoded according to the component its
+/// placeholder lands in: a value in a path segment cannot add segments, and
+/// a value in the query cannot add key-value pairs. The output is always a
+/// valid URI. Note that values such as `.` or `..` are dot segments when
+/// interpolated as a whole path segment.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::uri_format;
+///
+/// let id = 42;
+/// let tag = "rust & co#1";
+/// let uri = uri_format!("https://example.org/users/{}/posts?tag={}", id, tag);
+/// assert_eq!(uri, "https://example.org/users/42/posts?tag=rust%20%26%20co%231");
+/// ```
+///
+/// Invalid templates are rejected at compile time:
+///
+/// ```compile_fail
+/// let uri = iref::uri_format!("https://example.org:{}/", 8080);
+/// ```
+#[macro_export]
+#[cfg(feature = "std")]
+macro_rules! uri_format {
+	($template:literal $(, $arg:expr)* $(,)?) => {{
+		const TEMPLATE: &str = $template;
+		const N: usize = $crate::uri::format_placeholders(TEMPLATE);
+		const FORMAT: $crate::uri::FormatTemplate<N> =
+			$crate::uri::FormatTemplate::new::<{ TEMPLATE.len() }>(TEMPLATE);
+		FORMAT.format([$(&$arg as &dyn ::core::fmt::Display),*])
+	}};
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn format() {
+		let vectors = [
+			(
+				uri_format!("http://{}@{}.example.org/", "user:pw", "a b"),
+				"http://user%3Apw@a%20b.example.org/",
+			),
+			(
+				uri_format!("http://[::1]:8080/{}/{}", "a/b", "?#"),
+				"http://[::1]:8080/a%2Fb/%3F%23",
+			),
+			(
+				uri_format!("http://h/?a={}&b={}#{}", "1&c=2", "x+y", "f#g"),
+				"http://h/?a=1%26c%3D2&b=x%2By#f%23g",
+			),
+			(uri_format!("s:{}", ""), "s:"),
+			(uri_format!("s:/{}/{}", "", "b"), "s:/.//b"),
+			(uri_format!("s:{}/{}", "", "b"), "s:/b"),
+			(uri_format!("s:{}:{}", "a:b", 1), "s:a%3Ab:1"),
+			(uri_format!("s:x?{}", "?/:@"), "s:x??/:@"),
+			(uri_format!("s:x#{}", "100%"), "s:x#100%25"),
+			(uri_format!("http://example.org/"), "http://example.org/"),
+		];
+
+		for (uri, expected) in vectors {
+			assert_eq!(uri.as_str(), expected)
+		}
+	}
+
+	#[test]
+	fn components() {
+		use super::{FormatComponent::*, FormatTemplate};
+
+		const TEMPLATE: &str = "s://{}@{}:1/{}/a?{}#{}?{}";
+		let t = FormatTemplate::<6>::new::<{ TEMPLATE.len() }>(TEMPLATE);
+		assert_eq!(
+			t.components,
+			[UserInfo, Host, Segment, Query, Fragment, Fragment]
+		);
+		assert_eq!(t.path_start, None);
+	}
+}
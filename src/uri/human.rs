@@ -0,0 +1,188 @@
+//! Human-readable presentation ([RFC 3987 section 3.2]).
+//!
+//! [RFC 3987 section 3.2]: <https://datatracker.ietf.org/doc/html/rfc3987#section-3.2>
+use core::fmt;
+
+use crate::common::{is_bidi_control, is_delimiter_lookalike, is_invisible, is_ucschar};
+
+use super::UriRef;
+
+/// Checks if the given non-ASCII character can be displayed as is.
+fn is_displayable(c: char) -> bool {
+	is_ucschar(c)
+		&& !is_bidi_control(c)
+		&& !is_invisible(c)
+		&& !c.is_whitespace()
+		&& !is_delimiter_lookalike(c)
+}
+
+/// Parses the two hexadecimal digits following a `%` at the start of the
+/// given bytes.
+fn pct_encoded_byte(bytes: &[u8]) -> Option<u8> {
+	match bytes {
+		[b'%', h, l, ..] => {
+			Some(((*h as char).to_digit(16)? * 16 + (*l as char).to_digit(16)?) as u8)
+		}
+		_ => None,
+	}
+}
+
+/// Decodes the percent-encoded UTF-8 encoding of a non-ASCII character at
+/// the start of the given bytes.
+///
+/// Returns the character and the length of its percent-encoded form.
+fn decode_pct_char(bytes: &[u8]) -> Option<(char, usize)> {
+	let first = pct_encoded_byte(bytes)?;
+	let len = match first {
+		0xc2..=0xdf => 2,
+		0xe0..=0xef => 3,
+		0xf0..=0xf4 => 4,
+		_ => return None,
+	};
+
+	let mut buffer = [first, 0, 0, 0];
+	for (i, b) in buffer.iter_mut().enumerate().take(len).skip(1) {
+		*b = pct_encoded_byte(bytes.get((3 * i)..)?)?
+	}
+
+	let c = core::str::from_utf8(&buffer[..len]).ok()?.chars().next()?;
+	Some((c, 3 * len))
+}
+
+impl UriRef {
+	/// Returns a value displaying this URI reference in a form suitable for
+	/// humans, as described by [RFC 3987 section 3.2].
+	///
+	/// Percent-encoded UTF-8 sequences are decoded when the resulting
+	/// character is safe to display. The following stay percent-encoded:
+	/// - ASCII characters, including spaces and delimiters;
+	/// - bidirectional formatting characters, and invisible or format
+	///   characters;
+	/// - white spaces;
+	/// - characters looking like ASCII delimiters, or normalized into one by
+	///   web browsers (such as `／` or `。`);
+	/// - characters that cannot appear in an IRI, and invalid UTF-8
+	///   sequences.
+	///
+	/// Such characters appearing unencoded (in an IRI) are percent-encoded.
+	/// The output is a valid IRI reference, with the same components,
+	/// converting back to the original URI reference (up to the case of
+	/// percent-encoded bytes).
+	///
+	/// [RFC 3987 section 3.2]: <https://datatracker.ietf.org/doc/html/rfc3987#section-3.2>
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::Uri;
+	///
+	/// let uri = Uri::new("https://example.org/caf%C3%A9/a%20b%2Fc?q=%E2%80%AE#%EF%BC%8F").unwrap();
+	/// assert_eq!(
+	///     uri.display_human().to_string(),
+	///     "https://example.org/café/a%20b%2Fc?q=%E2%80%AE#%EF%BC%8F"
+	/// );
+	/// ```
+	pub fn display_human(&self) -> DisplayHuman<'_> {
+		DisplayHuman(self)
+	}
+}
+
+/// URI reference displayed in a form suitable for humans.
+///
+/// See [`UriRef::display_human`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayHuman<'a>(&'a UriRef);
+
+impl fmt::Display for DisplayHuman<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let s = self.0.as_str();
+		let bytes = s.as_bytes();
+
+		let mut i = 0;
+		while i < s.len() {
+			if bytes[i] == b'%' {
+				match decode_pct_char(&bytes[i..]) {
+					Some((c, len)) if is_displayable(c) => {
+						fmt::Write::write_char(f, c)?;
+						i += len
+					}
+					_ => {
+						f.write_str(&s[i..(i + 3)])?;
+						i += 3
+					}
+				}
+			} else {
+				let c = s[i..].chars().next().unwrap();
+				if c.is_ascii() || is_displayable(c) {
+					fmt::Write::write_char(f, c)?
+				} else {
+					for b in c.encode_utf8(&mut [0; 4]).bytes() {
+						write!(f, "%{b:02X}")?
+					}
+				}
+
+				i += c.len_utf8()
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn display_human() {
+		let vectors = [
+			("", ""),
+			("https://example.org/a?b#c", "https://example.org/a?b#c"),
+			("/caf%C3%A9", "/café"),
+			("/caf%c3%a9", "/café"),
+			("//%E9%A3%9F%E7%8B%AE.cn/", "//食狮.cn/"),
+			("/%F0%9F%A6%80", "/🦀"),
+			("/%41%2F%3F%23%25%20", "/%41%2F%3F%23%25%20"),
+			("/%C3", "/%C3"),
+			("/%C3%28", "/%C3%28"),
+			("/%E2%82", "/%E2%82"),
+			("/%C0%AF", "/%C0%AF"),
+			("/%ED%A0%80", "/%ED%A0%80"),
+			("/%EF%BF%BF", "/%EF%BF%BF"),
+			("/%E2%80%AEexe", "/%E2%80%AEexe"),
+			("/a%E2%80%8Bb", "/a%E2%80%8Bb"),
+			("/a%C2%A0b%E3%80%80c", "/a%C2%A0b%E3%80%80c"),
+			(
+				"//evil.com%EF%BC%8Fexample.org",
+				"//evil.com%EF%BC%8Fexample.org",
+			),
+			("//a%E3%80%82b", "//a%E3%80%82b"),
+			("/%E2%88%95", "/%E2%88%95"),
+			("?%EE%80%80", "?%EE%80%80"),
+		];
+
+		for (input, expected) in vectors {
+			let uri_ref = UriRef::new(input).unwrap();
+			assert_eq!(uri_ref.display_human().to_string(), expected, "{input}")
+		}
+	}
+
+	#[test]
+	fn display_human_iri() {
+		use crate::IriRef;
+
+		let vectors = [
+			("/café/%C3%A9", "/café/é"),
+			("/a\u{202E}b", "/a%E2%80%AEb"),
+			("/a\u{3000}b\u{FF0F}c", "/a%E3%80%80b%EF%BC%8Fc"),
+			("//食狮。cn", "//食狮%E3%80%82cn"),
+		];
+
+		for (input, expected) in vectors {
+			let iri_ref = IriRef::new(input).unwrap();
+			let human = iri_ref.display_human().to_string();
+			assert_eq!(human, expected, "{input}");
+			assert!(IriRef::new(&human).is_ok())
+		}
+	}
+}
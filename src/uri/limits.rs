@@ -0,0 +1,148 @@
+use crate::{LimitError, LimitedParseError, Limits};
+
+use super::{InvalidUri, InvalidUriRef, Uri, UriRef};
+
+impl Limits {
+	/// Checks that the given URI reference does not exceed these limits.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::{LimitError, Limits, UriRef};
+	///
+	/// let limits = Limits {
+	///     max_query_pairs: 2,
+	///     ..Limits::default()
+	/// };
+	///
+	/// assert_eq!(limits.check_uri_ref(UriRef::new("?a=1&b=2").unwrap()), Ok(()));
+	/// assert_eq!(
+	///     limits.check_uri_ref(UriRef::new("?a=1&b=2&c=3").unwrap()),
+	///     Err(LimitError::QueryPairs(2))
+	/// );
+	/// ```
+	pub fn check_uri_ref(&self, uri_ref: &UriRef) -> Result<(), LimitError> {
+		self.check_len(uri_ref.as_str().len())?;
+
+		if let Some(authority) = uri_ref.authority() {
+			self.check_host_len(authority.host().as_str().len())?
+		}
+
+		self.check_segments(uri_ref.path().segment_count())?;
+
+		if let Some(query) = uri_ref.query() {
+			self.check_query_pairs(query.as_str())?
+		}
+
+		Ok(())
+	}
+}
+
+impl Uri {
+	/// Parses the given URI, enforcing the given limits.
+	///
+	/// The input length is checked before parsing.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::{LimitError, LimitedParseError, Limits, Uri};
+	///
+	/// let limits = Limits {
+	///     max_len: 32,
+	///     ..Limits::default()
+	/// };
+	///
+	/// assert!(Uri::new_with_limits("https://example.org/", &limits).is_ok());
+	/// assert!(matches!(
+	///     Uri::new_with_limits("https://example.org/a/very/long/path", &limits),
+	///     Err(LimitedParseError::Limit(LimitError::Length(32)))
+	/// ));
+	/// ```
+	pub fn new_with_limits<'a, T: ?Sized + AsRef<[u8]>>(
+		input: &'a T,
+		limits: &Limits,
+	) -> Result<&'a Self, LimitedParseError<InvalidUri<&'a T>>> {
+		limits
+			.check_len(input.as_ref().len())
+			.map_err(LimitedParseError::Limit)?;
+		let result = Self::new(input).map_err(LimitedParseError::Invalid)?;
+		limits
+			.check_uri_ref(result)
+			.map_err(LimitedParseError::Limit)?;
+		Ok(result)
+	}
+}
+
+impl UriRef {
+	/// Parses the given URI reference, enforcing the given limits.
+	///
+	/// The input length is checked before parsing.
+	pub fn new_with_limits<'a, T: ?Sized + AsRef<[u8]>>(
+		input: &'a T,
+		limits: &Limits,
+	) -> Result<&'a Self, LimitedParseError<InvalidUriRef<&'a T>>> {
+		limits
+			.check_len(input.as_ref().len())
+			.map_err(LimitedParseError::Limit)?;
+		let result = Self::new(input).map_err(LimitedParseError::Invalid)?;
+		limits
+			.check_uri_ref(result)
+			.map_err(LimitedParseError::Limit)?;
+		Ok(result)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn limits() {
+		let limits = Limits {
+			max_len: 64,
+			max_segments: 3,
+			max_query_pairs: 2,
+			max_host_len: 11,
+		};
+
+		let vectors = [
+			("", Ok(())),
+			("https://example.org/a/b/c?a&b#f", Ok(())),
+			("//example.org/a/b/c/", Err(LimitError::Segments(3))),
+			("/a/b/c/d", Err(LimitError::Segments(3))),
+			("a/b/c", Ok(())),
+			("?a=1&b=2&", Err(LimitError::QueryPairs(2))),
+			("?a=1;b=2;c=3", Ok(())),
+			("//example.com", Ok(())),
+			("//www.example.com", Err(LimitError::HostLength(11))),
+			("//user@[::1]:8080", Ok(())),
+			(
+				"/aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+				Err(LimitError::Length(64)),
+			),
+		];
+
+		for (input, expected) in vectors {
+			assert_eq!(
+				UriRef::new_with_limits(input, &limits)
+					.map(|_| ())
+					.map_err(|e| match e {
+						LimitedParseError::Limit(e) => Some(e),
+						LimitedParseError::Invalid(_) => None,
+					}),
+				expected.map_err(Some),
+				"{input}"
+			)
+		}
+
+		assert!(matches!(
+			Uri::new_with_limits("/a", &limits),
+			Err(LimitedParseError::Invalid(_))
+		));
+		assert!(matches!(
+			UriRef::new_with_limits("a b", &limits),
+			Err(LimitedParseError::Invalid(_))
+		))
+	}
+}
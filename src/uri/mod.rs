@@ -11,18 +11,66 @@ pub use crate::{InvalidScheme, Scheme};
 pub use crate::SchemeBuf;
 
 mod authority;
+#[cfg(feature = "std")]
+mod builder;
+mod consts;
+mod digest;
 mod error;
+mod exact;
+mod fast;
+#[cfg(feature = "std")]
+mod file;
+#[cfg(feature = "std")]
+mod format;
 mod fragment;
+mod human;
+mod limits;
+mod ntriples;
+#[cfg(feature = "std")]
+mod origin;
+#[cfg(feature = "std")]
+mod owned;
+#[cfg(feature = "std")]
+mod parsed;
 mod path;
 mod query;
+mod redact;
 mod reference;
+#[cfg(feature = "psl")]
+mod site;
+#[cfg(feature = "std")]
+mod target;
+mod tel;
+mod vocabulary;
 
 pub use authority::*;
+#[cfg(feature = "std")]
+pub use builder::*;
+pub use consts::*;
 pub use error::*;
+#[cfg(feature = "std")]
+pub use exact::*;
+#[cfg(feature = "std")]
+pub use file::*;
+#[cfg(feature = "std")]
+pub use format::*;
 pub use fragment::*;
+pub use human::*;
+pub use ntriples::*;
+#[cfg(feature = "std")]
+pub use origin::*;
+#[cfg(feature = "std")]
+pub use owned::*;
+#[cfg(feature = "std")]
+pub use parsed::*;
 pub use path::*;
 pub use query::*;
+pub use redact::*;
 pub use reference::*;
+#[cfg(feature = "psl")]
+pub use site::*;
+pub use tel::*;
+pub use vocabulary::*;
 
 #[grammar(
 	file = "grammar.abnf",
@@ -47,17 +95,30 @@ pub(crate) mod grammar {}
 /// #
 /// # Ok(())
 /// # }
-#[derive(static_automata::Validate, str_newtype::StrNewType)]
-#[automaton(grammar::Uri)]
+#[derive(iref_newtype_derive::StrNewType)]
 #[newtype(name = "URI", no_deref, ord([u8], &[u8], str, &str))]
 #[cfg_attr(
 	feature = "std",
 	newtype(ord(Vec<u8>, String), owned(UriBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))
 )]
 #[cfg_attr(feature = "serde", newtype(serde))]
+#[cfg_attr(feature = "redact-debug", newtype(no_debug))]
 pub struct Uri(str);
 
 impl Uri {
+	/// Checks that the given string is a valid URI.
+	pub const fn validate_str(input: &str) -> bool {
+		self::fast::validate_uri(input.as_bytes()) || grammar::Uri::validate_str(input)
+	}
+
+	/// Checks that the given bytes are a valid URI.
+	///
+	/// Long ASCII inputs are validated using a fast path, falling back to
+	/// the grammar automaton when needed.
+	pub const fn validate_bytes(input: &[u8]) -> bool {
+		self::fast::validate_uri(input) || grammar::Uri::validate_bytes(input)
+	}
+
 	/// Returns all the parts of this URI.
 	///
 	/// This method parses the URI and returns a [`UriParts`] struct containing
@@ -352,7 +413,7 @@ impl PartialOrd<UriRefBuf> for Uri {
 ///
 /// Contains references to each component of a URI as defined
 /// in RFC 3986: scheme, authority, path, query, and fragment.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct UriParts<'a> {
 	/// Scheme component (e.g., `https`, `http`, `file`).
 	pub scheme: &'a Scheme,
@@ -859,6 +920,12 @@ impl UriBuf {
 	}
 }
 
+impl AsRef<UriRef> for Uri {
+	fn as_ref(&self) -> &UriRef {
+		self.as_uri_ref()
+	}
+}
+
 #[cfg(feature = "std")]
 impl AsRef<UriRef> for UriBuf {
 	fn as_ref(&self) -> &UriRef {
@@ -0,0 +1,253 @@
+//! N-Triples IRI references.
+//!
+//! [N-Triples], Turtle and SPARQL write IRI references between angle
+//! brackets, where any character can be escaped as `\uXXXX` or
+//! `\UXXXXXXXX`.
+//!
+//! [N-Triples]: <https://www.w3.org/TR/n-triples/#grammar-production-IRIREF>
+use core::fmt;
+
+use super::UriRef;
+
+#[cfg(feature = "std")]
+use super::{Query, UriRefBuf, grammar};
+
+/// Checks if the given character cannot appear unescaped between the angle
+/// brackets of an N-Triples IRI reference.
+fn is_forbidden(c: char) -> bool {
+	matches!(
+		c,
+		'\0'..=' ' | '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\'
+	)
+}
+
+/// Checks if the given character may appear in a URI reference.
+#[cfg(feature = "std")]
+fn is_allowed(c: char) -> bool {
+	matches!(c, '#' | '[' | ']' | '%') || Query::validate_str(c.encode_utf8(&mut [0; 4]))
+}
+
+/// Error returned by [`UriRefBuf::from_ntriples_escaped`].
+///
+/// Offsets are byte offsets in the escaped input.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum NTriplesError {
+	/// Invalid or unknown escape sequence.
+	#[error("invalid escape sequence at offset {0}")]
+	InvalidEscape(usize),
+
+	/// Character (possibly escaped) that cannot appear in a URI reference.
+	#[error("unexpected character at offset {0}")]
+	UnexpectedChar(usize),
+
+	/// The unescaped value (second field) is not a valid URI reference.
+	///
+	/// The offset is the one of the first character, possibly escaped, at
+	/// which the value stops being a valid URI reference prefix, or the
+	/// length of the input if the value is an incomplete URI reference.
+	#[error("invalid URI reference `{1}` at offset {0}")]
+	InvalidUriRef(usize, String),
+}
+
+/// Decodes the hexadecimal code point of an escape sequence.
+#[cfg(feature = "std")]
+fn decode_hex(digits: &str) -> Option<char> {
+	if digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+		u32::from_str_radix(digits, 16)
+			.ok()
+			.and_then(char::from_u32)
+	} else {
+		None
+	}
+}
+
+#[cfg(feature = "std")]
+impl UriRefBuf {
+	/// Parses an N-Triples escaped URI reference.
+	///
+	/// The input is the content of an N-Triples `IRIREF`, without the
+	/// surrounding angle brackets. Its `\uXXXX` and `\UXXXXXXXX` escape
+	/// sequences are decoded, and the result is validated. Error offsets
+	/// refer to the escaped input.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::{UriRefBuf, uri::NTriplesError};
+	///
+	/// let uri_ref = UriRefBuf::from_ntriples_escaped(r"http://example.org/\u0061?q").unwrap();
+	/// assert_eq!(uri_ref, "http://example.org/a?q");
+	///
+	/// assert_eq!(
+	///     UriRefBuf::from_ntriples_escaped(r"http://example.org/\u0020"),
+	///     Err(NTriplesError::UnexpectedChar(19))
+	/// );
+	/// ```
+	pub fn from_ntriples_escaped(input: &str) -> Result<Self, NTriplesError> {
+		let mut result = String::with_capacity(input.len());
+
+		// Offset in the escaped input of each decoded character.
+		let mut offsets = Vec::with_capacity(input.len());
+
+		let mut chars = input.char_indices();
+		while let Some((i, c)) = chars.next() {
+			let c = if c == '\\' {
+				let len = match input[(i + 1)..].chars().next() {
+					Some('u') => 4,
+					Some('U') => 8,
+					_ => return Err(NTriplesError::InvalidEscape(i)),
+				};
+
+				let digits = input
+					.get((i + 2)..(i + 2 + len))
+					.ok_or(NTriplesError::InvalidEscape(i))?;
+				let c = decode_hex(digits).ok_or(NTriplesError::InvalidEscape(i))?;
+				chars.nth(len);
+				c
+			} else if is_forbidden(c) {
+				return Err(NTriplesError::UnexpectedChar(i));
+			} else {
+				c
+			};
+
+			if !is_allowed(c) {
+				return Err(NTriplesError::UnexpectedChar(i));
+			}
+
+			result.push(c);
+			offsets.push(i)
+		}
+
+		Self::new(result).map_err(|e| {
+			let mut automaton = grammar::UriRef::new();
+			let offset = match e.0.chars().position(|c| !automaton.push(c)) {
+				Some(k) => offsets[k],
+				None => input.len(),
+			};
+
+			NTriplesError::InvalidUriRef(offset, e.0)
+		})
+	}
+}
+
+impl UriRef {
+	/// Returns a value displaying this URI reference in the canonical
+	/// N-Triples form, between angle brackets.
+	///
+	/// Characters that cannot appear unescaped in N-Triples are written as
+	/// `\uXXXX` escape sequences. No such character can appear in a valid
+	/// URI reference, so in practice the URI reference is written as is.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::UriRef;
+	///
+	/// let uri_ref = UriRef::new("http://example.org/a?q#f").unwrap();
+	/// assert_eq!(uri_ref.ntriples().to_string(), "<http://example.org/a?q#f>");
+	/// ```
+	pub fn ntriples(&self) -> NTriples<'_> {
+		NTriples(self)
+	}
+}
+
+/// URI reference displayed in the N-Triples form.
+///
+/// See [`UriRef::ntriples`].
+#[derive(Debug, Clone, Copy)]
+pub struct NTriples<'a>(&'a UriRef);
+
+impl fmt::Display for NTriples<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("<")?;
+		for c in self.0.as_str().chars() {
+			if is_forbidden(c) {
+				write!(f, "\\u{:04X}", c as u32)?
+			} else {
+				fmt::Write::write_char(f, c)?
+			}
+		}
+		f.write_str(">")
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_ntriples_escaped() {
+		let vectors = [
+			("", ""),
+			("http://example.org/", "http://example.org/"),
+			(
+				r"\u0068ttp://example.org/\U00000061",
+				"http://example.org/a",
+			),
+			(r"a\u002Fb\u003fc\u0023d", "a/b?c#d"),
+			(r"s:\u0025\u0032\u0030", "s:%20"),
+		];
+
+		for (input, expected) in vectors {
+			let uri_ref = UriRefBuf::from_ntriples_escaped(input).unwrap();
+			assert_eq!(uri_ref.as_str(), expected);
+			assert_eq!(
+				UriRefBuf::from_ntriples_escaped(uri_ref.as_str()).unwrap(),
+				uri_ref
+			)
+		}
+	}
+
+	#[test]
+	fn errors() {
+		let vectors = [
+			(r"a\b", NTriplesError::InvalidEscape(1)),
+			(r"a\u00", NTriplesError::InvalidEscape(1)),
+			(r"a\u00zz", NTriplesError::InvalidEscape(1)),
+			(r"a\uD800", NTriplesError::InvalidEscape(1)),
+			(r"a\U00110000", NTriplesError::InvalidEscape(1)),
+			("a b", NTriplesError::UnexpectedChar(1)),
+			("a>b", NTriplesError::UnexpectedChar(1)),
+			(r"\u0061\u0020", NTriplesError::UnexpectedChar(6)),
+			(r"\u0061\u005C", NTriplesError::UnexpectedChar(6)),
+			(
+				"http://[::1/",
+				NTriplesError::InvalidUriRef(11, "http://[::1/".to_string()),
+			),
+			("%zz", NTriplesError::InvalidUriRef(1, "%zz".to_string())),
+			(
+				r"http://\u005B::1\u005D:\u0078/",
+				NTriplesError::InvalidUriRef(23, "http://[::1]:x/".to_string()),
+			),
+			(
+				r"s:\u00250\u007A",
+				NTriplesError::InvalidUriRef(9, "s:%0z".to_string()),
+			),
+			("s:%2", NTriplesError::InvalidUriRef(4, "s:%2".to_string())),
+		];
+
+		for (input, expected) in vectors {
+			assert_eq!(
+				UriRefBuf::from_ntriples_escaped(input),
+				Err(expected),
+				"{input}"
+			)
+		}
+	}
+
+	#[test]
+	fn ntriples() {
+		let vectors = ["", "http://example.org/a?q#f", "s:%20", "a/b:c"];
+
+		for input in vectors {
+			let uri_ref = UriRef::new(input).unwrap();
+			let output = uri_ref.ntriples().to_string();
+			assert_eq!(output, format!("<{input}>"));
+			assert_eq!(
+				UriRefBuf::from_ntriples_escaped(&output[1..(output.len() - 1)]).unwrap(),
+				uri_ref
+			)
+		}
+	}
+}
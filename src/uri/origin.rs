@@ -0,0 +1,447 @@
+//! Web origins ([RFC 6454](https://datatracker.ietf.org/doc/html/rfc6454)).
+use core::{
+	fmt,
+	sync::atomic::{self, AtomicU64},
+};
+
+use crate::{
+	SchemeBuf,
+	common::{fold_char, punycode},
+};
+
+use super::{Host, HostBuf, Uri};
+
+/// Web origin of a URI, as defined by [RFC 6454].
+///
+/// URIs with a host and a known (or explicit) port have a tuple origin,
+/// made of their scheme, host and effective port. Other URIs (such as
+/// `data:` or `file:` URIs) have an opaque origin, which is only equal to
+/// itself.
+///
+/// [RFC 6454]: <https://datatracker.ietf.org/doc/html/rfc6454#section-4>
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Uri, uri::Origin};
+///
+/// let uri = Uri::new("HTTPS://Example.org:443/path").unwrap();
+/// let origin = uri.origin();
+/// assert_eq!(origin.ascii_serialization(), "https://example.org");
+///
+/// let other = Uri::new("https://example.org/other").unwrap();
+/// assert_eq!(origin, other.origin());
+///
+/// let data = Uri::new("data:text/plain,hello").unwrap();
+/// assert!(data.origin().is_opaque());
+/// assert_ne!(data.origin(), data.origin());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Origin {
+	/// Scheme, host and port tuple.
+	Tuple(TupleOrigin),
+
+	/// Opaque origin.
+	Opaque(OpaqueOrigin),
+}
+
+impl Origin {
+	/// Checks if this is an opaque origin.
+	pub fn is_opaque(&self) -> bool {
+		matches!(self, Self::Opaque(_))
+	}
+
+	/// Returns the tuple origin, if this is not an opaque origin.
+	pub fn as_tuple(&self) -> Option<&TupleOrigin> {
+		match self {
+			Self::Tuple(origin) => Some(origin),
+			Self::Opaque(_) => None,
+		}
+	}
+
+	/// Returns the ASCII serialization of this origin.
+	///
+	/// This is `scheme://host`, followed by `:port` if the port is not
+	/// the default port of the scheme, or `null` for opaque origins.
+	/// Non-ASCII host labels are encoded with Punycode.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::Uri;
+	///
+	/// let a = Uri::new("https://%E4%BE%8B%E3%81%88.%E3%83%86%E3%82%B9%E3%83%88/").unwrap();
+	/// let b = Uri::new("https://xn--r8jz45g.xn--zckzah/").unwrap();
+	/// assert_eq!(
+	///     a.origin().ascii_serialization(),
+	///     "https://xn--r8jz45g.xn--zckzah"
+	/// );
+	/// assert_eq!(a.origin(), b.origin());
+	/// ```
+	pub fn ascii_serialization(&self) -> String {
+		self.to_string()
+	}
+}
+
+impl fmt::Display for Origin {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Tuple(origin) => origin.fmt(f),
+			Self::Opaque(_) => f.write_str("null"),
+		}
+	}
+}
+
+/// Scheme, host and port tuple origin.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TupleOrigin {
+	scheme: SchemeBuf,
+	host: HostBuf,
+	port: u16,
+}
+
+impl TupleOrigin {
+	/// Returns the scheme, in lowercase.
+	pub fn scheme(&self) -> &SchemeBuf {
+		&self.scheme
+	}
+
+	/// Returns the host.
+	///
+	/// The host is normalized: ASCII letters are in lowercase,
+	/// percent-encoded unreserved characters are decoded, characters mapped
+	/// to ASCII by UTS #46 (such as fullwidth letters) are replaced by their
+	/// mapping, non-ASCII labels are lowercased and encoded with Punycode
+	/// (`xn--...`), and IPv6 addresses are in their canonical form.
+	pub fn host(&self) -> &HostBuf {
+		&self.host
+	}
+
+	/// Returns the effective port.
+	pub fn port(&self) -> u16 {
+		self.port
+	}
+}
+
+impl fmt::Display for TupleOrigin {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}://{}", self.scheme, self.host)?;
+		if self.scheme.default_port() != Some(self.port) {
+			write!(f, ":{}", self.port)?
+		}
+
+		Ok(())
+	}
+}
+
+/// Opaque origin.
+///
+/// Each opaque origin is a globally unique identifier: it is only equal to
+/// itself and its clones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OpaqueOrigin(u64);
+
+impl OpaqueOrigin {
+	/// Creates a new unique opaque origin.
+	pub fn new() -> Self {
+		static NEXT: AtomicU64 = AtomicU64::new(0);
+		Self(NEXT.fetch_add(1, atomic::Ordering::Relaxed))
+	}
+}
+
+impl Default for OpaqueOrigin {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Normalizes a host for origin comparison.
+fn normalize_host(host: &Host) -> HostBuf {
+	if host.is_ip_literal() {
+		return match host.to_ipv6() {
+			Some(addr) => HostBuf::from_ipv6(addr),
+			None => unsafe { HostBuf::new_unchecked(host.as_str().to_ascii_lowercase()) },
+		};
+	}
+
+	let decoded: Vec<u8> = host.as_pct_str().bytes().collect();
+	let result = match String::from_utf8(decoded) {
+		Ok(decoded) if !decoded.is_ascii() => normalize_unicode_host(&decoded),
+		_ => None,
+	};
+
+	let result = result.unwrap_or_else(|| normalize_ascii_host(host.as_str()));
+	unsafe { HostBuf::new_unchecked(result) }
+}
+
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+fn is_unreserved(b: u8) -> bool {
+	b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~')
+}
+
+fn push_pct_encoded(result: &mut String, b: u8) {
+	result.push('%');
+	result.push(HEX[(b >> 4) as usize] as char);
+	result.push(HEX[(b & 0x0f) as usize] as char)
+}
+
+/// Normalizes a host, lowercasing ASCII letters and decoding
+/// percent-encoded unreserved characters.
+fn normalize_ascii_host(host: &str) -> String {
+	let mut result = String::with_capacity(host.len());
+	let mut chars = host.chars();
+	while let Some(c) = chars.next() {
+		if c == '%' {
+			// A valid host only has valid percent-encoded bytes.
+			let b = u8::from_str_radix(&chars.as_str()[..2], 16).unwrap();
+			if is_unreserved(b) {
+				result.push(b.to_ascii_lowercase() as char)
+			} else {
+				push_pct_encoded(&mut result, b)
+			}
+
+			chars.nth(1);
+		} else {
+			result.push(c.to_ascii_lowercase())
+		}
+	}
+
+	result
+}
+
+/// Normalizes a percent-decoded internationalized host into its ASCII form.
+///
+/// Non-ASCII characters mapped to ASCII by UTS #46 (such as fullwidth
+/// letters or ideographic full stops) are first replaced by their mapping,
+/// as in the WHATWG URL Standard host parser. Labels are then lowercased,
+/// and non-ASCII labels are encoded with Punycode. The other UTS #46
+/// mappings of non-ASCII characters (full case folding and NFC
+/// normalization) are not applied, so hosts only equivalent through these
+/// mappings have different origins. Returns `None` if a non-ASCII label
+/// contains reserved ASCII characters, or cannot be encoded.
+fn normalize_unicode_host(host: &str) -> Option<String> {
+	let mut folded = String::with_capacity(host.len());
+	for c in host.chars() {
+		match fold_char(c) {
+			Some(mapping) if !c.is_ascii() => folded.push_str(mapping),
+			_ => folded.push(c),
+		}
+	}
+
+	let mut result = String::with_capacity(folded.len());
+	for (i, label) in folded.split('.').enumerate() {
+		if i > 0 {
+			result.push('.')
+		}
+
+		let label = label.to_lowercase();
+		if label.is_ascii() {
+			for b in label.bytes() {
+				if is_unreserved(b) || is_sub_delim(b) {
+					result.push(b as char)
+				} else {
+					push_pct_encoded(&mut result, b)
+				}
+			}
+		} else if label.bytes().all(|b| !b.is_ascii() || is_unreserved(b)) {
+			result.push_str("xn--");
+			result.push_str(&punycode::encode(&label)?)
+		} else {
+			return None;
+		}
+	}
+
+	Some(result)
+}
+
+fn is_sub_delim(b: u8) -> bool {
+	matches!(
+		b,
+		b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+	)
+}
+
+impl Uri {
+	/// Returns the origin of this URI, as defined by [RFC 6454].
+	///
+	/// The URI has a tuple origin if it has a non-empty host and a port,
+	/// either explicit or given by [`Scheme::default_port`](crate::Scheme::default_port).
+	/// Otherwise, and for `file` URIs, a new opaque origin is returned.
+	///
+	/// [RFC 6454]: <https://datatracker.ietf.org/doc/html/rfc6454#section-4>
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::Uri;
+	///
+	/// let uri = Uri::new("http://[0:0::1]:8080/").unwrap();
+	/// let origin = uri.origin();
+	/// let tuple = origin.as_tuple().unwrap();
+	/// assert_eq!(tuple.host(), "[::1]");
+	/// assert_eq!(tuple.port(), 8080);
+	/// assert_eq!(origin.ascii_serialization(), "http://[::1]:8080");
+	/// ```
+	pub fn origin(&self) -> Origin {
+		self.tuple_origin()
+			.map(Origin::Tuple)
+			.unwrap_or_else(|| Origin::Opaque(OpaqueOrigin::new()))
+	}
+
+	fn tuple_origin(&self) -> Option<TupleOrigin> {
+		let scheme = self.scheme();
+		if scheme == crate::Scheme::FILE {
+			return None;
+		}
+
+		let authority = self.authority()?;
+		let parts = authority.parts();
+		if parts.host.as_str().is_empty() {
+			return None;
+		}
+
+		let port = match parts.port.filter(|p| !p.as_str().is_empty()) {
+			Some(port) => port.as_u16()?,
+			None => scheme.default_port()?,
+		};
+
+		Some(TupleOrigin {
+			scheme: unsafe { SchemeBuf::new_unchecked(scheme.as_str().to_ascii_lowercase()) },
+			host: normalize_host(parts.host),
+			port,
+		})
+	}
+}
+
+/// Checks if the given URIs have the same origin.
+///
+/// URIs with an opaque origin are never of the same origin.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Uri, uri::same_origin};
+///
+/// let a = Uri::new("https://example.org/a").unwrap();
+/// let b = Uri::new("https://EXAMPLE.org:443/b?q").unwrap();
+/// let c = Uri::new("http://example.org/a").unwrap();
+/// assert!(same_origin(a, b));
+/// assert!(!same_origin(a, c));
+/// ```
+pub fn same_origin(a: &Uri, b: &Uri) -> bool {
+	match (a.tuple_origin(), b.tuple_origin()) {
+		(Some(a), Some(b)) => a == b,
+		_ => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn origin() {
+		let vectors = [
+			("http://example.org/", Some("http://example.org")),
+			("http://example.org:80/", Some("http://example.org")),
+			("http://example.org:/", Some("http://example.org")),
+			("http://example.org:8080", Some("http://example.org:8080")),
+			("HTTP://User@EXAMPLE.org/a?b#c", Some("http://example.org")),
+			("https://ex%61mple.org/", Some("https://example.org")),
+			("https://a%2fb%2F.org/", Some("https://a%2Fb%2F.org")),
+			(
+				"https://%E4%BE%8B%E3%81%88.%E3%83%86%E3%82%B9%E3%83%88/",
+				Some("https://xn--r8jz45g.xn--zckzah"),
+			),
+			(
+				"https://B%C3%BCcher.Example%E3%80%82org/",
+				Some("https://xn--bcher-kva.example.org"),
+			),
+			("https://%C3%A9%2F.org/", Some("https://%C3%A9%2F.org")),
+			("wss://127.0.0.1:443", Some("wss://127.0.0.1")),
+			("http://[0::1]", Some("http://[::1]")),
+			("http://[v1.FE]", Some("http://[v1.fe]")),
+			("ftp://example.org:80", Some("ftp://example.org:80")),
+			("foo://example.org:80", Some("foo://example.org:80")),
+			("foo://example.org", None),
+			("http://example.org:65536", None),
+			("http:///path", None),
+			("http:path", None),
+			("file:///etc/hosts", None),
+			("file://host/share", None),
+			("data:text/plain,hello", None),
+			("urn:isbn:0451450523", None),
+		];
+
+		for (input, expected) in vectors {
+			let uri = Uri::new(input).unwrap();
+			let origin = uri.origin();
+			match expected {
+				Some(expected) => {
+					assert_eq!(origin.ascii_serialization(), expected, "{input}");
+					assert_eq!(origin, uri.origin());
+					assert!(same_origin(uri, uri))
+				}
+				None => {
+					assert_eq!(origin.ascii_serialization(), "null", "{input}");
+					assert_eq!(origin, origin.clone());
+					assert_ne!(origin, uri.origin());
+					assert!(!same_origin(uri, uri))
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn internationalized_origin() {
+		// Only valid in the IRI module.
+		if let Ok(a) = Uri::new("https://例え.テスト/") {
+			let b = Uri::new("https://xn--r8jz45g.xn--zckzah/").unwrap();
+			assert_eq!(
+				a.origin().ascii_serialization(),
+				"https://xn--r8jz45g.xn--zckzah"
+			);
+			assert!(same_origin(a, b))
+		}
+
+		if let Ok(a) = Uri::new("https://ＥＸＡＭＰＬＥ。org/") {
+			let b = Uri::new("https://example.org/").unwrap();
+			assert_eq!(a.origin().ascii_serialization(), "https://example.org");
+			assert!(same_origin(a, b))
+		}
+
+		if let Ok(a) = Uri::new("https://例え。テスト/") {
+			let b = Uri::new("https://xn--r8jz45g.xn--zckzah/").unwrap();
+			assert!(same_origin(a, b))
+		}
+	}
+
+	#[test]
+	fn same_origins() {
+		let vectors = [
+			("http://example.org/a", "http://example.org:80/b", true),
+			("http://example.org/", "https://example.org/", false),
+			("http://example.org/", "http://example.org:8080/", false),
+			("http://example.org/", "http://www.example.org/", false),
+			("http://Example.ORG/", "http://%65xample.org/", true),
+			("http://%C3%A9.org/", "http://xn--9ca.org/", true),
+			("http://%C3%89.org/", "http://%C3%A9.org/", true),
+			(
+				"http://%EF%BC%A5%EF%BC%B8%EF%BC%A1%EF%BC%AD%EF%BC%B0%EF%BC%AC%EF%BC%A5.org/",
+				"http://example.org/",
+				true,
+			),
+			("http://ex%C2%ADample.org/", "http://example.org/", true),
+			("http://[::1]/", "http://[0:0:0:0:0:0:0:1]/", true),
+			("http://127.0.0.1/", "http://[::ffff:127.0.0.1]/", false),
+		];
+
+		for (a, b, expected) in vectors {
+			let a = Uri::new(a).unwrap();
+			let b = Uri::new(b).unwrap();
+			assert_eq!(same_origin(a, b), expected, "{a} {b}");
+			assert_eq!(same_origin(b, a), expected, "{a} {b}")
+		}
+	}
+}
@@ -0,0 +1,318 @@
+use core::{
+	borrow::Borrow,
+	cmp::Ordering,
+	fmt,
+	hash::{Hash, Hasher},
+	ops::Deref,
+};
+
+use crate::{Storage, StorageMut};
+
+use super::{InvalidUri, InvalidUriRef, Uri, UriBuf, UriRef, UriRefBuf};
+
+macro_rules! uri_owned {
+	($(
+		$(#[$meta:meta])*
+		$ident:ident: $borrowed:ident, $buf:ident, $error:ident, $as_borrowed:ident
+	);*) => {
+		$(
+			$(#[$meta])*
+			#[derive(Clone)]
+			pub struct $ident<S = String>(S);
+
+			impl<S: Storage> $ident<S> {
+				/// Creates a new value by validating the given storage.
+				pub fn new(storage: S) -> Result<Self, $error<S>> {
+					if $borrowed::validate_bytes(storage.as_bytes()) {
+						Ok(Self(storage))
+					} else {
+						Err($error(storage))
+					}
+				}
+
+				/// Creates a new value from the given storage without
+				/// validation.
+				///
+				/// # Safety
+				///
+				/// The storage content must be valid.
+				pub unsafe fn new_unchecked(storage: S) -> Self {
+					Self(storage)
+				}
+
+				/// Copies the given borrowed value into a new storage.
+				pub fn from_borrowed(value: &$borrowed) -> Self {
+					// Valid, since `Storage::from_str` preserves the input bytes.
+					Self(S::from_str(value.as_str()))
+				}
+
+				/// Returns a borrowed reference to the value.
+				pub fn $as_borrowed(&self) -> &$borrowed {
+					unsafe { $borrowed::new_unchecked_from_bytes(self.0.as_bytes()) }
+				}
+
+				/// Returns a reference to the underlying storage.
+				pub fn storage(&self) -> &S {
+					&self.0
+				}
+
+				/// Returns the underlying storage.
+				pub fn into_storage(self) -> S {
+					self.0
+				}
+
+				/// Copies the value into another kind of storage.
+				pub fn to_storage<T: Storage>(&self) -> $ident<T> {
+					$ident::from_borrowed(self.$as_borrowed())
+				}
+
+				/// Converts the value into a `String`-backed buffer.
+				///
+				/// This does not reallocate for storage types owning a
+				/// `String` or `Vec<u8>` buffer.
+				pub fn into_buf(self) -> $buf {
+					// SAFETY: `Storage::into_bytes` returns the stored bytes,
+					// which are valid.
+					unsafe { $buf::new_unchecked(self.0.into_bytes()) }
+				}
+			}
+
+			impl<S: StorageMut> $ident<S> {
+				/// Mutates the value in place using the
+				#[doc = concat!("[`", stringify!($buf), "`]")]
+				/// API.
+				///
+				/// The storage content is moved into a buffer passed to `f`,
+				/// and moved back afterward (even if `f` panics).
+				pub fn modify<R>(&mut self, f: impl FnOnce(&mut $buf) -> R) -> R {
+					// SAFETY: `StorageMut::take_bytes` returns the stored bytes,
+					// which are valid, and `$buf` maintains their validity.
+					unsafe {
+						crate::common::storage::modify(
+							&mut self.0,
+							|bytes| $buf::new_unchecked(bytes),
+							$buf::into_bytes,
+							f,
+						)
+					}
+				}
+			}
+
+			impl<S: Storage> Deref for $ident<S> {
+				type Target = $borrowed;
+
+				fn deref(&self) -> &$borrowed {
+					self.$as_borrowed()
+				}
+			}
+
+			impl<S: Storage> AsRef<$borrowed> for $ident<S> {
+				fn as_ref(&self) -> &$borrowed {
+					self.$as_borrowed()
+				}
+			}
+
+			impl<S: Storage> Borrow<$borrowed> for $ident<S> {
+				fn borrow(&self) -> &$borrowed {
+					self.$as_borrowed()
+				}
+			}
+
+			impl<S: Storage> From<&$borrowed> for $ident<S> {
+				fn from(value: &$borrowed) -> Self {
+					Self::from_borrowed(value)
+				}
+			}
+
+			impl<S: Storage> From<$buf> for $ident<S> {
+				fn from(value: $buf) -> Self {
+					// Valid, since `Storage::from_string` preserves the input
+					// bytes.
+					Self(S::from_string(value.into_string()))
+				}
+			}
+
+			impl<S: Storage> From<$ident<S>> for $buf {
+				fn from(value: $ident<S>) -> Self {
+					value.into_buf()
+				}
+			}
+
+			impl<S: Storage, T: Storage> PartialEq<$ident<T>> for $ident<S> {
+				fn eq(&self, other: &$ident<T>) -> bool {
+					self.$as_borrowed() == other.$as_borrowed()
+				}
+			}
+
+			impl<S: Storage> Eq for $ident<S> {}
+
+			impl<S: Storage> PartialEq<$borrowed> for $ident<S> {
+				fn eq(&self, other: &$borrowed) -> bool {
+					self.$as_borrowed() == other
+				}
+			}
+
+			impl<'a, S: Storage> PartialEq<&'a $borrowed> for $ident<S> {
+				fn eq(&self, other: &&'a $borrowed) -> bool {
+					self.$as_borrowed() == *other
+				}
+			}
+
+			impl<S: Storage> PartialEq<$buf> for $ident<S> {
+				fn eq(&self, other: &$buf) -> bool {
+					self.$as_borrowed() == other.$as_borrowed()
+				}
+			}
+
+			impl<S: Storage, T: Storage> PartialOrd<$ident<T>> for $ident<S> {
+				fn partial_cmp(&self, other: &$ident<T>) -> Option<Ordering> {
+					Some(self.$as_borrowed().cmp(other.$as_borrowed()))
+				}
+			}
+
+			impl<S: Storage> Ord for $ident<S> {
+				fn cmp(&self, other: &Self) -> Ordering {
+					self.$as_borrowed().cmp(other.$as_borrowed())
+				}
+			}
+
+			impl<S: Storage> Hash for $ident<S> {
+				fn hash<H: Hasher>(&self, state: &mut H) {
+					self.$as_borrowed().hash(state)
+				}
+			}
+
+			impl<S: Storage> fmt::Display for $ident<S> {
+				fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+					fmt::Display::fmt(self.$as_borrowed(), f)
+				}
+			}
+
+			impl<S: Storage> fmt::Debug for $ident<S> {
+				fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+					fmt::Debug::fmt(self.$as_borrowed(), f)
+				}
+			}
+		)*
+	};
+}
+
+uri_owned! {
+	/// Owned URI, generic over its [`Storage`].
+	///
+	/// With the default `String` storage, this is equivalent to [`UriBuf`].
+	/// Other storage types allow cheap cloning (`Arc<str>`, `Rc<str>`),
+	/// compact representation (`Box<str>`) or inline storage of short URIs
+	/// ([`SmallVec`](smallvec::SmallVec)).
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use std::sync::Arc;
+	/// use iref::uri::OwnedUri;
+	///
+	/// let uri: OwnedUri<Arc<str>> = OwnedUri::new("https://example.org/".into()).unwrap();
+	/// let copy = uri.clone(); // no allocation.
+	/// assert_eq!(copy.scheme(), "https");
+	///
+	/// let mut uri: OwnedUri<Box<str>> = uri.to_storage();
+	/// uri.modify(|buf| {
+	///     buf.path_mut().push("foo".try_into().unwrap());
+	/// });
+	/// assert_eq!(uri.as_str(), "https://example.org/foo");
+	/// ```
+	OwnedUri: Uri, UriBuf, InvalidUri, as_uri;
+
+	/// Owned URI reference, generic over its [`Storage`].
+	///
+	/// With the default `String` storage, this is equivalent to
+	/// [`UriRefBuf`]. See [`OwnedUri`].
+	OwnedUriRef: UriRef, UriRefBuf, InvalidUriRef, as_uri_ref
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{rc::Rc, sync::Arc};
+
+	use smallvec::SmallVec;
+
+	use super::*;
+
+	#[test]
+	fn storages() {
+		let input = "https://example.org/foo?bar#baz";
+		let a: OwnedUri = OwnedUri::new(input.to_owned()).unwrap();
+		let b: OwnedUri<Box<str>> = OwnedUri::new(input.into()).unwrap();
+		let c: OwnedUri<Arc<str>> = OwnedUri::new(input.into()).unwrap();
+		let d: OwnedUri<Rc<str>> = OwnedUri::new(input.into()).unwrap();
+		let e: OwnedUri<SmallVec<[u8; 64]>> = OwnedUri::from_borrowed(Uri::new(input).unwrap());
+		let f: OwnedUri<Vec<u8>> = OwnedUri::new(input.as_bytes().to_vec()).unwrap();
+
+		assert!(!e.storage().spilled());
+		assert_eq!(a, b);
+		assert_eq!(a, c);
+		assert_eq!(a, d);
+		assert_eq!(a, e);
+		assert_eq!(a, f);
+		assert_eq!(a.path(), "/foo");
+		assert_eq!(e.query().unwrap(), "bar");
+
+		let c2 = c.clone();
+		assert!(Arc::ptr_eq(c.storage(), c2.storage()));
+
+		assert!(OwnedUri::<Arc<str>>::new("foo".into()).is_err());
+		assert!(OwnedUriRef::<Arc<str>>::new("foo".into()).is_ok());
+	}
+
+	#[test]
+	fn equivalence() {
+		let a: OwnedUri<Arc<str>> = OwnedUri::new("HTTP://example.org/%61".into()).unwrap();
+		let b: OwnedUri<Box<str>> = OwnedUri::new("http://example.org/a".into()).unwrap();
+		assert_eq!(a, b);
+		assert_eq!(a, Uri::new("http://example.org/./a").unwrap());
+	}
+
+	#[test]
+	fn modify() {
+		let mut a: OwnedUri<SmallVec<[u8; 32]>> =
+			OwnedUri::from_borrowed(Uri::new("http://a.org/").unwrap());
+		a.modify(|buf| buf.set_path("/a/b/c/d/e/f/g/h/i/j/k/l/m".try_into().unwrap()));
+		assert_eq!(a.as_str(), "http://a.org/a/b/c/d/e/f/g/h/i/j/k/l/m");
+		assert!(a.storage().spilled());
+		a.modify(|buf| buf.set_path("/".try_into().unwrap()));
+		assert_eq!(a.as_str(), "http://a.org/");
+		assert!(!a.storage().spilled());
+
+		let mut b: OwnedUriRef<Box<str>> = OwnedUriRef::new("a/b".into()).unwrap();
+		b.modify(|buf| buf.set_fragment(Some("f".try_into().unwrap())));
+		assert_eq!(b.as_str(), "a/b#f");
+	}
+
+	#[test]
+	fn modify_panic() {
+		let mut a: OwnedUri = OwnedUri::new("http://a.org/".to_owned()).unwrap();
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			a.modify(|buf| {
+				buf.set_query(Some("q".try_into().unwrap()));
+				panic!()
+			})
+		}));
+
+		assert!(result.is_err());
+		assert_eq!(a.as_str(), "http://a.org/?q")
+	}
+
+	#[test]
+	fn conversions() {
+		let buf = UriBuf::new("http://a.org/".to_owned()).unwrap();
+		let ptr = buf.as_str().as_ptr();
+		let owned: OwnedUri = buf.into();
+		assert_eq!(owned.storage().as_ptr(), ptr);
+		let buf = owned.into_buf();
+		assert_eq!(buf.as_str().as_ptr(), ptr);
+
+		let owned: OwnedUri<Rc<str>> = buf.into();
+		let buf: UriBuf = owned.into();
+		assert_eq!(buf, "http://a.org/");
+	}
+}
@@ -0,0 +1,406 @@
+use core::{
+	borrow::Borrow,
+	cmp::Ordering,
+	fmt,
+	hash::{Hash, Hasher},
+	ops::{Deref, Range},
+};
+
+use crate::{
+	Storage,
+	common::parse::{self, Parts, ReferenceParts},
+};
+
+use super::{
+	Authority, Fragment, InvalidUri, InvalidUriRef, OwnedUri, OwnedUriRef, Path, Query, Scheme,
+	Uri, UriBuf, UriParts, UriRef, UriRefBuf, UriRefParts,
+};
+
+/// Parsed URI, caching the position of its components.
+///
+/// The URI is parsed once, when the value is created. Component accessors
+/// ([`scheme`](Self::scheme), [`authority`](Self::authority), etc.),
+/// comparison and hashing then run without scanning the URI again, which
+/// makes this type suitable for comparison-heavy workloads (e.g. as a map
+/// key).
+///
+/// Like [`OwnedUri`], this type is generic over its [`Storage`], and derefs
+/// to [`Uri`].
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Uri, uri::ParsedUri};
+///
+/// let uri: ParsedUri = ParsedUri::new("https://example.org/foo?q".to_owned()).unwrap();
+/// assert_eq!(uri.scheme(), "https");
+/// assert_eq!(uri.authority().unwrap(), "example.org");
+/// assert_eq!(uri.path(), "/foo");
+/// assert_eq!(uri.query().unwrap(), "q");
+/// assert_eq!(uri, Uri::new("HTTPS://example.org/%66oo?q").unwrap());
+/// ```
+#[derive(Clone)]
+pub struct ParsedUri<S = String> {
+	value: OwnedUri<S>,
+	ranges: Parts,
+}
+
+impl<S: Storage> ParsedUri<S> {
+	/// Parses the given storage.
+	pub fn new(storage: S) -> Result<Self, InvalidUri<S>> {
+		OwnedUri::new(storage).map(Self::from_owned)
+	}
+
+	/// Copies and parses the given URI.
+	pub fn from_borrowed(uri: &Uri) -> Self {
+		Self::from_owned(OwnedUri::from_borrowed(uri))
+	}
+
+	/// Parses the given owned URI.
+	pub fn from_owned(value: OwnedUri<S>) -> Self {
+		let ranges = parse::parts(value.as_bytes(), 0);
+		Self { value, ranges }
+	}
+
+	/// Returns the URI.
+	pub fn as_uri(&self) -> &Uri {
+		self.value.as_uri()
+	}
+
+	/// Returns the owned URI, dropping the cached positions.
+	pub fn into_owned(self) -> OwnedUri<S> {
+		self.value
+	}
+
+	/// Returns the URI as a `String`-backed buffer.
+	pub fn into_buf(self) -> UriBuf {
+		self.value.into_buf()
+	}
+
+	/// Returns all the parts of this URI.
+	pub fn parts(&self) -> UriParts<'_> {
+		UriParts {
+			scheme: self.scheme(),
+			authority: self.authority(),
+			path: self.path(),
+			query: self.query(),
+			fragment: self.fragment(),
+		}
+	}
+
+	/// Returns the scheme of the URI.
+	pub fn scheme(&self) -> &Scheme {
+		unsafe { Scheme::new_unchecked_from_bytes(&self.as_bytes()[self.ranges.scheme.clone()]) }
+	}
+
+	/// Returns the authority part of the URI, if any.
+	pub fn authority(&self) -> Option<&Authority> {
+		self.ranges
+			.authority
+			.clone()
+			.map(|r| unsafe { Authority::new_unchecked(self.slice(r)) })
+	}
+
+	/// Returns the path of the URI.
+	pub fn path(&self) -> &Path {
+		unsafe { Path::new_unchecked(self.slice(self.ranges.path.clone())) }
+	}
+
+	/// Returns the query of the URI, if any.
+	pub fn query(&self) -> Option<&Query> {
+		self.ranges
+			.query
+			.clone()
+			.map(|r| unsafe { Query::new_unchecked(self.slice(r)) })
+	}
+
+	/// Returns the fragment of the URI, if any.
+	pub fn fragment(&self) -> Option<&Fragment> {
+		self.ranges
+			.fragment
+			.clone()
+			.map(|r| unsafe { Fragment::new_unchecked(self.slice(r)) })
+	}
+
+	fn slice(&self, range: Range<usize>) -> &str {
+		&self.as_str()[range]
+	}
+}
+
+/// Parsed URI reference, caching the position of its components.
+///
+/// See [`ParsedUri`].
+#[derive(Clone)]
+pub struct ParsedUriRef<S = String> {
+	value: OwnedUriRef<S>,
+	ranges: ReferenceParts,
+}
+
+impl<S: Storage> ParsedUriRef<S> {
+	/// Parses the given storage.
+	pub fn new(storage: S) -> Result<Self, InvalidUriRef<S>> {
+		OwnedUriRef::new(storage).map(Self::from_owned)
+	}
+
+	/// Copies and parses the given URI reference.
+	pub fn from_borrowed(uri_ref: &UriRef) -> Self {
+		Self::from_owned(OwnedUriRef::from_borrowed(uri_ref))
+	}
+
+	/// Parses the given owned URI reference.
+	pub fn from_owned(value: OwnedUriRef<S>) -> Self {
+		let ranges = parse::reference_parts(value.as_bytes(), 0);
+		Self { value, ranges }
+	}
+
+	/// Returns the URI reference.
+	pub fn as_uri_ref(&self) -> &UriRef {
+		self.value.as_uri_ref()
+	}
+
+	/// Returns the owned URI reference, dropping the cached positions.
+	pub fn into_owned(self) -> OwnedUriRef<S> {
+		self.value
+	}
+
+	/// Returns the URI reference as a `String`-backed buffer.
+	pub fn into_buf(self) -> UriRefBuf {
+		self.value.into_buf()
+	}
+
+	/// Returns all the parts of this URI reference.
+	pub fn parts(&self) -> UriRefParts<'_> {
+		UriRefParts {
+			scheme: self.scheme(),
+			authority: self.authority(),
+			path: self.path(),
+			query: self.query(),
+			fragment: self.fragment(),
+		}
+	}
+
+	/// Returns the scheme of the URI reference, if any.
+	pub fn scheme(&self) -> Option<&Scheme> {
+		self.ranges
+			.scheme
+			.clone()
+			.map(|r| unsafe { Scheme::new_unchecked_from_bytes(&self.as_bytes()[r]) })
+	}
+
+	/// Returns the authority part of the URI reference, if any.
+	pub fn authority(&self) -> Option<&Authority> {
+		self.ranges
+			.authority
+			.clone()
+			.map(|r| unsafe { Authority::new_unchecked(self.slice(r)) })
+	}
+
+	/// Returns the path of the URI reference.
+	pub fn path(&self) -> &Path {
+		unsafe { Path::new_unchecked(self.slice(self.ranges.path.clone())) }
+	}
+
+	/// Returns the query of the URI reference, if any.
+	pub fn query(&self) -> Option<&Query> {
+		self.ranges
+			.query
+			.clone()
+			.map(|r| unsafe { Query::new_unchecked(self.slice(r)) })
+	}
+
+	/// Returns the fragment of the URI reference, if any.
+	pub fn fragment(&self) -> Option<&Fragment> {
+		self.ranges
+			.fragment
+			.clone()
+			.map(|r| unsafe { Fragment::new_unchecked(self.slice(r)) })
+	}
+
+	fn slice(&self, range: Range<usize>) -> &str {
+		&self.as_str()[range]
+	}
+}
+
+macro_rules! uri_parsed_impls {
+	($($ident:ident: $borrowed:ident, $owned:ident, $buf:ident, $as_borrowed:ident),*) => {
+		$(
+			impl<S: Storage> Deref for $ident<S> {
+				type Target = $borrowed;
+
+				fn deref(&self) -> &$borrowed {
+					self.$as_borrowed()
+				}
+			}
+
+			impl<S: Storage> AsRef<$borrowed> for $ident<S> {
+				fn as_ref(&self) -> &$borrowed {
+					self.$as_borrowed()
+				}
+			}
+
+			impl<S: Storage> Borrow<$borrowed> for $ident<S> {
+				fn borrow(&self) -> &$borrowed {
+					self.$as_borrowed()
+				}
+			}
+
+			impl<S: Storage> From<&$borrowed> for $ident<S> {
+				fn from(value: &$borrowed) -> Self {
+					Self::from_borrowed(value)
+				}
+			}
+
+			impl<S: Storage> From<$owned<S>> for $ident<S> {
+				fn from(value: $owned<S>) -> Self {
+					Self::from_owned(value)
+				}
+			}
+
+			impl<S: Storage> From<$buf> for $ident<S> {
+				fn from(value: $buf) -> Self {
+					Self::from_owned(value.into())
+				}
+			}
+
+			impl<S: Storage> From<$ident<S>> for $owned<S> {
+				fn from(value: $ident<S>) -> Self {
+					value.into_owned()
+				}
+			}
+
+			impl<S: Storage> From<$ident<S>> for $buf {
+				fn from(value: $ident<S>) -> Self {
+					value.into_buf()
+				}
+			}
+
+			impl<S: Storage, T: Storage> PartialEq<$ident<T>> for $ident<S> {
+				fn eq(&self, other: &$ident<T>) -> bool {
+					self.parts() == other.parts()
+				}
+			}
+
+			impl<S: Storage> Eq for $ident<S> {}
+
+			impl<S: Storage> PartialEq<$borrowed> for $ident<S> {
+				fn eq(&self, other: &$borrowed) -> bool {
+					self.parts() == other.parts()
+				}
+			}
+
+			impl<'a, S: Storage> PartialEq<&'a $borrowed> for $ident<S> {
+				fn eq(&self, other: &&'a $borrowed) -> bool {
+					self.parts() == other.parts()
+				}
+			}
+
+			impl<S: Storage, T: Storage> PartialOrd<$ident<T>> for $ident<S> {
+				fn partial_cmp(&self, other: &$ident<T>) -> Option<Ordering> {
+					Some(self.parts().cmp(&other.parts()))
+				}
+			}
+
+			impl<S: Storage> Ord for $ident<S> {
+				fn cmp(&self, other: &Self) -> Ordering {
+					self.parts().cmp(&other.parts())
+				}
+			}
+
+			impl<S: Storage> Hash for $ident<S> {
+				fn hash<H: Hasher>(&self, state: &mut H) {
+					self.parts().hash(state)
+				}
+			}
+
+			impl<S: Storage> fmt::Display for $ident<S> {
+				fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+					fmt::Display::fmt(self.$as_borrowed(), f)
+				}
+			}
+
+			impl<S: Storage> fmt::Debug for $ident<S> {
+				fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+					fmt::Debug::fmt(self.$as_borrowed(), f)
+				}
+			}
+		)*
+	};
+}
+
+uri_parsed_impls! {
+	ParsedUri: Uri, OwnedUri, UriBuf, as_uri,
+	ParsedUriRef: UriRef, OwnedUriRef, UriRefBuf, as_uri_ref
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		collections::{HashSet, hash_map::DefaultHasher},
+		sync::Arc,
+	};
+
+	use super::*;
+
+	fn hash(value: impl Hash) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		value.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	#[test]
+	fn parts() {
+		let vectors = [
+			"https://example.org:8080/path?query#fragment",
+			"urn:isbn:0451450523",
+			"file:///etc/hosts",
+			"http://example.org",
+			"mailto:someone@example.org?subject=hi",
+			"a:?#",
+		];
+
+		for input in vectors {
+			let uri = Uri::new(input).unwrap();
+			let parsed: ParsedUri<Arc<str>> = ParsedUri::from_borrowed(uri);
+			assert_eq!(parsed.parts(), uri.parts(), "{input}");
+			assert_eq!(hash(&parsed), hash(uri), "{input}");
+			assert_eq!(parsed, uri);
+		}
+	}
+
+	#[test]
+	fn reference_parts() {
+		let vectors = [
+			"https://example.org/path?query#fragment",
+			"//example.org/path",
+			"path/to?q",
+			"",
+			"#f",
+			"../a:b",
+		];
+
+		for input in vectors {
+			let uri_ref = UriRef::new(input).unwrap();
+			let parsed: ParsedUriRef = ParsedUriRef::from_borrowed(uri_ref);
+			assert_eq!(parsed.parts(), uri_ref.parts(), "{input}");
+			assert_eq!(hash(&parsed), hash(uri_ref), "{input}");
+			assert_eq!(parsed, uri_ref);
+		}
+	}
+
+	#[test]
+	fn equivalence() {
+		let set: HashSet<ParsedUri> = [
+			"http://example.org/a",
+			"HTTP://example.org/%61",
+			"http://example.org/./a",
+			"http://example.org/b",
+		]
+		.into_iter()
+		.map(|s| ParsedUri::new(s.to_owned()).unwrap())
+		.collect();
+
+		assert_eq!(set.len(), 2);
+		assert!(set.contains(Uri::new("http://example.org/b").unwrap()));
+		assert!(ParsedUri::<String>::new("foo".to_owned()).is_err());
+	}
+}
@@ -17,14 +17,14 @@ use super::{
 };
 
 /// URI reference.
-#[derive(static_automata::Validate, str_newtype::StrNewType)]
-#[automaton(super::grammar::UriRef)]
+#[derive(iref_newtype_derive::StrNewType)]
 #[newtype(name = "URI reference", ord([u8], &[u8], str, &str))]
 #[cfg_attr(
 	feature = "std",
 	newtype(ord(Vec<u8>, String), owned(UriRefBuf, derive(Default, PartialEq, Eq, PartialOrd, Ord, Hash)))
 )]
 #[cfg_attr(feature = "serde", newtype(serde))]
+#[cfg_attr(feature = "redact-debug", newtype(no_debug))]
 pub struct UriRef(str);
 
 /// Individual components of a URI reference.
@@ -62,6 +62,20 @@ impl Default for &UriRef {
 }
 
 impl UriRef {
+	/// Checks that the given string is a valid URI reference.
+	pub const fn validate_str(input: &str) -> bool {
+		super::fast::validate_uri_ref(input.as_bytes())
+			|| super::grammar::UriRef::validate_str(input)
+	}
+
+	/// Checks that the given bytes are a valid URI reference.
+	///
+	/// Long ASCII inputs are validated using a fast path, falling back to
+	/// the grammar automaton when needed.
+	pub const fn validate_bytes(input: &[u8]) -> bool {
+		super::fast::validate_uri_ref(input) || super::grammar::UriRef::validate_bytes(input)
+	}
+
 	/// Empty URI reference.
 	pub const EMPTY: &'static Self = unsafe { Self::new_unchecked("") };
 
@@ -0,0 +1,218 @@
+//! Registrable domains and sites, using the Public Suffix List.
+use crate::psl::{self, PublicSuffixList};
+
+use super::{Host, Uri};
+
+impl Host {
+	/// Checks if this host is a domain name (not an IP address).
+	fn is_domain(&self) -> bool {
+		!self.is_ip_literal() && !self.is_ipv4() && !self.as_str().is_empty()
+	}
+
+	/// Returns the public suffix of this host, according to the built-in
+	/// [Public Suffix List](crate::psl).
+	///
+	/// Returns `None` if the host is an IP address, or is not a valid
+	/// domain name.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::uri::Host;
+	///
+	/// let host = Host::new("www.example.co.uk").unwrap();
+	/// assert_eq!(host.public_suffix().unwrap(), "co.uk");
+	/// ```
+	pub fn public_suffix(&self) -> Option<&Self> {
+		self.public_suffix_in(PublicSuffixList::builtin())
+	}
+
+	/// Returns the public suffix of this host, according to the given
+	/// Public Suffix List.
+	///
+	/// Returns `None` if the host is an IP address, or is not a valid
+	/// domain name.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::{psl::PublicSuffixList, uri::Host};
+	///
+	/// let list = PublicSuffixList::parse("uk\nco.uk\n");
+	/// let host = Host::new("www.example.co.uk").unwrap();
+	/// assert_eq!(host.public_suffix_in(&list).unwrap(), "co.uk");
+	/// ```
+	pub fn public_suffix_in(&self, list: &PublicSuffixList) -> Option<&Self> {
+		if self.is_domain() {
+			list.public_suffix(self.as_str())
+				.map(|s| unsafe { Self::new_unchecked(s) })
+		} else {
+			None
+		}
+	}
+
+	/// Returns the registrable domain of this host (its public suffix and
+	/// the label before it), according to the built-in
+	/// [Public Suffix List](crate::psl).
+	///
+	/// Returns `None` if the host is an IP address, a public suffix, or is
+	/// not a valid domain name.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::uri::Host;
+	///
+	/// let host = Host::new("www.example.co.uk").unwrap();
+	/// assert_eq!(host.registrable_domain().unwrap(), "example.co.uk");
+	///
+	/// let host = Host::new("co.uk").unwrap();
+	/// assert_eq!(host.registrable_domain(), None);
+	/// ```
+	pub fn registrable_domain(&self) -> Option<&Self> {
+		self.registrable_domain_in(PublicSuffixList::builtin())
+	}
+
+	/// Returns the registrable domain of this host (its public suffix and
+	/// the label before it), according to the given Public Suffix List.
+	///
+	/// Returns `None` if the host is an IP address, a public suffix, or is
+	/// not a valid domain name.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::{psl::PublicSuffixList, uri::Host};
+	///
+	/// let list = PublicSuffixList::parse("*.example\n");
+	/// let host = Host::new("www.foo.example").unwrap();
+	/// assert_eq!(host.registrable_domain_in(&list).unwrap(), "www.foo.example");
+	/// assert_eq!(host.registrable_domain(), Some(Host::new("foo.example").unwrap()));
+	/// ```
+	pub fn registrable_domain_in(&self, list: &PublicSuffixList) -> Option<&Self> {
+		if self.is_domain() {
+			list.registrable_domain(self.as_str())
+				.map(|s| unsafe { Self::new_unchecked(s) })
+		} else {
+			None
+		}
+	}
+
+	/// Returns the normalized site of this host: its registrable domain if
+	/// any, or the host itself.
+	fn site(&self, list: &PublicSuffixList) -> String {
+		if let Some(addr) = self.to_ipv6() {
+			return format!("[{addr:x}]");
+		}
+
+		let host = self.registrable_domain_in(list).unwrap_or(self);
+		psl::normalize(host.as_str()).unwrap_or_else(|| host.as_str().to_lowercase())
+	}
+}
+
+/// Checks if the given URIs are schemefully same-site.
+///
+/// Two URIs are same-site if they have the same scheme, and their hosts
+/// have the same registrable domain (or are equal, when they have no
+/// registrable domain), according to the built-in
+/// [Public Suffix List](crate::psl). Hosts are compared after converting
+/// Punycode labels into Unicode and lowercasing. URIs without host are
+/// never same-site.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Uri, uri::same_site};
+///
+/// let a = Uri::new("https://www.example.co.uk/").unwrap();
+/// let b = Uri::new("https://api.EXAMPLE.co.uk:8443/").unwrap();
+/// let c = Uri::new("https://other.co.uk/").unwrap();
+/// let d = Uri::new("http://www.example.co.uk/").unwrap();
+/// assert!(same_site(a, b));
+/// assert!(!same_site(a, c));
+/// assert!(!same_site(a, d));
+/// ```
+pub fn same_site(a: &Uri, b: &Uri) -> bool {
+	same_site_in(a, b, PublicSuffixList::builtin())
+}
+
+/// Checks if the given URIs are schemefully same-site, according to the
+/// given Public Suffix List.
+///
+/// See [`same_site`] for details.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Uri, psl::PublicSuffixList, uri::same_site_in};
+///
+/// let list = PublicSuffixList::parse("org\nexample.org\n");
+/// let a = Uri::new("https://a.example.org/").unwrap();
+/// let b = Uri::new("https://b.example.org/").unwrap();
+/// assert!(!same_site_in(a, b, &list));
+/// ```
+pub fn same_site_in(a: &Uri, b: &Uri, list: &PublicSuffixList) -> bool {
+	match (a.authority(), b.authority()) {
+		(Some(a_authority), Some(b_authority)) => {
+			let a_host = a_authority.host();
+			let b_host = b_authority.host();
+			!a_host.as_str().is_empty()
+				&& !b_host.as_str().is_empty()
+				&& a.scheme() == b.scheme()
+				&& a_host.site(list) == b_host.site(list)
+		}
+		_ => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn same_sites() {
+		let vectors = [
+			("https://a.example.com", "https://b.example.com", true),
+			("https://example.com", "https://example.com:8443/a", true),
+			("https://a.example.com", "https://a.example.org", false),
+			("https://a.github.io", "https://b.github.io", false),
+			("https://a.b.github.io", "https://c.b.github.io", true),
+			("https://co.uk", "https://co.uk", true),
+			("https://127.0.0.1", "https://127.0.0.1:8080", true),
+			("https://[::1]", "https://[0::1]", true),
+			("https://127.0.0.1", "https://[::ffff:127.0.0.1]", false),
+			(
+				"https://xn--85x722f.com.cn",
+				"https://www.XN--85X722F.com.cn",
+				true,
+			),
+			("s:a", "s:a", false),
+			("file:///a", "file:///a", false),
+		];
+
+		for (a, b, expected) in vectors {
+			let a = Uri::new(a).unwrap();
+			let b = Uri::new(b).unwrap();
+			assert_eq!(same_site(a, b), expected, "{a} {b}");
+			assert_eq!(same_site(b, a), expected, "{a} {b}")
+		}
+	}
+
+	#[test]
+	fn same_sites_in() {
+		let list = PublicSuffixList::parse("com\nexample.com\n!www.example.com\n");
+		let vectors = [
+			("https://a.example.com", "https://b.example.com", false),
+			("https://x.a.example.com", "https://y.a.example.com", true),
+			("https://www.example.com", "https://x.www.example.com", true),
+			("https://a.github.io", "https://b.github.io", true),
+		];
+
+		for (a, b, expected) in vectors {
+			let a = Uri::new(a).unwrap();
+			let b = Uri::new(b).unwrap();
+			assert_eq!(same_site_in(a, b, &list), expected, "{a} {b}");
+			assert_eq!(same_site_in(b, a, &list), expected, "{a} {b}")
+		}
+	}
+}
@@ -0,0 +1,229 @@
+use crate::{
+	IpScope, TargetError, TargetPolicy,
+	common::{fold_host, is_localhost},
+};
+
+use super::Uri;
+
+impl TargetPolicy {
+	/// Checks that the given URI is an allowed target.
+	///
+	/// The URI must have an allowed scheme and a non-empty host. If the host
+	/// is an IP address (in any of the forms accepted by
+	/// [`Host::ip_scope`](super::Host::ip_scope)) or `localhost`, its scope
+	/// must be allowed. Other registered names are accepted: their resolved
+	/// addresses must be checked separately.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::{IpScope, TargetError, TargetPolicy, Uri};
+	///
+	/// let policy = TargetPolicy::default();
+	/// let check = |s| policy.check_uri(Uri::new(s).unwrap());
+	///
+	/// assert_eq!(check("https://8.8.8.8/"), Ok(()));
+	/// assert_eq!(check("http://[::ffff:192.168.0.1]/"), Err(TargetError::Scope(IpScope::Private)));
+	/// assert_eq!(check("http://api.localhost/"), Err(TargetError::Scope(IpScope::Loopback)));
+	/// assert_eq!(check("http:///path"), Err(TargetError::MissingHost));
+	/// ```
+	pub fn check_uri(&self, uri: &Uri) -> Result<(), TargetError> {
+		self.check_scheme(uri.scheme())?;
+
+		let host = uri
+			.authority()
+			.map(|authority| authority.host())
+			.filter(|host| !host.as_str().is_empty())
+			.ok_or(TargetError::MissingHost)?;
+
+		if host.is_ip_literal() && !host.is_ipv6() {
+			return Err(TargetError::UnsupportedHost(host.as_str().to_owned()));
+		}
+
+		match host.ip_scope() {
+			Some(scope) => self.check_scope(scope),
+			None if is_localhost(fold_host(host.as_pct_str().bytes())) => {
+				self.check_scope(IpScope::Loopback)
+			}
+			None => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn check_default_policy() {
+		let vectors = [
+			("https://example.org/hook", Ok(())),
+			("HTTP://93.184.215.14:8080/", Ok(())),
+			("https://[2606:4700::1]/", Ok(())),
+			(
+				"http://127.0.0.1/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			("http://127.1/", Err(TargetError::Scope(IpScope::Loopback))),
+			(
+				"http://2130706433/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			(
+				"http://0x7f000001/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			(
+				"http://0177.0.0.1./",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			(
+				"http://%31%32%37.0.0.1/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			("http://[::1]/", Err(TargetError::Scope(IpScope::Loopback))),
+			(
+				"http://[::ffff:7f00:1]/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			(
+				"http://[::ffff:0:7f00:1]/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			(
+				"http://[64:ff9b:1::a00:1]/",
+				Err(TargetError::Scope(IpScope::Private)),
+			),
+			(
+				"http://%EF%BC%91%EF%BC%92%EF%BC%97.0.0.1/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			(
+				"http://127%E3%80%820%E3%80%820%E3%80%821/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			(
+				"http://%EF%BD%8C%EF%BD%8F%EF%BD%83%EF%BD%81%EF%BD%8C%EF%BD%88%EF%BD%8F%EF%BD%93%EF%BD%94/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			(
+				"http://localhost%E3%80%82/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			(
+				"http://local%C2%ADhost/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			(
+				"http://LocalHost./",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			(
+				"http://a.b.localhost/",
+				Err(TargetError::Scope(IpScope::Loopback)),
+			),
+			("http://0/", Err(TargetError::Scope(IpScope::Unspecified))),
+			(
+				"http://[::]/",
+				Err(TargetError::Scope(IpScope::Unspecified)),
+			),
+			(
+				"http://10.1.2.3/",
+				Err(TargetError::Scope(IpScope::Private)),
+			),
+			(
+				"http://[fd12::1]/",
+				Err(TargetError::Scope(IpScope::Private)),
+			),
+			(
+				"http://169.254.169.254/",
+				Err(TargetError::Scope(IpScope::LinkLocal)),
+			),
+			(
+				"http://[fe80::1]/",
+				Err(TargetError::Scope(IpScope::LinkLocal)),
+			),
+			(
+				"http://224.0.0.1/",
+				Err(TargetError::Scope(IpScope::Multicast)),
+			),
+			(
+				"http://192.0.2.1/",
+				Err(TargetError::Scope(IpScope::Documentation)),
+			),
+			(
+				"http://255.255.255.255/",
+				Err(TargetError::Scope(IpScope::Reserved)),
+			),
+			(
+				"http://[v1.x]/",
+				Err(TargetError::UnsupportedHost("[v1.x]".to_owned())),
+			),
+			("http:///path", Err(TargetError::MissingHost)),
+			("http:path", Err(TargetError::MissingHost)),
+			(
+				"file:///etc/passwd",
+				Err(TargetError::Scheme(crate::Scheme::FILE.to_owned())),
+			),
+			(
+				"gopher://example.org/",
+				Err(TargetError::Scheme(
+					crate::Scheme::new("gopher").unwrap().to_owned(),
+				)),
+			),
+			// Not IPv4 addresses.
+			("http://1.2.3.4.5/", Ok(())),
+			("http://localhost.example.org/", Ok(())),
+			("http://%FF127.0.0.1/", Ok(())),
+			("http://%E4%BE%8B.localhost.org/", Ok(())),
+		];
+
+		let policy = TargetPolicy::new();
+		for (input, expected) in vectors {
+			let uri = Uri::new(input).unwrap();
+			assert_eq!(policy.check_uri(uri), expected, "{input}")
+		}
+	}
+
+	#[test]
+	fn check_unicode_hosts() {
+		let vectors = [
+			"http://１２７.０.０.１/",
+			"http://𝟏𝟐𝟕.0.0.1/",
+			"http://127。0。0。1/",
+			"http://127．0．0．1/",
+			"http://ｌｏｃａｌｈｏｓｔ/",
+			"http://LOCALHOST。/",
+			"http://api.localhost｡/",
+		];
+
+		let policy = TargetPolicy::new();
+		for input in vectors {
+			// Only IRIs can contain these hosts unencoded.
+			if let Ok(uri) = Uri::new(input) {
+				assert_eq!(
+					policy.check_uri(uri),
+					Err(TargetError::Scope(IpScope::Loopback)),
+					"{input}"
+				)
+			}
+		}
+	}
+
+	#[test]
+	fn check_custom_policy() {
+		let policy = TargetPolicy::new()
+			.allow_scheme(crate::Scheme::FTP)
+			.allow_scope(IpScope::Loopback);
+
+		assert_eq!(
+			policy.check_uri(Uri::new("ftp://localhost/").unwrap()),
+			Ok(())
+		);
+		assert_eq!(policy.check_uri(Uri::new("http://[::1]/").unwrap()), Ok(()));
+		assert_eq!(
+			policy.check_uri(Uri::new("http://10.0.0.1/").unwrap()),
+			Err(TargetError::Scope(IpScope::Private))
+		);
+	}
+}
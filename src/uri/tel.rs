@@ -0,0 +1,512 @@
+use core::{
+	cmp::Ordering,
+	fmt,
+	hash::{Hash, Hasher},
+};
+
+use pct_str::PctStr;
+use smallvec::SmallVec;
+
+use super::{Scheme, Uri};
+
+/// Invalid `tel` URI error.
+///
+/// This error is raised by [`TelUri::new`] when the input URI is not a valid
+/// [RFC 3966](https://www.rfc-editor.org/rfc/rfc3966) `tel` URI.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid `tel` URI: {0}")]
+pub struct InvalidTelUri<T>(pub T);
+
+/// `tel` URI ([RFC 3966](https://www.rfc-editor.org/rfc/rfc3966)).
+///
+/// Borrowed view over a [`Uri`] with the `tel` scheme, giving access to the
+/// telephone number and its parameters. Comparison follows the
+/// equivalence rules of RFC 3966 Section 4: visual separators are ignored,
+/// parameters are compared by name regardless of their order and the
+/// comparison is case-insensitive.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::Uri;
+///
+/// let a = Uri::new("tel:+1-201-555-0123;ext=42").unwrap().as_tel().unwrap();
+/// let b = Uri::new("tel:+1(201)555.0123;EXT=42").unwrap().as_tel().unwrap();
+///
+/// assert!(a.is_global());
+/// assert_eq!(a.digits().collect::<String>(), "12015550123");
+/// assert_eq!(a.extension().unwrap(), "42");
+/// assert_eq!(a, b);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TelUri<'a> {
+	uri: &'a Uri,
+
+	/// Telephone number, up to the first `;`.
+	number: &'a str,
+
+	/// Parameters, starting with `;`, or empty.
+	parameters: &'a str,
+}
+
+impl Uri {
+	/// Interprets this URI as a `tel` URI.
+	///
+	/// See [`TelUri::new`].
+	pub fn as_tel(&self) -> Result<TelUri<'_>, InvalidTelUri<&Self>> {
+		TelUri::new(self)
+	}
+}
+
+impl<'a> TelUri<'a> {
+	/// Interprets the given URI as a `tel` URI.
+	///
+	/// Fails if the scheme is not `tel`, if the URI has an authority or a
+	/// query, or if the path is not a valid `telephone-subscriber`. A local
+	/// number must have a `phone-context` parameter, while a global number
+	/// must not. The fragment, if any, is ignored.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use iref::{Uri, uri::TelUri};
+	///
+	/// let uri = Uri::new("tel:7042;phone-context=example.com").unwrap();
+	/// let tel = TelUri::new(uri).unwrap();
+	/// assert!(tel.is_local());
+	/// assert_eq!(tel.phone_context().unwrap(), "example.com");
+	///
+	/// assert!(TelUri::new(Uri::new("tel:7042").unwrap()).is_err());
+	/// ```
+	pub fn new(uri: &'a Uri) -> Result<Self, InvalidTelUri<&'a Uri>> {
+		if *uri.scheme() != *Scheme::TEL || uri.authority().is_some() || uri.query().is_some() {
+			return Err(InvalidTelUri(uri));
+		}
+
+		let path = uri.path().as_str();
+		let (number, parameters) = match path.find(';') {
+			Some(i) => (&path[..i], &path[i..]),
+			None => (path, ""),
+		};
+
+		let tel = Self {
+			uri,
+			number,
+			parameters,
+		};
+
+		if tel.is_valid() {
+			Ok(tel)
+		} else {
+			Err(InvalidTelUri(uri))
+		}
+	}
+
+	/// Returns the underlying URI.
+	pub fn as_uri(&self) -> &'a Uri {
+		self.uri
+	}
+
+	/// Checks if this is a global number, starting with `+`.
+	pub fn is_global(&self) -> bool {
+		self.number.starts_with('+')
+	}
+
+	/// Checks if this is a local number, qualified by a `phone-context`
+	/// parameter.
+	pub fn is_local(&self) -> bool {
+		!self.is_global()
+	}
+
+	/// Returns the telephone number as written, including the leading `+`
+	/// of global numbers and visual separators.
+	pub fn number(&self) -> &'a PctStr {
+		unsafe { PctStr::new_unchecked(self.number) }
+	}
+
+	/// Returns an iterator over the digits of the telephone number.
+	///
+	/// The leading `+` and the visual separators (`-`, `.`, `(`, `)`) are
+	/// skipped, and percent-encoded characters (such as `%23` for `#`) are
+	/// decoded.
+	pub fn digits(&self) -> TelDigits<'a> {
+		TelDigits(self.number().bytes())
+	}
+
+	/// Returns an iterator over the parameters, including `ext`, `isub` and
+	/// `phone-context`, in order of appearance.
+	pub fn parameters(&self) -> TelParameters<'a> {
+		TelParameters(self.parameters.get(1..).map(|p| p.split(';')))
+	}
+
+	/// Returns the parameters, sorted modulo RFC 3966 equivalence.
+	fn sorted_parameters(&self) -> SmallVec<[TelParameter<'a>; 4]> {
+		let mut result: SmallVec<[TelParameter<'a>; 4]> = self.parameters().collect();
+		result.sort_unstable_by(TelParameter::cmp_equivalent);
+		result
+	}
+
+	/// Returns the value of the first parameter with the given name, if
+	/// any.
+	///
+	/// Parameter names are case-insensitive. Parameters without value
+	/// return `Some(None)`.
+	pub fn parameter(&self, name: &str) -> Option<Option<&'a PctStr>> {
+		self.parameters()
+			.find(|p| p.name.eq_ignore_ascii_case(name))
+			.map(|p| p.value)
+	}
+
+	/// Returns the value of the `ext` (extension) parameter, if any.
+	pub fn extension(&self) -> Option<&'a PctStr> {
+		self.parameter("ext").flatten()
+	}
+
+	/// Returns the value of the `isub` (ISDN subaddress) parameter, if any.
+	pub fn isdn_subaddress(&self) -> Option<&'a PctStr> {
+		self.parameter("isub").flatten()
+	}
+
+	/// Returns the value of the `phone-context` parameter, if any.
+	///
+	/// It is either a domain name or a global number.
+	pub fn phone_context(&self) -> Option<&'a PctStr> {
+		self.parameter("phone-context").flatten()
+	}
+
+	fn is_valid(&self) -> bool {
+		let global = self.is_global();
+		let number = self.number();
+		let valid_number = if global {
+			is_global_number_digits(number)
+		} else {
+			is_local_number_digits(number)
+		};
+
+		if !valid_number {
+			return false;
+		}
+
+		let mut ext = false;
+		let mut isub = false;
+		let mut context = false;
+
+		for p in self.parameters() {
+			if p.name.is_empty()
+				|| !p
+					.name
+					.bytes()
+					.all(|b| b.is_ascii_alphanumeric() || b == b'-')
+			{
+				return false;
+			}
+
+			let (seen, valid) = if p.name.eq_ignore_ascii_case("ext") {
+				(&mut ext, p.value.is_some_and(is_phone_digits))
+			} else if p.name.eq_ignore_ascii_case("isub") {
+				(&mut isub, true)
+			} else if p.name.eq_ignore_ascii_case("phone-context") {
+				(&mut context, p.value.is_some_and(is_descriptor))
+			} else {
+				continue;
+			};
+
+			if *seen || !valid {
+				return false;
+			}
+
+			*seen = true
+		}
+
+		context != global
+	}
+}
+
+impl PartialEq for TelUri<'_> {
+	fn eq(&self, other: &Self) -> bool {
+		if self.is_global() != other.is_global()
+			|| !normalized_digits_eq(self.number(), other.number())
+		{
+			return false;
+		}
+
+		let a = self.sorted_parameters();
+		let b = other.sorted_parameters();
+		a.len() == b.len() && a.iter().zip(&b).all(|(a, b)| a.cmp_equivalent(b).is_eq())
+	}
+}
+
+impl Eq for TelUri<'_> {}
+
+impl Hash for TelUri<'_> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.is_global().hash(state);
+		for b in normalized_digits(self.number()) {
+			state.write_u8(b)
+		}
+	}
+}
+
+impl fmt::Display for TelUri<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.uri.fmt(f)
+	}
+}
+
+/// Iterator over the digits of a [`TelUri`] number.
+///
+/// See [`TelUri::digits`].
+pub struct TelDigits<'a>(pct_str::Bytes<'a>);
+
+impl Iterator for TelDigits<'_> {
+	type Item = char;
+
+	fn next(&mut self) -> Option<char> {
+		self.0
+			.by_ref()
+			.find(|b| !is_visual_separator(*b) && *b != b'+')
+			.map(char::from)
+	}
+}
+
+/// `tel` URI parameter.
+#[derive(Debug, Clone, Copy)]
+pub struct TelParameter<'a> {
+	/// Parameter name.
+	pub name: &'a str,
+
+	/// Parameter value, if any.
+	pub value: Option<&'a PctStr>,
+}
+
+impl TelParameter<'_> {
+	/// Compares two parameters modulo RFC 3966 equivalence.
+	///
+	/// Equivalent parameters compare equal.
+	fn cmp_equivalent(&self, other: &Self) -> Ordering {
+		self.name
+			.bytes()
+			.map(|b| b.to_ascii_lowercase())
+			.cmp(other.name.bytes().map(|b| b.to_ascii_lowercase()))
+			.then_with(|| match (self.value, other.value) {
+				(Some(a), Some(b)) => self.normalized_value(a).cmp(other.normalized_value(b)),
+				(a, b) => a.is_some().cmp(&b.is_some()),
+			})
+	}
+
+	/// Normalizes the given value of this parameter.
+	///
+	/// Phone numbers (`ext` values and global `phone-context` values) are
+	/// normalized as [`normalized_digits`], other values are in lower case.
+	fn normalized_value<'v>(&self, value: &'v PctStr) -> impl 'v + Iterator<Item = u8> {
+		let digits = self.name.eq_ignore_ascii_case("ext")
+			|| (self.name.eq_ignore_ascii_case("phone-context") && value.as_str().starts_with('+'));
+
+		value.bytes().filter_map(move |b| {
+			if !digits {
+				Some(b.to_ascii_lowercase())
+			} else if is_visual_separator(b) {
+				None
+			} else {
+				Some(b.to_ascii_uppercase())
+			}
+		})
+	}
+}
+
+/// Iterator over the parameters of a [`TelUri`].
+///
+/// See [`TelUri::parameters`].
+pub struct TelParameters<'a>(Option<core::str::Split<'a, char>>);
+
+impl<'a> Iterator for TelParameters<'a> {
+	type Item = TelParameter<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let p = self.0.as_mut()?.next()?;
+		let (name, value) = match p.split_once('=') {
+			Some((name, value)) => (name, Some(unsafe { PctStr::new_unchecked(value) })),
+			None => (p, None),
+		};
+
+		Some(TelParameter { name, value })
+	}
+}
+
+fn is_visual_separator(b: u8) -> bool {
+	matches!(b, b'-' | b'.' | b'(' | b')')
+}
+
+/// Decoded digits, without visual separators, in upper case.
+fn normalized_digits(s: &PctStr) -> impl '_ + Iterator<Item = u8> {
+	s.bytes()
+		.filter(|b| !is_visual_separator(*b))
+		.map(|b| b.to_ascii_uppercase())
+}
+
+fn normalized_digits_eq(a: &PctStr, b: &PctStr) -> bool {
+	normalized_digits(a).eq(normalized_digits(b))
+}
+
+/// Checks `global-number-digits = "+" *phonedigit DIGIT *phonedigit`.
+fn is_global_number_digits(s: &PctStr) -> bool {
+	let mut bytes = s.bytes();
+	bytes.next() == Some(b'+') && is_phone_digits_bytes(bytes)
+}
+
+/// Checks `1*phonedigit` with at least one `DIGIT`.
+fn is_phone_digits(s: &PctStr) -> bool {
+	is_phone_digits_bytes(s.bytes())
+}
+
+fn is_phone_digits_bytes(bytes: impl Iterator<Item = u8>) -> bool {
+	let mut digit = false;
+	for b in bytes {
+		if b.is_ascii_digit() {
+			digit = true
+		} else if !is_visual_separator(b) {
+			return false;
+		}
+	}
+
+	digit
+}
+
+/// Checks `local-number-digits = *phonedigit-hex (HEXDIG / "*" / "#")
+/// *phonedigit-hex`.
+fn is_local_number_digits(s: &PctStr) -> bool {
+	let mut digit = false;
+	for b in s.bytes() {
+		if b.is_ascii_hexdigit() || b == b'*' || b == b'#' {
+			digit = true
+		} else if !is_visual_separator(b) {
+			return false;
+		}
+	}
+
+	digit
+}
+
+/// Checks `descriptor = domainname / global-number-digits`.
+fn is_descriptor(s: &PctStr) -> bool {
+	if s.as_str().starts_with('+') {
+		return is_global_number_digits(s);
+	}
+
+	let domain = s.as_str();
+	let domain = domain.strip_suffix('.').unwrap_or(domain);
+	!domain.is_empty()
+		&& domain.split('.').all(|label| {
+			!label.is_empty()
+				&& !label.starts_with('-')
+				&& !label.ends_with('-')
+				&& label
+					.bytes()
+					.all(|b| b.is_ascii_alphanumeric() || b == b'-')
+		}) && domain
+		.rsplit('.')
+		.next()
+		.is_some_and(|top| top.starts_with(|c: char| c.is_ascii_alphabetic()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn tel(s: &str) -> TelUri<'_> {
+		Uri::new(s).unwrap().as_tel().unwrap()
+	}
+
+	#[test]
+	fn parse() {
+		let t = tel("tel:+1-201-555-0123");
+		assert!(t.is_global());
+		assert_eq!(t.number(), "+1-201-555-0123");
+		assert_eq!(t.digits().collect::<String>(), "12015550123");
+		assert_eq!(t.parameters().count(), 0);
+
+		let t = tel("tel:*61%23;phone-context=example.com;isub=%31ab;foo");
+		assert!(t.is_local());
+		assert_eq!(t.digits().collect::<String>(), "*61#");
+		assert_eq!(t.phone_context().unwrap(), "example.com");
+		assert_eq!(t.isdn_subaddress().unwrap().as_str(), "%31ab");
+		assert_eq!(t.extension(), None);
+		assert_eq!(t.parameter("FOO"), Some(None));
+
+		let t = tel("tel:863-1234;phone-context=+1-914-555");
+		assert_eq!(t.phone_context().unwrap(), "+1-914-555");
+
+		let t = tel("tel:+1-201-555-0123;ext=1234#section");
+		assert_eq!(t.extension().unwrap(), "1234");
+	}
+
+	#[test]
+	fn invalid() {
+		let vectors = [
+			"http:+1-201-555-0123",
+			"tel://host/+1",
+			"tel:+1?query",
+			"tel:12345",
+			"tel:+",
+			"tel:+12a",
+			"tel:+1;phone-context=example.com",
+			"tel:1;phone-context=",
+			"tel:1;phone-context=-example.com",
+			"tel:1;phone-context=example.123",
+			"tel:+1;ext=",
+			"tel:+1;ext=1a",
+			"tel:+1;ext=1;ext=2",
+			"tel:+1;=value",
+			"tel:+1;a_b",
+		];
+
+		for input in vectors {
+			assert!(Uri::new(input).unwrap().as_tel().is_err(), "{input}")
+		}
+	}
+
+	#[test]
+	fn equivalence() {
+		let equivalent = [
+			("tel:+1-201-555-0123", "tel:+1(201)555.0123"),
+			("tel:+1-201-555-0123", "tel:+%31-201-555-0123"),
+			(
+				"tel:+12015550123;ext=1;foo=bar",
+				"tel:+12015550123;FOO=BAR;ext=1",
+			),
+			("tel:+12015550123;ext=1-2", "tel:+12015550123;ext=12"),
+			(
+				"tel:7042;phone-context=EXAMPLE.com",
+				"tel:7042;phone-context=example.com",
+			),
+			(
+				"tel:abc;phone-context=+1-914",
+				"tel:ABC;phone-context=+1914",
+			),
+			("tel:%2361;phone-context=a.b", "tel:%2361;phone-context=a.b"),
+			("tel:+12015550123;a;b;a", "tel:+12015550123;A;a;B"),
+		];
+
+		for (a, b) in equivalent {
+			assert_eq!(tel(a), tel(b), "{a} = {b}")
+		}
+
+		let distinct = [
+			("tel:+1-201-555-0123", "tel:+1-201-555-0124"),
+			("tel:+12015550123", "tel:+12015550123;ext=1"),
+			("tel:+12015550123;ext=1", "tel:+12015550123;ext=2"),
+			("tel:+12015550123;foo", "tel:+12015550123;foo=bar"),
+			(
+				"tel:7042;phone-context=example.com",
+				"tel:7042;phone-context=example.org",
+			),
+			("tel:+12015550123;a;a", "tel:+12015550123;a;b"),
+			("tel:+12015550123;a;a;b", "tel:+12015550123;a;b;b"),
+			("tel:+12015550123;a;b;b", "tel:+12015550123;A;A;b"),
+		];
+
+		for (a, b) in distinct {
+			assert_ne!(tel(a), tel(b), "{a} != {b}")
+		}
+	}
+}
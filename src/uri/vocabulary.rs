@@ -0,0 +1,223 @@
+use super::Uri;
+
+/// URI that is not a term of a vocabulary generated by
+/// [`uri_vocabulary!`](crate::uri_vocabulary).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("unknown vocabulary term: {0}")]
+pub struct UnknownTerm<T>(pub T);
+
+/// Returns the local name of a vocabulary term.
+///
+/// This is the explicit `name` if any, or the term identifier without its
+/// raw identifier prefix.
+#[doc(hidden)]
+pub const fn const_term_name<'a>(ident: &'a str, name: &[&'a str]) -> &'a str {
+	match name {
+		[name] => name,
+		_ => match ident.as_bytes() {
+			[b'r', b'#', ..] => ident.split_at(2).1,
+			_ => ident,
+		},
+	}
+}
+
+/// Returns the URI of a vocabulary term, where `L` is its length.
+#[doc(hidden)]
+pub const fn const_term<const L: usize>(prefix: &Uri, name: &str) -> [u8; L] {
+	super::const_concat(prefix.as_str(), name)
+}
+
+/// Declares a vocabulary module, with a `&'static Uri` constant per term.
+///
+/// The module contains:
+/// - a `PREFIX` constant, the common prefix of all terms;
+/// - one constant per term, named after the term, equal to the prefix
+///   followed by the term local name;
+/// - a `Term` enum with one variant per term, implementing
+///   `AsRef<Uri>`, `Display` and `TryFrom<&Uri>`.
+///
+/// The local name of a term is its identifier, without the `r#` prefix of
+/// raw identifiers. It can be given explicitly with `ident = "name"`, for
+/// names that are not valid identifiers. Every term URI is checked at
+/// compile time.
+///
+/// Converting a URI into a `Term` compares it with the term URIs as
+/// strings, without normalization, and returns [`UnknownTerm`] if no term
+/// matches.
+///
+/// # Example
+///
+/// ```rust
+/// use iref::{Uri, uri_vocabulary};
+///
+/// uri_vocabulary! {
+///     /// Friend of a Friend vocabulary.
+///     pub mod foaf {
+///         prefix = "http://xmlns.com/foaf/0.1/";
+///         Person, name, knows, mbox_sha1 = "mbox_sha1sum"
+///     }
+/// }
+///
+/// assert_eq!(foaf::name, "http://xmlns.com/foaf/0.1/name");
+/// assert_eq!(foaf::mbox_sha1, "http://xmlns.com/foaf/0.1/mbox_sha1sum");
+///
+/// let uri = Uri::new("http://xmlns.com/foaf/0.1/knows").unwrap();
+/// match foaf::Term::try_from(uri) {
+///     Ok(foaf::Term::knows) => (),
+///     _ => panic!("not `foaf:knows`"),
+/// }
+/// ```
+///
+/// Invalid terms are rejected at compile time:
+///
+/// ```compile_fail
+/// iref::uri_vocabulary! {
+///     mod example {
+///         prefix = "http://example.org/";
+///         valid, invalid = "invalid name"
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! uri_vocabulary {
+	{
+		$(#[$meta:meta])*
+		$vis:vis mod $module:ident {
+			prefix = $prefix:literal;
+			$($term:ident $(= $name:literal)?),+ $(,)?
+		}
+	} => {
+		$(#[$meta])*
+		#[allow(non_upper_case_globals)]
+		$vis mod $module {
+			/// Common prefix of every term.
+			pub const PREFIX: &$crate::uri::Uri = match $crate::uri::Uri::from_str($prefix) {
+				Ok(value) => value,
+				Err(_) => panic!("invalid vocabulary prefix"),
+			};
+
+			mod __names {
+				$(
+					pub const $term: &str = $crate::uri::const_term_name(
+						stringify!($term),
+						&[$($name)?],
+					);
+				)+
+			}
+
+			$(
+				#[doc = concat!("`", $prefix, "` term `", stringify!($term), "`.")]
+				pub const $term: &$crate::uri::Uri = {
+					const __LEN: usize = PREFIX.as_str().len() + __names::$term.len();
+					const __BYTES: [u8; __LEN] = $crate::uri::const_term(PREFIX, __names::$term);
+					match $crate::uri::Uri::from_bytes(&__BYTES) {
+						Ok(value) => value,
+						Err(_) => panic!("invalid vocabulary term"),
+					}
+				};
+			)+
+
+			/// Vocabulary term.
+			#[allow(non_camel_case_types)]
+			#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+			pub enum Term {
+				$(
+					#[doc = concat!("`", $prefix, "` term `", stringify!($term), "`.")]
+					$term
+				),+
+			}
+
+			impl ::core::convert::AsRef<$crate::uri::Uri> for Term {
+				fn as_ref(&self) -> &$crate::uri::Uri {
+					match self {
+						$(Self::$term => $term),+
+					}
+				}
+			}
+
+			impl ::core::convert::From<Term> for &'static $crate::uri::Uri {
+				fn from(term: Term) -> Self {
+					match term {
+						$(Term::$term => $term),+
+					}
+				}
+			}
+
+			impl ::core::fmt::Display for Term {
+				fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+					::core::fmt::Display::fmt(::core::convert::AsRef::<$crate::uri::Uri>::as_ref(self), f)
+				}
+			}
+
+			impl<'a> ::core::convert::TryFrom<&'a $crate::uri::Uri> for Term {
+				type Error = $crate::uri::UnknownTerm<&'a $crate::uri::Uri>;
+
+				fn try_from(uri: &'a $crate::uri::Uri) -> ::core::result::Result<Self, Self::Error> {
+					match uri.as_str().strip_prefix(PREFIX.as_str()) {
+						$(Some(__names::$term) => Ok(Self::$term),)+
+						_ => Err($crate::uri::UnknownTerm(uri)),
+					}
+				}
+			}
+		}
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	uri_vocabulary! {
+		mod rdf {
+			prefix = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+			r#type, Property, first, rest, nil, lang_string = "langString"
+		}
+	}
+
+	#[test]
+	fn constants() {
+		assert_eq!(
+			rdf::PREFIX.as_str(),
+			"http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+		);
+		assert_eq!(
+			rdf::r#type.as_str(),
+			"http://www.w3.org/1999/02/22-rdf-syntax-ns#type"
+		);
+		assert_eq!(
+			rdf::lang_string.as_str(),
+			"http://www.w3.org/1999/02/22-rdf-syntax-ns#langString"
+		);
+	}
+
+	#[test]
+	fn terms() {
+		let vectors = [
+			(rdf::r#type, Some(rdf::Term::r#type)),
+			(rdf::Property, Some(rdf::Term::Property)),
+			(rdf::lang_string, Some(rdf::Term::lang_string)),
+			(rdf::PREFIX, None),
+		];
+
+		for (uri, expected) in vectors {
+			let term = rdf::Term::try_from(uri).ok();
+			assert_eq!(term, expected);
+			if let Some(term) = term {
+				assert_eq!(term.as_ref(), uri);
+				assert_eq!(<&Uri>::from(term), uri)
+			}
+		}
+
+		let vectors = [
+			"http://www.w3.org/1999/02/22-rdf-syntax-ns#lang_string",
+			"http://www.w3.org/1999/02/22-rdf-syntax-ns#typ",
+			"http://www.w3.org/1999/02/22-rdf-syntax-ns#type/",
+			"http://example.org/type",
+		];
+
+		for input in vectors {
+			let uri = Uri::new(input).unwrap();
+			assert_eq!(rdf::Term::try_from(uri), Err(UnknownTerm(uri)))
+		}
+	}
+}